@@ -0,0 +1,177 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::cat::{Cat, CatEvent, CatState};
+
+// 実績の一覧（表示する順）。goal があるものは、そこまでの進み具合を表示する
+pub const ACHIEVEMENT_RULES: &[AchievementRule] = &[
+    AchievementRule {
+        achievement: Achievement::FirstFeed,
+        label: "はじめてのごはん",
+        description: "はじめて餌をあげる",
+        goal: None,
+    },
+    AchievementRule {
+        achievement: Achievement::Survived,
+        label: "24時間いっしょ",
+        description: "1匹の猫と24時間過ごす",
+        goal: Some(Goal { seconds: 24.0 * 3600.0, unit_seconds: 3600.0, unit: "時間" }),
+    },
+    AchievementRule {
+        achievement: Achievement::HappyHour,
+        label: "ごきげんな1時間",
+        description: "1時間ずっとご機嫌のままにする",
+        goal: Some(Goal { seconds: 3600.0, unit_seconds: 60.0, unit: "分" }),
+    },
+    AchievementRule {
+        achievement: Achievement::CalmBath,
+        label: "お風呂じょうず",
+        description: "不機嫌にさせずにお風呂に入れる",
+        goal: None,
+    },
+    AchievementRule {
+        achievement: Achievement::Revived,
+        label: "危機一髪",
+        description: "瀕死の猫を元気にする",
+        goal: None,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Achievement {
+    FirstFeed, // はじめてのごはん
+    Survived,  // 24時間いっしょ
+    HappyHour, // ごきげんな1時間
+    CalmBath,  // お風呂じょうず
+    Revived,   // 危機一髪
+}
+
+pub struct AchievementRule {
+    pub achievement: Achievement,
+    pub label: &'static str,
+    pub description: &'static str,
+    pub goal: Option<Goal>,
+}
+
+// 時間を積み上げる実績の目標（ゲーム内の秒）と表示の単位
+#[derive(Debug, Clone, Copy)]
+pub struct Goal {
+    pub seconds: f64,
+    pub unit_seconds: f64,
+    pub unit: &'static str,
+}
+
+impl Achievement {
+    pub fn rule(&self) -> &'static AchievementRule {
+        ACHIEVEMENT_RULES
+            .iter()
+            .find(|rule| rule.achievement == *self)
+            .expect("すべての実績にルールがある")
+    }
+
+    pub fn label(&self) -> &'static str {
+        self.rule().label
+    }
+}
+
+// 解除した実績と時刻（実時刻）
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Unlock {
+    pub achievement: Achievement,
+    pub at: DateTime<Local>,
+}
+
+// ご機嫌が続いている時間（猫は Cat::id で指す）
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct Streak {
+    cat: u64,
+    seconds: f64,
+}
+
+// 家族みんなで集める実績
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Achievements {
+    unlocked: Vec<Unlock>,
+    happy: Vec<Streak>,
+    // これまでにいちばん長く一緒に過ごした時間・ご機嫌が続いた時間（秒）
+    longest_seconds: f64,
+    happiest_seconds: f64,
+}
+
+impl Achievements {
+    pub fn unlocked(&self) -> &[Unlock] {
+        &self.unlocked
+    }
+
+    pub fn unlocked_at(&self, achievement: Achievement) -> Option<DateTime<Local>> {
+        self.unlocked
+            .iter()
+            .find(|unlock| unlock.achievement == achievement)
+            .map(|unlock| unlock.at)
+    }
+
+    // 目標のある実績の進み具合（秒、目標で頭打ち）
+    pub fn progress(&self, achievement: Achievement) -> Option<f64> {
+        let goal = achievement.rule().goal?;
+        let seconds = match achievement {
+            Achievement::Survived => self.longest_seconds,
+            Achievement::HappyHour => self.happiest_seconds,
+            Achievement::FirstFeed | Achievement::CalmBath | Achievement::Revived => 0.0,
+        };
+        Some(seconds.min(goal.seconds))
+    }
+
+    // 猫の出来事から解除する
+    pub fn observe(&mut self, event: &CatEvent, at: DateTime<Local>) -> Option<Achievement> {
+        let achievement = match event {
+            CatEvent::Fed => Achievement::FirstFeed,
+            CatEvent::Bathed { calm: true } => Achievement::CalmBath,
+            CatEvent::Revived => Achievement::Revived,
+            _ => return None,
+        };
+        self.unlock(achievement, at)
+    }
+
+    // 時間経過。一緒に過ごした時間とご機嫌が続いた時間を数える
+    // 留守中はご機嫌が続いたか分からないので delta_seconds を 0 にして呼ぶ
+    pub fn step(&mut self, cats: &[Cat], delta_seconds: f64, at: DateTime<Local>) -> Vec<Achievement> {
+        self.happy.retain(|streak| cats.iter().any(|cat| cat.id == streak.cat));
+        for cat in cats.iter().filter(|cat| cat.outcome().is_none()) {
+            self.longest_seconds = self.longest_seconds.max(cat.age_seconds());
+            let index = match self.happy.iter().position(|streak| streak.cat == cat.id) {
+                Some(index) => index,
+                None => {
+                    self.happy.push(Streak { cat: cat.id, seconds: 0.0 });
+                    self.happy.len() - 1
+                }
+            };
+            let streak = &mut self.happy[index];
+            if cat.get_state() == CatState::Happy {
+                streak.seconds += delta_seconds.max(0.0);
+            } else {
+                streak.seconds = 0.0;
+            }
+            self.happiest_seconds = self.happiest_seconds.max(streak.seconds);
+        }
+        let mut unlocked = Vec::new();
+        for rule in ACHIEVEMENT_RULES {
+            if let (Some(goal), Some(seconds)) = (rule.goal, self.progress(rule.achievement))
+                && seconds >= goal.seconds
+                && let Some(achievement) = self.unlock(rule.achievement, at)
+            {
+                unlocked.push(achievement);
+            }
+        }
+        unlocked
+    }
+
+    // まだ解除していなければ解除する
+    fn unlock(&mut self, achievement: Achievement, at: DateTime<Local>) -> Option<Achievement> {
+        if self.unlocked_at(achievement).is_some() {
+            return None;
+        }
+        self.unlocked.push(Unlock { achievement, at });
+        Some(achievement)
+    }
+}
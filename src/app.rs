@@ -1,87 +1,1043 @@
 use chrono::{DateTime, Local};
 use crossterm::event::{self, Event, KeyCode};
+use rand::{Rng, RngCore};
+use std::rc::Rc;
 use std::time::Duration;
 
-use crate::cat::Cat;
+use crate::achievements::{Achievement, Achievements};
+use crate::away::{self, AwaySummary};
+use crate::behavior::Behavior;
+use crate::cat::{Cat, CatEvent};
+use crate::clock::{self, Clock, ScaledClock, SharedClock};
+use crate::coat::Coat;
+use crate::dice::Dice;
+use crate::difficulty::Difficulty;
+use crate::events;
+use crate::items::{Category, Inventory, ItemKind};
+use crate::life::LifeStage;
+use crate::memorial::MemorialEntry;
+use crate::personality::Personality;
+use crate::quests::{Quest, QuestBoard};
+use crate::relations::{Happening, Interaction, Relations};
+use crate::shop::{Cosmetic, ShopEntry};
+use crate::wallet::Earning;
+use crate::weight::Build;
+use crate::rules::Rules;
+use crate::save::SaveData;
+
+// [f] キーで切り替える速度
+const SPEEDS: [f64; 3] = [1.0, 10.0, 60.0];
+
+// お知らせを表示する時間（実時間の秒数）
+const NOTICE_SECONDS: i64 = 8;
+
+// 実績解除・クエスト達成のトーストを表示する時間（実時間の秒数）
+const TOAST_SECONDS: i64 = 6;
+
+// 猫どうしの関わり合いを並べて表示する時間（実時間の秒数）
+const SCENE_SECONDS: i64 = 10;
+
+// 絆が深まった猫が選択肢のカーソルへ1行歩く時間（実時間の秒数）
+const FOLLOW_STEP_SECONDS: f64 = 0.15;
+
+// 新しい猫の名前の最大文字数
+const NAME_MAX_CHARS: usize = 12;
+
+// 一緒に暮らせる猫の数
+pub const MAX_CATS: usize = 4;
+
+// 一度に生まれる子猫の最大数（家族の空きまで）
+const MAX_LITTER: usize = 3;
+
+const DEFAULT_NAME: &str = "ネコ";
+
+// 表示中の画面
+#[derive(Debug, Clone, PartialEq)]
+pub enum Screen {
+    Main,
+    // お別れした猫の一生のまとめ
+    GameOver,
+    // 思い出（お別れした猫の一覧）
+    Memorial,
+    // 猫のプロフィール（性格など）
+    Profile,
+    // 新しい猫の名前を入力中（replace なら選択中の猫と入れ替える）
+    // 最後の1匹と入れ替えるときは最初からやり直すので、difficulty で難易度も選び直せる
+    Adopt { name: String, replace: bool, difficulty: Option<Difficulty> },
+    // あげるごはん・遊ぶおもちゃを選択中
+    Picker { category: Category, selected: usize },
+    // ショップ
+    Shop { selected: usize },
+    // 子猫を産む相手を選択中
+    Litter { selected: usize },
+    // 実績の一覧
+    Achievements,
+    // 今日のクエスト
+    Quests,
+}
+
+// 一定時間だけ表示するお知らせ
+#[derive(Debug, Clone)]
+pub struct Notice {
+    pub text: String,
+    until: DateTime<Local>,
+}
+
+// 猫のパネルに2匹並べて表示する関わり合い
+#[derive(Debug, Clone)]
+pub struct Scene {
+    pub happening: Happening,
+    until: DateTime<Local>,
+}
 
 pub struct App {
-    pub cat: Cat,
+    // 一緒に暮らしている猫たち（1匹以上）と、選択中の猫
+    pub cats: Vec<Cat>,
+    pub focus: usize,
     pub should_quit: bool,
+    pub screen: Screen,
+    pub memorial: Vec<MemorialEntry>,
+    pub inventory: Inventory,
+    pub coins: u32,
+    // 買った着せかえ
+    pub wardrobe: Vec<Cosmetic>,
+    // 猫どうしの関係と、表示中の関わり合い
+    pub relations: Relations,
+    pub scene: Option<Scene>,
+    // 集めた実績と、実績解除・クエスト達成のトースト
+    pub achievements: Achievements,
+    pub toast: Option<Notice>,
+    // 今日のクエスト
+    pub quests: QuestBoard,
+    // 留守中の様子（閉じるまで表示）
+    pub away: Option<AwaySummary>,
+    pub notice: Option<Notice>,
+    // 倍速・一時停止のため元の時計を包んで使う
+    clock: Rc<ScaledClock>,
     last_update: DateTime<Local>,
+    last_real_update: DateTime<Local>,
+    // 一時停止前の速度
+    paused_speed: Option<f64>,
+    // 選択肢の画面で、カーソルを追いかけて歩く猫のいる行と、次の1歩までに歩いた時間
+    pub follower: usize,
+    follow_seconds: f64,
+    // 新しい猫の種や偶然の出来事を決める乱数（--seed があればその種から。セーブされる）
+    pub dice: Dice,
+    // 新しいゲームで選んだ難易度（猫たちのゲームバランスに反映済み）
+    pub difficulty: Difficulty,
+    // 難易度をかける前のゲームバランス（--rules の設定）
+    rules: Rc<Rules>,
     #[cfg(debug_assertions)]
     pub show_debug: bool,
 }
 
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl App {
     pub fn new() -> Self {
+        Self::with_cat(Cat::new(DEFAULT_NAME.to_string()))
+    }
+
+    pub fn with_cat(cat: Cat) -> Self {
+        Self::with_clock(cat, clock::real())
+    }
+
+    // 猫とアプリで同じ時計を使う
+    pub fn with_clock(mut cat: Cat, clock: SharedClock) -> Self {
+        let clock = Rc::new(ScaledClock::new(clock, 1.0));
+        cat.set_clock(clock.clone());
         Self {
-            cat: Cat::new("ネコ".to_string()),
+            screen: Self::home_screen(&cat),
+            coins: cat.rules().coins.start,
+            rules: cat.rules(),
+            cats: vec![cat],
+            focus: 0,
             should_quit: false,
-            last_update: Local::now(),
+            memorial: Vec::new(),
+            inventory: Inventory::default(),
+            wardrobe: Vec::new(),
+            relations: Relations::default(),
+            scene: None,
+            achievements: Achievements::default(),
+            toast: None,
+            quests: QuestBoard::default(),
+            away: None,
+            notice: None,
+            last_update: clock.now(),
+            last_real_update: clock.inner_now(),
+            paused_speed: None,
+            follower: 0,
+            follow_seconds: 0.0,
+            dice: Dice::random(),
+            difficulty: Difficulty::default(),
+            clock,
             #[cfg(debug_assertions)]
             show_debug: false,
         }
     }
 
+    // 新しいゲーム。rules に難易度を反映して使う
+    // 乱数は seed（無ければランダム）から始め、最初の猫の性格と毛並みもその乱数から別々に引く
+    pub fn new_game(clock: SharedClock, rules: Rc<Rules>, seed: Option<u64>, difficulty: Difficulty) -> Self {
+        let mut dice = Dice::new(seed.unwrap_or_else(rand::random));
+        let mut cat = Cat::new(DEFAULT_NAME.to_string());
+        cat.set_rules(Rc::new(difficulty.apply(&rules)));
+        cat.id = dice.next_u64();
+        cat.personality = Personality::generate(dice.next_u64());
+        cat.coat = Coat::generate(dice.next_u64());
+        let mut app = Self::with_clock(cat, clock);
+        app.dice = dice;
+        app.difficulty = difficulty;
+        app.rules = rules;
+        app
+    }
+
+    // セーブデータから復元（無ければふつうの難易度で新しいゲーム）。閉じていた間の時間も進める
+    // seed があれば乱数をその種からやり直す（無ければセーブされた続きから）
+    pub fn from_save(data: Option<SaveData>, clock: SharedClock, rules: Rc<Rules>, seed: Option<u64>) -> Self {
+        let Some(data) = data else {
+            return Self::new_game(clock, rules, seed, Difficulty::default());
+        };
+        let mut cats = data.cats.into_iter();
+        let Some(first) = cats.next() else {
+            return Self::new_game(clock, rules, seed, data.difficulty);
+        };
+        let base = rules;
+        let rules = Rc::new(data.difficulty.apply(&base));
+        let mut app = Self::with_clock(first, clock);
+        app.rules = base;
+        app.cats.extend(cats);
+        app.dice = seed.map(Dice::new).unwrap_or(data.dice);
+        for cat in &mut app.cats {
+            cat.set_rules(rules.clone());
+            cat.set_clock(app.clock.clone());
+            if cat.id == 0 {
+                cat.id = app.dice.next_u64();
+            }
+        }
+        app.focus = data.focus.min(app.cats.len() - 1);
+        app.screen = Self::home_screen(app.cat());
+        app.memorial = data.memorial;
+        app.inventory = data.inventory;
+        app.coins = data.coins;
+        app.wardrobe = data.wardrobe;
+        app.relations = data.relations;
+        app.relations.retain(&app.cats);
+        app.achievements = data.achievements;
+        app.quests = data.quests;
+        app.difficulty = data.difficulty;
+        app.catch_up(data.saved_at, app.last_update);
+        app.handle_cat_events();
+        if app.cat_mut().greet() {
+            app.notify(Behavior::Greet.message(&app.cat().name));
+        }
+        app
+    }
+
+    pub fn to_save(&self) -> SaveData {
+        // 倍速で先に進んだ時刻を実時刻に戻して保存する
+        let real_now = self.clock.inner_now();
+        let cats = self
+            .cats
+            .iter()
+            .map(|cat| {
+                let mut cat = cat.clone();
+                cat.shift_timestamps(self.real_offset());
+                cat
+            })
+            .collect();
+        SaveData {
+            saved_at: real_now,
+            cats,
+            focus: self.focus,
+            memorial: self.memorial.clone(),
+            inventory: self.inventory.clone(),
+            coins: self.coins,
+            wardrobe: self.wardrobe.clone(),
+            relations: self.relations.clone(),
+            achievements: self.achievements.clone(),
+            quests: self.quests.clone(),
+            dice: self.dice.clone(),
+            difficulty: self.difficulty,
+        }
+    }
+
+    // 選択中の猫（お世話はこの猫にだけ届く）
+    pub fn cat(&self) -> &Cat {
+        &self.cats[self.focus]
+    }
+
+    pub fn cat_mut(&mut self) -> &mut Cat {
+        &mut self.cats[self.focus]
+    }
+
+    pub fn cat_by_id(&self, id: u64) -> Option<&Cat> {
+        self.cats.iter().find(|cat| cat.id == id)
+    }
+
+    // 選択中の猫が関わっている関わり合い（仕掛けた猫, 相手）
+    pub fn scene(&self) -> Option<(&Cat, &Cat, Interaction)> {
+        let happening = self.scene.as_ref()?.happening;
+        if happening.actor != self.cat().id && happening.partner != self.cat().id {
+            return None;
+        }
+        Some((self.cat_by_id(happening.actor)?, self.cat_by_id(happening.partner)?, happening.interaction))
+    }
+
+    // Tab で次の猫、Shift+Tab で前の猫
+    pub fn focus_next(&mut self) {
+        self.focus_on((self.focus + 1) % self.cats.len());
+    }
+
+    pub fn focus_previous(&mut self) {
+        self.focus_on((self.focus + self.cats.len() - 1) % self.cats.len());
+    }
+
+    fn focus_on(&mut self, index: usize) {
+        self.focus = index;
+        self.screen = Self::home_screen(self.cat());
+    }
+
+    // 倍速で進んだ時刻と実時刻の差
+    fn real_offset(&self) -> chrono::Duration {
+        self.clock.inner_now() - self.clock.now()
+    }
+
+    // お別れした猫を思い出に残す（時刻は実時刻に戻す）
+    fn remember(&mut self, index: usize) {
+        let mut cat = self.cats[index].clone();
+        cat.shift_timestamps(self.real_offset());
+        if let Some(entry) = MemorialEntry::of(&cat) {
+            self.memorial.push(entry);
+        }
+    }
+
+    // 新しい猫を迎える。replace ならお別れした（家出中の）猫と入れ替え、そうでなければ家族に加える
+    // 家出中の猫は近所の家の子として思い出に残す
+    pub fn adopt(&mut self, name: String, replace: bool) {
+        let mut cat = Cat::with_clock(name, self.clock.clone());
+        cat.set_rules(self.cat().rules());
+        cat.id = self.dice.next_u64();
+        cat.personality = Personality::generate(self.dice.next_u64());
+        cat.coat = Coat::generate(self.dice.next_u64());
+        if replace {
+            if self.cat().has_run_away() {
+                self.remember(self.focus);
+            }
+            *self.cat_mut() = cat;
+            self.relations.retain(&self.cats);
+        } else if self.cats.len() < MAX_CATS {
+            self.cats.push(cat);
+            self.focus = self.cats.len() - 1;
+        }
+        self.screen = Screen::Main;
+        self.notify(format!("🐾 {}を家族に迎えました", self.cat().name));
+    }
+
+    // 家族を増やせるなら名前の入力へ
+    fn start_adoption(&mut self) {
+        if self.cats.len() >= MAX_CATS {
+            self.notify(format!("一緒に暮らせるのは{}匹までです", MAX_CATS));
+            return;
+        }
+        self.screen = Screen::Adopt { name: String::new(), replace: false, difficulty: None };
+    }
+
+    // 選択中の猫と入れ替える猫の名前の入力へ。最後の1匹ならやり直しなので難易度も選べる
+    fn start_replacement(&mut self) {
+        let difficulty = (self.cats.len() == 1).then_some(self.difficulty);
+        self.screen = Screen::Adopt { name: String::new(), replace: true, difficulty };
+    }
+
+    // 難易度を変えて、家族の猫すべてのゲームバランスに反映する
+    fn set_difficulty(&mut self, difficulty: Difficulty) {
+        self.difficulty = difficulty;
+        let rules = Rc::new(difficulty.apply(&self.rules));
+        for cat in &mut self.cats {
+            cat.set_rules(rules.clone());
+        }
+    }
+
+    // 子猫の親になれる猫（元気な大人）
+    fn can_parent(cat: &Cat) -> bool {
+        cat.outcome().is_none() && cat.life_stage() != LifeStage::Kitten
+    }
+
+    // 選択中の猫と子猫を産める相手
+    pub fn partners(&self) -> Vec<usize> {
+        (0..self.cats.len())
+            .filter(|&index| index != self.focus && Self::can_parent(&self.cats[index]))
+            .collect()
+    }
+
+    fn start_litter(&mut self) {
+        if self.cats.len() >= MAX_CATS {
+            self.notify(format!("一緒に暮らせるのは{}匹までです", MAX_CATS));
+        } else if !Self::can_parent(self.cat()) {
+            self.notify("子猫を産めるのは元気な大人の猫だけです".to_string());
+        } else if self.partners().is_empty() {
+            self.notify("相手になる大人の猫がいません".to_string());
+        } else {
+            self.screen = Screen::Litter { selected: 0 };
+        }
+    }
+
+    // 選択中の猫と partner の子猫が生まれる。毛並みは両親から受け継ぎ、同じ種なら同じ子猫たちになる
+    pub fn litter(&mut self, partner: usize) {
+        let count = self.dice.gen_range(1..=MAX_LITTER).min(MAX_CATS - self.cats.len());
+        let (mother, father) = (self.cat(), &self.cats[partner]);
+        let parents = [mother.name.clone(), father.name.clone()];
+        let (mother_coat, father_coat) = (mother.coat, father.coat);
+        let rules = mother.rules();
+        for number in 1..=count {
+            let mut kitten = Cat::with_clock(format!("{}の子{}", parents[0], number), self.clock.clone());
+            kitten.set_rules(rules.clone());
+            kitten.id = self.dice.next_u64();
+            kitten.personality = Personality::generate(self.dice.next_u64());
+            kitten.coat = Coat::inherit(&mother_coat, &father_coat, self.dice.next_u64());
+            kitten.parents = Some(parents.clone());
+            self.cats.push(kitten);
+        }
+        self.screen = Screen::Main;
+        self.notify(format!("🍼 {}と{}の子猫が{}匹生まれました！", parents[0], parents[1], count));
+    }
+
+    // お別れした猫を家から見送り、ほかの猫のところへ戻る（最後の1匹なら新しい猫を迎えるしかない）
+    fn farewell(&mut self) {
+        if self.cats.len() <= 1 || !self.cat().is_dead() {
+            return;
+        }
+        self.cats.remove(self.focus);
+        self.relations.retain(&self.cats);
+        self.focus_on(self.focus.min(self.cats.len() - 1));
+    }
+
+    // None は在庫の要らない選択肢（手で遊ぶ・ありあわせのごはん）
+    pub fn picker_options(category: Category) -> Vec<Option<ItemKind>> {
+        let items = ItemKind::of(category).map(Some);
+        match category {
+            Category::Toy => std::iter::once(None).chain(items).collect(),
+            Category::Food => items.chain(std::iter::once(None)).collect(),
+            Category::Medicine => items.collect(),
+        }
+    }
+
+    fn open_picker(&mut self, category: Category) {
+        self.screen = Screen::Picker { category, selected: 0 };
+    }
+
+    // 選んだごはん・おもちゃを使う。在庫が無ければ選択画面のまま
+    pub fn use_item(&mut self, category: Category, item: Option<ItemKind>) {
+        if !self.cat().can_perform_action() {
+            self.screen = Screen::Main;
+            return;
+        }
+        let Some(kind) = item else {
+            let fed = category == Category::Food;
+            if fed {
+                self.cat_mut().feed_with(None);
+                self.notify(format!("{}にありあわせのごはんをあげました", self.cat().name));
+            } else {
+                self.cat_mut().play();
+            }
+            self.screen = Screen::Main;
+            self.after_care(fed);
+            return;
+        };
+        if !self.inventory.consume(kind) {
+            self.notify(format!("{}がありません", kind.label()));
+            return;
+        }
+        match kind.category() {
+            Category::Food => {
+                self.cat_mut().feed_item(kind);
+                self.notify(format!("{}に{}をあげました", self.cat().name, kind.label()));
+                self.after_care(true);
+            }
+            Category::Toy => {
+                self.cat_mut().play_with(Some(kind));
+                self.notify(format!("{}で遊びました", kind.label()));
+                self.after_care(false);
+            }
+            Category::Medicine => {
+                self.cat_mut().medicate();
+                self.notify(format!("{}に薬を飲ませました", self.cat().name));
+                // 治った・和らいだお知らせをすぐに出す
+                self.handle_cat_events();
+                self.after_care(false);
+            }
+        }
+        self.screen = Screen::Main;
+    }
+
+    // お世話のあとのほかの猫の反応（餌ならごはんの取り合い、構いすぎればやきもち）
+    fn after_care(&mut self, fed: bool) {
+        let rules = self.cat().rules();
+        let mut happenings = Vec::new();
+        if fed {
+            happenings.extend(self.relations.fed(&mut self.cats, self.focus, &rules.relations));
+        }
+        happenings.extend(self.relations.cared(&mut self.cats, self.focus, &rules.relations));
+        self.show_happenings(happenings);
+    }
+
+    // 時間経過による猫どうしの関わり合い
+    fn relate(&mut self, delta_seconds: f64) {
+        let rules = self.cat().rules();
+        let happenings = self.relations.step(&mut self.cats, delta_seconds, &rules.relations);
+        self.show_happenings(happenings);
+    }
+
+    fn show_happenings(&mut self, happenings: Vec<Happening>) {
+        for happening in happenings {
+            let (Some(actor), Some(partner)) = (self.cat_by_id(happening.actor), self.cat_by_id(happening.partner))
+            else {
+                continue;
+            };
+            let text = happening.interaction.message(&actor.name, &partner.name);
+            self.notify(text);
+            self.scene = Some(Scene {
+                happening,
+                until: self.clock.inner_now() + chrono::Duration::seconds(SCENE_SECONDS),
+            });
+        }
+    }
+
+    // 買う。着せかえは持っていれば付け外しする
+    pub fn buy(&mut self, entry: ShopEntry) {
+        if let ShopEntry::Cosmetic(cosmetic) = entry
+            && self.wardrobe.contains(&cosmetic)
+        {
+            self.toggle_accessory(cosmetic);
+            return;
+        }
+        let price = entry.price(&self.cat().rules().shop);
+        if self.coins < price {
+            self.notify(format!("コインが足りません（{}コイン必要）", price));
+            return;
+        }
+        self.coins -= price;
+        match entry {
+            ShopEntry::Item(kind) => self.inventory.add(kind, 1),
+            ShopEntry::Cosmetic(cosmetic) => {
+                self.wardrobe.push(cosmetic);
+                self.cat_mut().accessory = Some(cosmetic);
+            }
+        }
+        self.notify(format!("{}を買いました（-{}コイン）", entry.label(), price));
+    }
+
+    fn toggle_accessory(&mut self, cosmetic: Cosmetic) {
+        if self.cat_mut().accessory == Some(cosmetic) {
+            self.cat_mut().accessory = None;
+            self.notify(format!("{}を外しました", cosmetic.label()));
+        } else {
+            self.cat_mut().accessory = Some(cosmetic);
+            self.notify(format!("{}に{}を着けました", self.cat().name, cosmetic.label()));
+        }
+    }
+
+    // 動物病院へ連れて行く。病気のときだけで、費用がかかる
+    pub fn visit_vet(&mut self) {
+        if self.cat().is_at_vet() {
+            self.notify(format!("{}は診察中です", self.cat().name));
+            return;
+        }
+        if self.cat().illness().is_none() {
+            self.notify(format!("{}は病気ではありません", self.cat().name));
+            return;
+        }
+        if !self.cat().can_perform_action() {
+            return;
+        }
+        let rules = self.cat().rules();
+        let price = rules.illness.vet_price;
+        if self.coins < price {
+            self.notify(format!("コインが足りません（診察代{}コイン）", price));
+            return;
+        }
+        if self.cat_mut().visit_vet() {
+            self.coins -= price;
+            self.notify(format!(
+                "🏥 {}を動物病院へ連れて行きました（-{}コイン、{}分で戻ります）",
+                self.cat().name, price, rules.illness.vet_minutes
+            ));
+        }
+    }
+
+    // 家出した猫を呼ぶ。帰ってきたときのお知らせは CatEvent::Returned で出す
+    pub fn coax(&mut self) {
+        if !self.cat_mut().coax() {
+            let remaining = self.cat().coax_remaining();
+            self.notify(format!("{}を呼んでいます…（あと{}回）", self.cat().name, remaining));
+        }
+    }
+
+    // 選択肢のある画面で選んでいる行
+    pub fn selected(&self) -> Option<usize> {
+        match self.screen {
+            Screen::Picker { selected, .. } | Screen::Shop { selected } | Screen::Litter { selected } => Some(selected),
+            _ => None,
+        }
+    }
+
+    // 選択肢の画面では、カーソルのある行へ1行ずつ歩いていく。ほかの画面では先頭で待つ
+    fn follow(&mut self, real_seconds: f64) {
+        let Some(target) = self.selected() else {
+            self.follower = 0;
+            self.follow_seconds = 0.0;
+            return;
+        };
+        self.follow_seconds += real_seconds.max(0.0);
+        while self.follower != target && self.follow_seconds >= FOLLOW_STEP_SECONDS {
+            self.follow_seconds -= FOLLOW_STEP_SECONDS;
+            if self.follower < target {
+                self.follower += 1;
+            } else {
+                self.follower -= 1;
+            }
+        }
+        if self.follower == target {
+            self.follow_seconds = 0.0;
+        }
+    }
+
+    // 思い出や名前入力から戻る先
+    fn home_screen(cat: &Cat) -> Screen {
+        if cat.is_dead() {
+            Screen::GameOver
+        } else {
+            Screen::Main
+        }
+    }
+
     pub fn tick(&mut self) {
-        let now = Local::now();
+        let now = self.clock.now();
+        let real_now = self.clock.inner_now();
         let delta = now.signed_duration_since(self.last_update);
         let delta_seconds = delta.num_milliseconds() as f64 / 1000.0;
-        
-        self.cat.update(delta_seconds);
+        let real_gap = real_now.signed_duration_since(self.last_real_update);
+
+        // サスペンドやスリープ復帰で大きく時間が飛んだ場合は留守中として扱う
+        if real_gap.num_seconds() as f64 > away::AWAY_THRESHOLD_SECONDS {
+            self.catch_up(self.last_update, now);
+        } else if delta_seconds > away::STEP_SECONDS as f64 {
+            // 高倍速では1回の更新が長くなるので小刻みに進める（途中で通った状態もクエストに数える）
+            for cat in &mut self.cats {
+                let caught_up = away::catch_up(cat, self.last_update, now);
+                self.quests.pass(cat, &caught_up.states);
+                cat.update(0.0);
+            }
+            self.relate(delta_seconds);
+            self.happen(delta_seconds);
+            self.achieve(delta_seconds);
+            self.pursue(delta_seconds);
+        } else {
+            for cat in &mut self.cats {
+                cat.update(delta_seconds);
+            }
+            self.relate(delta_seconds);
+            self.happen(delta_seconds);
+            self.achieve(delta_seconds);
+            self.pursue(delta_seconds);
+        }
         self.last_update = now;
+        self.last_real_update = real_now;
+        self.follow(real_gap.num_milliseconds() as f64 / 1000.0);
+
+        self.handle_cat_events();
+        if self.notice.as_ref().is_some_and(|notice| real_now >= notice.until) {
+            self.notice = None;
+        }
+        if self.scene.as_ref().is_some_and(|scene| real_now >= scene.until) {
+            self.scene = None;
+        }
+        if self.toast.as_ref().is_some_and(|toast| real_now >= toast.until) {
+            self.toast = None;
+        }
+    }
+
+    // どの猫の出来事も名前つきでお知らせする
+    fn handle_cat_events(&mut self) {
+        for index in 0..self.cats.len() {
+            self.handle_events_of(index);
+        }
+    }
+
+    fn handle_events_of(&mut self, index: usize) {
+        let name = self.cats[index].name.clone();
+        for event in self.cats[index].take_events() {
+            if let Some(achievement) = self.achievements.observe(&event, self.clock.inner_now()) {
+                self.celebrate(vec![achievement]);
+            }
+            let done = self.quests.observe(&event, self.clock.now(), &self.cat().rules().quests);
+            self.reward(done);
+            match event {
+                CatEvent::StageUp(stage) => {
+                    self.notify(format!("🎉 {}が{}になりました！", name, stage.label()));
+                }
+                CatEvent::Died(_) => {
+                    self.remember(index);
+                    self.focus = index;
+                    self.screen = Screen::GameOver;
+                }
+                CatEvent::RanAway => {
+                    self.notify(format!("{}は近所の家へ家出してしまいました… [c] で呼び戻せます", name));
+                }
+                CatEvent::Returned => {
+                    self.notify(format!("🏠 {}が帰ってきました！", name));
+                }
+                CatEvent::Earned(earning) => {
+                    self.coins += earning.coins();
+                    if let Earning::Streak { hours, coins } = earning {
+                        self.notify(format!("🔥 {}時間お世話が続きました！ +{}コイン", hours, coins));
+                    }
+                }
+                CatEvent::FellIll(illness) => {
+                    self.notify(format!("🤒 {}が{}にかかりました… [5] 薬 / [v] 動物病院", name, illness.label()));
+                }
+                CatEvent::Worsened(illness, severity) => {
+                    self.notify(format!("{}の{}が{}なりました", name, illness.label(), severity.worse_label()));
+                }
+                CatEvent::Eased(illness) => {
+                    self.notify(format!("💊 {}が少し和らぎました。重い病気は [v] 動物病院へ", illness.label()));
+                }
+                CatEvent::Cured(illness) => {
+                    self.notify(format!("✨ {}の{}が治りました！", name, illness.label()));
+                }
+                CatEvent::DozedOff => {
+                    self.notify(format!("🌙 {}はうとうと眠ってしまいました", name));
+                }
+                CatEvent::Reshaped(build) => match build {
+                    Build::Chubby => self.notify(format!("⚖️ {}が太りすぎになりました。遊んで運動させましょう", name)),
+                    Build::Skinny => self.notify(format!("⚖️ {}が痩せすぎになりました。しっかり食べさせましょう", name)),
+                    Build::Normal => self.notify(format!("⚖️ {}が標準の体型に戻りました", name)),
+                },
+                CatEvent::Bonded(perk) => {
+                    self.notify(perk.message(&name));
+                }
+                CatEvent::Behaved(behavior) => {
+                    self.notify(behavior.message(&name));
+                }
+                CatEvent::Encountered(event) => {
+                    self.notify(event.message(&name));
+                }
+                CatEvent::Revived => {
+                    self.notify(format!("💪 {}が瀕死から持ち直しました！", name));
+                }
+                CatEvent::Fed | CatEvent::Played | CatEvent::Bathed { .. } => {}
+            }
+        }
+    }
+
+    // 眠っている猫を起こす
+    fn wake(&mut self) {
+        let cost = self.cat().rules().sleep.wake_happiness;
+        if self.cat_mut().wake() {
+            self.notify(format!("⏰ {}を起こしました（幸福度-{}）", self.cat().name, cost));
+        }
+    }
+
+    // 眠っている猫にはお世話できず、眠りが浅くなるだけ
+    fn disturb(&mut self) {
+        let name = self.cat().name.clone();
+        if self.cat_mut().disturb() {
+            self.notify(format!("💤 {}は眠っています。起こすなら [4]（眠りが浅くなりました）", name));
+        } else {
+            self.notify(format!("💤 {}は眠っています。起こすなら [4]", name));
+        }
+    }
+
+    // 見ている間だけ偶然の出来事が起きる（留守中は起きない）
+    // 騒がしい出来事は、眠っているほかの猫の眠りを浅くする
+    fn happen(&mut self, delta_seconds: f64) {
+        let rules = self.cat().rules();
+        let mut noisy = false;
+        for cat in &mut self.cats {
+            if let Some(event) = events::roll(cat, &rules.events, delta_seconds / 60.0, &mut self.dice) {
+                cat.encounter(event);
+                noisy |= event.rule().noisy;
+            }
+        }
+        if noisy {
+            for cat in &mut self.cats {
+                cat.disturb();
+            }
+        }
+    }
+
+    // 時間経過で解除する実績
+    fn achieve(&mut self, delta_seconds: f64) {
+        let unlocked = self.achievements.step(&self.cats, delta_seconds, self.clock.inner_now());
+        self.celebrate(unlocked);
+    }
+
+    // 解除した実績をトーストで知らせる（同時に解除したものはまとめて）
+    fn celebrate(&mut self, unlocked: Vec<Achievement>) {
+        if unlocked.is_empty() {
+            return;
+        }
+        let labels: Vec<&str> = unlocked.iter().map(|achievement| achievement.label()).collect();
+        self.toast(format!("🏆 実績解除: {}", labels.join("、")));
+    }
+
+    // 同じ瞬間に出したトーストは並べて表示する
+    fn toast(&mut self, text: String) {
+        let until = self.clock.inner_now() + chrono::Duration::seconds(TOAST_SECONDS);
+        match &mut self.toast {
+            Some(toast) if toast.until == until => toast.text = format!("{} / {}", toast.text, text),
+            _ => self.toast = Some(Notice { text, until }),
+        }
+    }
+
+    // 時間経過で進むクエスト（日付はゲーム内の時刻で数える）
+    fn pursue(&mut self, delta_seconds: f64) {
+        let done = self.quests.step(&self.cats, delta_seconds, self.clock.now(), &self.cat().rules().quests);
+        self.reward(done);
+    }
+
+    // 達成したクエストのコインを渡す
+    fn reward(&mut self, done: Vec<Quest>) {
+        let reward = self.cat().rules().quests.reward;
+        for quest in done {
+            self.coins += reward;
+            self.toast(format!("📜 クエスト達成: {}（+{}コイン）", quest.goal.label(), reward));
+        }
+    }
+
+    // 倍速中でも読めるよう実時間で表示時間を数える
+    pub fn notify(&mut self, text: String) {
+        self.notice = Some(Notice {
+            text,
+            until: self.clock.inner_now() + chrono::Duration::seconds(NOTICE_SECONDS),
+        });
+    }
+
+    pub fn speed(&self) -> f64 {
+        self.clock.speed()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_speed.is_some()
+    }
+
+    pub fn set_speed(&mut self, speed: f64) {
+        self.paused_speed = None;
+        self.clock.set_speed(speed);
+    }
+
+    // 1倍 → 10倍 → 60倍 → 1倍 ...
+    pub fn cycle_speed(&mut self) {
+        let current = self.paused_speed.unwrap_or(self.speed());
+        let next = SPEEDS
+            .iter()
+            .copied()
+            .find(|&speed| speed > current)
+            .unwrap_or(SPEEDS[0]);
+        self.set_speed(next);
+    }
+
+    pub fn toggle_pause(&mut self) {
+        match self.paused_speed.take() {
+            Some(speed) => self.clock.set_speed(speed),
+            None => {
+                self.paused_speed = Some(self.speed());
+                self.clock.set_speed(0.0);
+            }
+        }
+    }
+
+    fn catch_up(&mut self, from: DateTime<Local>, to: DateTime<Local>) {
+        // 留守中の様子は選択中の猫のものを見せる
+        let mut summary = None;
+        for (index, cat) in self.cats.iter_mut().enumerate() {
+            let caught_up = away::catch_up(cat, from, to);
+            if index == self.focus {
+                summary = Some(caught_up);
+            }
+        }
+        self.relate(to.signed_duration_since(from).num_milliseconds() as f64 / 1000.0);
+        // 留守中ご機嫌が続いていたかは分からないので、一緒に過ごした時間だけ数える
+        self.achieve(0.0);
+        self.pursue(0.0);
+        let Some(summary) = summary else {
+            return;
+        };
+        if summary.elapsed.num_seconds() as f64 > away::AWAY_THRESHOLD_SECONDS {
+            self.away = Some(summary);
+        }
     }
 
     pub fn handle_input(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        if event::poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                match key.code {
+        if event::poll(Duration::from_millis(100))?
+            && let Event::Key(key) = event::read()?
+        {
+            self.handle_key(key.code);
+        }
+        Ok(())
+    }
+
+    pub fn handle_key(&mut self, code: KeyCode) {
+        // 留守中の様子を表示している間は何かキーを押すまで操作を受け付けない
+        if self.away.is_some() && code != KeyCode::Char('q') {
+            self.away = None;
+            return;
+        }
+
+        // 子猫を産む相手の候補（画面を借りている間は self を使えないので先に求める）
+        let partners = self.partners();
+        match &mut self.screen {
+            Screen::Main => self.handle_main_key(code),
+            Screen::GameOver => match code {
+                KeyCode::Char('q') => self.should_quit = true,
+                KeyCode::Char('n') => self.start_replacement(),
+                KeyCode::Char('m') => self.screen = Screen::Memorial,
+                KeyCode::Esc => self.farewell(),
+                KeyCode::Tab => self.focus_next(),
+                KeyCode::BackTab => self.focus_previous(),
+                _ => {}
+            },
+            Screen::Memorial => match code {
+                KeyCode::Char('q') => self.should_quit = true,
+                KeyCode::Esc | KeyCode::Char('m') => self.screen = Self::home_screen(self.cat()),
+                _ => {}
+            },
+            Screen::Profile => match code {
+                KeyCode::Char('q') => self.should_quit = true,
+                KeyCode::Esc | KeyCode::Char('p') => self.screen = Screen::Main,
+                _ => {}
+            },
+            Screen::Achievements => match code {
+                KeyCode::Char('q') => self.should_quit = true,
+                KeyCode::Esc | KeyCode::Char('t') => self.screen = Self::home_screen(self.cat()),
+                _ => {}
+            },
+            Screen::Quests => match code {
+                KeyCode::Char('q') => self.should_quit = true,
+                KeyCode::Esc | KeyCode::Char('g') => self.screen = Self::home_screen(self.cat()),
+                _ => {}
+            },
+            Screen::Picker { category, selected } => {
+                let category = *category;
+                let options = Self::picker_options(category);
+                match code {
                     KeyCode::Char('q') => self.should_quit = true,
-                    KeyCode::Char('1') => {
-                        if self.cat.can_perform_action() {
-                            self.cat.feed();
-                        }
-                    }
-                    KeyCode::Char('2') => {
-                        if self.cat.can_perform_action() {
-                            self.cat.play();
-                        }
+                    KeyCode::Up | KeyCode::Char('k') => *selected = selected.saturating_sub(1),
+                    KeyCode::Down | KeyCode::Char('j') => *selected = (*selected + 1).min(options.len() - 1),
+                    KeyCode::Enter | KeyCode::Char(' ') => {
+                        let item = options[*selected];
+                        self.use_item(category, item);
                     }
-                    KeyCode::Char('3') => {
-                        if self.cat.can_perform_action() {
-                            self.cat.bathe();
-                        }
-                    }
-                    KeyCode::Char('4') => {
-                        if self.cat.can_perform_action() {
-                            self.cat.sleep();
-                        }
-                    }
-                    // デバッグキー (デバッグビルドのみ)
-                    #[cfg(debug_assertions)]
-                    KeyCode::Char('d') => {
-                        self.show_debug = true;
-                    }
-                    #[cfg(debug_assertions)]
-                    KeyCode::Char('0') => {
-                        self.cat.set_status_for_test(5, 5, 5, 5); // 瀕死状態
-                    }
-                    #[cfg(debug_assertions)]
-                    KeyCode::Char('8') => {
-                        self.cat.set_status_for_test(15, 50, 50, 15); // 病気状態
+                    KeyCode::Esc => self.screen = Screen::Main,
+                    _ => {}
+                }
+            }
+            Screen::Shop { selected } => {
+                let catalogue = ShopEntry::catalogue();
+                match code {
+                    KeyCode::Char('q') => self.should_quit = true,
+                    KeyCode::Up | KeyCode::Char('k') => *selected = selected.saturating_sub(1),
+                    KeyCode::Down | KeyCode::Char('j') => *selected = (*selected + 1).min(catalogue.len() - 1),
+                    KeyCode::Enter | KeyCode::Char(' ') => {
+                        let entry = catalogue[*selected];
+                        self.buy(entry);
                     }
-                    #[cfg(debug_assertions)]
-                    KeyCode::Char('9') => {
-                        self.cat.set_status_for_test(15, 50, 50, 50); // 空腹状態
+                    KeyCode::Esc | KeyCode::Char('s') => self.screen = Screen::Main,
+                    _ => {}
+                }
+            }
+            Screen::Litter { selected } => match code {
+                KeyCode::Char('q') => self.should_quit = true,
+                KeyCode::Up | KeyCode::Char('k') => *selected = selected.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => *selected = (*selected + 1).min(partners.len().saturating_sub(1)),
+                KeyCode::Enter | KeyCode::Char(' ') => {
+                    if let Some(&partner) = partners.get(*selected) {
+                        self.litter(partner);
                     }
-                    #[cfg(debug_assertions)]
-                    KeyCode::Char('h') => {
-                        self.show_debug = false; // デバッグ情報を非表示
+                }
+                KeyCode::Esc => self.screen = Screen::Main,
+                _ => {}
+            },
+            Screen::Adopt { name, replace, difficulty } => match code {
+                KeyCode::Tab if difficulty.is_some() => *difficulty = difficulty.map(|difficulty| difficulty.next()),
+                KeyCode::Char(c) if name.chars().count() < NAME_MAX_CHARS => name.push(c),
+                KeyCode::Backspace => {
+                    name.pop();
+                }
+                KeyCode::Enter => {
+                    let name = match name.trim() {
+                        "" => DEFAULT_NAME.to_string(),
+                        name => name.to_string(),
+                    };
+                    let replace = *replace;
+                    if let Some(difficulty) = *difficulty {
+                        self.set_difficulty(difficulty);
                     }
-                    _ => {}
+                    self.adopt(name, replace);
                 }
+                KeyCode::Esc => self.screen = Self::home_screen(self.cat()),
+                _ => {}
+            },
+        }
+    }
+
+    fn handle_main_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Char('f') => self.cycle_speed(),
+            KeyCode::Char(' ') => self.toggle_pause(),
+            KeyCode::Char('m') => self.screen = Screen::Memorial,
+            KeyCode::Char('p') => self.screen = Screen::Profile,
+            KeyCode::Char('t') => self.screen = Screen::Achievements,
+            KeyCode::Char('g') => self.screen = Screen::Quests,
+            KeyCode::Char('s') => self.screen = Screen::Shop { selected: 0 },
+            KeyCode::Char('a') => self.start_adoption(),
+            KeyCode::Char('l') => self.start_litter(),
+            KeyCode::Tab => self.focus_next(),
+            KeyCode::BackTab => self.focus_previous(),
+            KeyCode::Char('4') if self.cat().is_sleeping() => self.wake(),
+            KeyCode::Char('1' | '2' | '3' | '5') if self.cat().is_sleeping() => self.disturb(),
+            KeyCode::Char('1') if self.cat().can_perform_action() => self.open_picker(Category::Food),
+            KeyCode::Char('2') if self.cat().can_perform_action() => self.open_picker(Category::Toy),
+            KeyCode::Char('3') if self.cat().can_perform_action() => {
+                self.cat_mut().bathe();
+                self.after_care(false);
+            }
+            KeyCode::Char('4') if self.cat().can_perform_action() => {
+                self.cat_mut().sleep();
+                self.after_care(false);
+            }
+            KeyCode::Char('5') if self.cat().can_perform_action() => self.use_item(Category::Medicine, Some(ItemKind::Medicine)),
+            KeyCode::Char('v') if self.cat().outcome().is_none() => self.visit_vet(),
+            // 家出中: 呼び戻すか、新しい猫を迎える
+            KeyCode::Char('c') if self.cat().has_run_away() => self.coax(),
+            KeyCode::Char('n') if self.cat().has_run_away() => self.start_replacement(),
+            // デバッグキー (デバッグビルドのみ)
+            #[cfg(debug_assertions)]
+            KeyCode::Char('d') => {
+                self.show_debug = true;
+            }
+            #[cfg(debug_assertions)]
+            KeyCode::Char('0') => {
+                self.cat_mut().set_status_for_test(5, 5, 5, 5); // 瀕死状態
             }
+            #[cfg(debug_assertions)]
+            KeyCode::Char('8') => {
+                self.cat_mut().set_status_for_test(15, 50, 50, 15); // 病気状態
+            }
+            #[cfg(debug_assertions)]
+            KeyCode::Char('9') => {
+                self.cat_mut().set_status_for_test(15, 50, 50, 50); // 空腹状態
+            }
+            #[cfg(debug_assertions)]
+            KeyCode::Char('h') => {
+                self.show_debug = false; // デバッグ情報を非表示
+            }
+            _ => {}
         }
-        Ok(())
     }
-}
\ No newline at end of file
+}
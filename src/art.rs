@@ -0,0 +1,395 @@
+use crate::life::LifeStage;
+use crate::weight::Build;
+
+// 猫のASCIIアート（6行×18文字）
+// 状態ごとのアニメーションは目とエフェクト（行6）だけを返し、体は成長段階ごとのテンプレートで組み立てる
+// 胴体（行4・5）は体型で太さが変わる
+
+// 1フレーム分の表情
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression {
+    pub pose: Pose,
+    pub eyes: &'static str,
+    pub effect: String,
+}
+
+// 体の姿勢
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pose {
+    Sitting, // 座り姿勢（成長段階ごとのテンプレート）
+    Angel,   // 天使の輪（お別れ）
+    Absent,  // 空っぽのクッション（家出中）
+}
+
+impl Expression {
+    fn new(eyes: &'static str, effect: impl Into<String>) -> Self {
+        Self::posed(Pose::Sitting, eyes, effect)
+    }
+
+    fn posed(pose: Pose, eyes: &'static str, effect: impl Into<String>) -> Self {
+        Self {
+            pose,
+            eyes,
+            effect: effect.into(),
+        }
+    }
+}
+
+// 状態ごとのアニメーション（フレーム番号 → 表情）
+pub type Animation = fn(usize) -> Expression;
+
+pub fn compose(expression: &Expression, stage: LifeStage, build: Build) -> String {
+    let eyes = expression.eyes;
+    let effect = &expression.effect;
+    match expression.pose {
+        Pose::Sitting => {}
+        Pose::Angel => {
+            return format!(
+                "   .~~~.          \n   /\\_/\\          \n  {}         \n   > ^ <          \n (\\|___|/)        \n{}",
+                eyes, effect
+            );
+        }
+        Pose::Absent => {
+            return format!(
+                "                  \n                  \n                  \n       {}    \n  [__________]    \n{}",
+                eyes, effect
+            );
+        }
+    }
+    let body = body(stage, build);
+    match stage {
+        // 子猫: ひとまわり小さい体
+        LifeStage::Kitten => format!(
+            "                  \n    /\\_/\\         \n   {}        \n{}\n{}",
+            eyes, body, effect
+        ),
+        LifeStage::Adult => format!(
+            "   /\\_/\\          \n  {}         \n   > ^ <     /)   \n{}\n{}",
+            eyes, body, effect
+        ),
+        // シニア猫: 首輪がゆるみ、耳元の毛が白くなる
+        LifeStage::Senior => format!(
+            "   /\\_/\\  ~~      \n  {}         \n   > ~ <     /)   \n{}\n{}",
+            eyes, body, effect
+        ),
+    }
+}
+
+// 胴体の2行（行4・5）。太りすぎは横に広く、痩せすぎは細い
+fn body(stage: LifeStage, build: Build) -> &'static str {
+    match (stage, build) {
+        (LifeStage::Kitten, Build::Normal) => "    (   )  _      \n    (_u_u)/       ",
+        (LifeStage::Kitten, Build::Chubby) => "   (     ) _      \n   (_u___u)/      ",
+        (LifeStage::Kitten, Build::Skinny) => "    ( )    _      \n    (uu)  /       ",
+        (_, Build::Normal) => "  /|   |\\   / /   \n ( |___| ) /_/    ",
+        (_, Build::Chubby) => " /|     |\\  / /   \n(  |_____|  )/_/  ",
+        (_, Build::Skinny) => "   /| |\\    / /   \n  (|_|)    /_/    ",
+    }
+}
+
+// compose で目（7文字）を描く位置（行, 列）
+pub fn eye_position(pose: Pose, stage: LifeStage) -> Option<(usize, usize)> {
+    match (pose, stage) {
+        (Pose::Absent, _) => None,
+        (Pose::Angel, _) => Some((2, 2)),
+        (Pose::Sitting, LifeStage::Kitten) => Some((2, 3)),
+        (Pose::Sitting, LifeStage::Adult | LifeStage::Senior) => Some((1, 2)),
+    }
+}
+
+pub fn normal(frame: usize) -> Expression {
+    let eyes = match frame % 4 {
+        0 => "( o.o )",
+        1 => "( -.o )",
+        2 => "( o.- )",
+        _ => "( o.o )",
+    };
+    Expression::new(eyes, "                  ")
+}
+
+pub fn dying(_frame: usize) -> Expression {
+    Expression::new("( x.x )", "       ...        ")
+}
+
+pub fn hungry(frame: usize) -> Expression {
+    match frame % 4 {
+        0 | 1 => Expression::new("( >.< )", "おなかすいた...   "),
+        _ => Expression::new("( >.< )", "    グゥ～        "),
+    }
+}
+
+pub fn happy(frame: usize) -> Expression {
+    match frame % 4 {
+        0 | 2 => Expression::new("( ^.^ )", "       ♪         "),
+        _ => Expression::new("( ^.^ )", "      ♪♪         "),
+    }
+}
+
+pub fn dirty(frame: usize) -> Expression {
+    let sweat = match frame % 4 {
+        0 => ";;           ",
+        1 => ";;;          ",
+        2 => ";;;;         ",
+        _ => ";;;;;;       ",
+    };
+    Expression::new("( >.< )", format!("    {}", sweat))
+}
+
+pub fn tired(frame: usize) -> Expression {
+    let (eyes, sleep_effect) = match frame % 4 {
+        0 => ("( -.- )", "Zzz          "),
+        1 => ("( _._ )", "zZz          "),
+        2 => ("( -.- )", "zzZ          "),
+        _ => ("( _._ )", "ZzZ          "),
+    };
+    Expression::new(eyes, format!("    {}", sleep_effect))
+}
+
+pub fn unhappy(_frame: usize) -> Expression {
+    Expression::new("( -.~ )", "    ムスッ       ")
+}
+
+pub fn full(_frame: usize) -> Expression {
+    Expression::new("( ^ω^ )", "ごちそうさま♪    ")
+}
+
+pub fn sick(frame: usize) -> Expression {
+    match frame % 2 {
+        0 => Expression::new("( @.@ )", "   ぐったり      "),
+        _ => Expression::new("( x.x )", "   ぐったり      "),
+    }
+}
+
+pub fn sleeping(frame: usize) -> Expression {
+    let (eyes, sleep_effect) = match frame % 4 {
+        0 => ("( -.z )", "Zzz...       "),
+        1 => ("( z.- )", "zzZ...       "),
+        2 => ("( z.z )", "ZZZ...       "),
+        _ => ("( -.z )", "zzz...       "),
+    };
+    Expression::new(eyes, format!("   {}", sleep_effect))
+}
+
+pub fn dead(frame: usize) -> Expression {
+    match frame % 2 {
+        0 => Expression::posed(Pose::Angel, "( -.- )", "   おやすみ...    "),
+        _ => Expression::posed(Pose::Angel, "( -.- )", "   おやすみ.      "),
+    }
+}
+
+// 家出中は猫の代わりに空のクッションと足跡を表示する
+pub fn ran_away(frame: usize) -> Expression {
+    match frame % 4 {
+        0 | 1 => Expression::posed(Pose::Absent, ":. :.  ", "となりの家かな... "),
+        _ => Expression::posed(Pose::Absent, " :. :. ", "    にゃーん?     "),
+    }
+}
+
+// 動物病院に行っている間も猫はいない
+pub fn at_vet(frame: usize) -> Expression {
+    match frame % 4 {
+        0 | 1 => Expression::posed(Pose::Absent, "  +    ", "動物病院で診察中  "),
+        _ => Expression::posed(Pose::Absent, "   +   ", "動物病院で診察中. "),
+    }
+}
+
+pub fn cold(frame: usize) -> Expression {
+    match frame % 4 {
+        0 | 1 => Expression::new("( -.- )", "   ずるずる...    "),
+        2 => Expression::new("( >.< )", "   へっ...        "),
+        _ => Expression::new("( >o< )", "   くしゅん!      "),
+    }
+}
+
+pub fn upset_stomach(frame: usize) -> Expression {
+    match frame % 2 {
+        0 => Expression::new("( @_@ )", "  おなかいたい... "),
+        _ => Expression::new("( @_@ )", "  ぎゅるる...     "),
+    }
+}
+
+pub fn skin_trouble(frame: usize) -> Expression {
+    match frame % 4 {
+        0 | 2 => Expression::new("( >.< )", "   かいかい...    "),
+        1 => Expression::new("( >.< )", "   ｶﾘｶﾘ           "),
+        _ => Expression::new("( -.- )", "   ｶﾘｶﾘｶﾘ         "),
+    }
+}
+
+pub fn hairball(frame: usize) -> Expression {
+    match frame % 4 {
+        0 | 1 => Expression::new("( o.o )", "   ケホッ...      "),
+        2 => Expression::new("( >o< )", "   ケホッ ケホッ  "),
+        _ => Expression::new("( -.- )", "   ...@           "),
+    }
+}
+
+pub fn playful(frame: usize) -> Expression {
+    match frame % 4 {
+        0 | 2 => Expression::new("( o.o )", "   ﾌﾘﾌﾘ~ あそぼ!  "),
+        1 => Expression::new("( O.O )", "   ﾌﾘﾌﾘ~          "),
+        _ => Expression::new("( O.O )", "   ｿﾜｿﾜ...        "),
+    }
+}
+
+// 体型
+pub fn overweight(frame: usize) -> Expression {
+    match frame % 4 {
+        0 | 1 => Expression::new("( -ω- )", "   ふぅ...おもい  "),
+        2 => Expression::new("( -.- )", "   どっこいしょ   "),
+        _ => Expression::new("( -ω- )", "   ごろーん       "),
+    }
+}
+
+pub fn underweight(frame: usize) -> Expression {
+    match frame % 2 {
+        0 => Expression::new("( ;.; )", "  ひょろひょろ... "),
+        _ => Expression::new("( ;.; )", "   ふらふら...    "),
+    }
+}
+
+// ひとりでするふるまい
+pub fn self_grooming(frame: usize) -> Expression {
+    match frame % 4 {
+        0 | 2 => Expression::new("( -.- )", "   ペロペロ       "),
+        1 => Expression::new("( -ω- )", "   ペロ...        "),
+        _ => Expression::new("( ^.- )", "   ふわふわ~      "),
+    }
+}
+
+pub fn napping(frame: usize) -> Expression {
+    match frame % 4 {
+        0 | 1 => Expression::new("( -ω- )", "   すやすや...    "),
+        2 => Expression::new("( -.- )", "   すぴー         "),
+        _ => Expression::new("( -ω- )", "   むにゃ...      "),
+    }
+}
+
+pub fn chasing_tail(frame: usize) -> Expression {
+    match frame % 4 {
+        0 => Expression::new("( O.O )", "   ～S  ぐるぐる  "),
+        1 => Expression::new("( >.< )", "   S～  ぐるぐる  "),
+        2 => Expression::new("( O.O )", "   ～S  まてー!   "),
+        _ => Expression::new("( @.@ )", "   ふらふら...    "),
+    }
+}
+
+pub fn knocking_over(frame: usize) -> Expression {
+    match frame % 4 {
+        0 => Expression::new("( o.o )", "   ﾁﾗｯ   [u]      "),
+        1 => Expression::new("( o.o )", "   ｽｯ  ->[u]      "),
+        2 => Expression::new("( ^.^ )", "   ｶﾞｼｬｰﾝ! \\u/    "),
+        _ => Expression::new("( ^ω^ )", "   ...ﾆﾔﾘ         "),
+    }
+}
+
+pub fn greeting(frame: usize) -> Expression {
+    match frame % 4 {
+        0 | 2 => Expression::new("( ^.^ )", "   おかえり!♪    "),
+        1 => Expression::new("( ^o^ )", "   にゃーん♪     "),
+        _ => Expression::new("( ^ω^ )", "   すりすり~      "),
+    }
+}
+
+// 偶然の出来事
+pub fn gift_mouse(frame: usize) -> Expression {
+    match frame % 4 {
+        0 => Expression::new("( ^.^ )", "   ﾄｺﾄｺ... ~<:3   "),
+        1 => Expression::new("( ^ω^ )", "   はい、どうぞ!  "),
+        2 => Expression::new("( ^.^ )", "   ~<:3  ﾄﾞﾔｯ     "),
+        _ => Expression::new("( ^ω^ )", "   ﾌﾟﾚｾﾞﾝﾄ♪      "),
+    }
+}
+
+pub fn knocked_cup(frame: usize) -> Expression {
+    match frame % 4 {
+        0 => Expression::new("( o.o )", "   ｿｰｯ    [_]     "),
+        1 => Expression::new("( o.o )", "   ﾁｮｲ  ->[_]     "),
+        2 => Expression::new("( O.O )", "   ﾊﾟﾘｰﾝ! \\_/     "),
+        _ => Expression::new("( -.- )", "   しらんぷり...  "),
+    }
+}
+
+pub fn thunderstorm(frame: usize) -> Expression {
+    match frame % 4 {
+        0 => Expression::new("( O.O )", "   ﾋﾟｶｯ!! ///     "),
+        1 => Expression::new("( >.< )", "   ｺﾞﾛｺﾞﾛ...      "),
+        2 => Expression::new("( ;.; )", "   ﾌﾞﾙﾌﾞﾙ...      "),
+        _ => Expression::new("( >.< )", "   こわいよ...    "),
+    }
+}
+
+pub fn sunbeam(frame: usize) -> Expression {
+    match frame % 4 {
+        0 => Expression::new("( -.- )", "   \\|/ ぽかぽか   "),
+        1 => Expression::new("( -ω- )", "   -*- ごろーん   "),
+        2 => Expression::new("( -.- )", "   /|\\ ぬくぬく~  "),
+        _ => Expression::new("( ^ω^ )", "   ひなたぼっこ♪  "),
+    }
+}
+
+// 猫どうしの関わり合い（左が仕掛けた猫、右が相手）
+pub fn playing_left(frame: usize) -> Expression {
+    match frame % 2 {
+        0 => Expression::new("( ^o^ )", "   ≡○ じゃれじゃれ"),
+        _ => Expression::new("( ^.^ )", "     ○≡ ♪        "),
+    }
+}
+
+pub fn playing_right(frame: usize) -> Expression {
+    match frame % 2 {
+        0 => Expression::new("( O.O )", "  ♪              "),
+        _ => Expression::new("( ^o^ )", "  ≡○ ♪♪          "),
+    }
+}
+
+pub fn grooming(frame: usize) -> Expression {
+    match frame % 2 {
+        0 => Expression::new("( -.- )", "   ペロペロ       "),
+        _ => Expression::new("( -ω- )", "   ペロ...        "),
+    }
+}
+
+pub fn groomed(frame: usize) -> Expression {
+    match frame % 2 {
+        0 => Expression::new("( ^.- )", "    ~♡~           "),
+        _ => Expression::new("( -.- )", "   ごろごろ       "),
+    }
+}
+
+pub fn fighting(frame: usize) -> Expression {
+    match frame % 2 {
+        0 => Expression::new("( >o< )", "   シャーッ!      "),
+        _ => Expression::new("( `o´ )", "   ﾊﾞｼｯ ﾊﾞｼｯ       "),
+    }
+}
+
+pub fn defending(frame: usize) -> Expression {
+    match frame % 2 {
+        0 => Expression::new("( `.´ )", "   フーッ!        "),
+        _ => Expression::new("( >.< )", "   ぼくのごはん!  "),
+    }
+}
+
+pub fn sulking(frame: usize) -> Expression {
+    match frame % 2 {
+        0 => Expression::new("( -_- )", "   ぷいっ         "),
+        _ => Expression::new("( ¬_¬ )", "   ...ずるい      "),
+    }
+}
+
+pub fn pampered(frame: usize) -> Expression {
+    match frame % 2 {
+        0 => Expression::new("( ^.^ )", "   ♪              "),
+        _ => Expression::new("( ^ω^ )", "   ♪♪             "),
+    }
+}
+
+// 2匹を横に並べる（どちらも6行）
+pub fn side_by_side(left: &str, right: &str) -> String {
+    left.lines()
+        .zip(right.lines())
+        .map(|(left, right)| format!("{}  {}", left, right))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,98 @@
+use chrono::{DateTime, Duration, Local};
+use std::rc::Rc;
+
+use crate::cat::{Cat, CatState};
+use crate::clock::{format_duration, ManualClock};
+
+// 一度に進める最大時間（秒）。健康度の減少速度切り替えを途中で反映させるため細かく刻む
+pub const STEP_SECONDS: i64 = 10;
+
+// これ以上の空白があれば「留守中の様子」を表示する
+pub const AWAY_THRESHOLD_SECONDS: f64 = 60.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub hunger: i32,
+    pub happiness: i32,
+    pub cleanliness: i32,
+    pub health: i32,
+}
+
+impl Stats {
+    fn of(cat: &Cat) -> Self {
+        Self {
+            hunger: cat.hunger,
+            happiness: cat.happiness,
+            cleanliness: cat.cleanliness,
+            health: cat.health,
+        }
+    }
+}
+
+// 留守中に起きたことのまとめ
+#[derive(Debug, Clone)]
+pub struct AwaySummary {
+    pub elapsed: Duration,
+    pub before: Stats,
+    pub after: Stats,
+    // 通過した状態（連続する重複は除く）
+    pub states: Vec<CatState>,
+    pub woke_up: bool,
+}
+
+impl AwaySummary {
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = vec![format!("{}のあいだ留守にしていました", format_duration(self.elapsed))];
+        lines.push(String::new());
+        lines.push(format_stat("空腹度", self.before.hunger, self.after.hunger));
+        lines.push(format_stat("幸福度", self.before.happiness, self.after.happiness));
+        lines.push(format_stat("清潔度", self.before.cleanliness, self.after.cleanliness));
+        lines.push(format_stat("健康度", self.before.health, self.after.health));
+        lines.push(String::new());
+        let states: Vec<&str> = self.states.iter().map(|state| state.label()).collect();
+        lines.push(format!("様子: {}", states.join(" → ")));
+        if self.woke_up {
+            lines.push("留守中に目を覚ましました".to_string());
+        }
+        lines
+    }
+}
+
+// from から to までの時間を小刻みに進めて猫を追いつかせる
+pub fn catch_up(cat: &mut Cat, from: DateTime<Local>, to: DateTime<Local>) -> AwaySummary {
+    // 途中の時刻で状態を判定できるよう、一時的に手動の時計へ差し替える
+    let original_clock = cat.clock();
+    let clock = Rc::new(ManualClock::new(from));
+    cat.set_clock(clock.clone());
+
+    let before = Stats::of(cat);
+    let was_sleeping = cat.is_sleeping();
+    let mut states = vec![cat.get_state()];
+
+    let mut now = from;
+    while now < to {
+        let step = (to - now).min(Duration::seconds(STEP_SECONDS));
+        now += step;
+        clock.set(now);
+        cat.decay(step.num_milliseconds() as f64 / 1000.0);
+
+        let state = cat.get_state();
+        if states.last() != Some(&state) {
+            states.push(state);
+        }
+    }
+    let woke_up = was_sleeping && !cat.is_sleeping();
+    cat.set_clock(original_clock);
+
+    AwaySummary {
+        elapsed: (to - from).max(Duration::zero()),
+        before,
+        after: Stats::of(cat),
+        states,
+        woke_up,
+    }
+}
+
+fn format_stat(name: &str, before: i32, after: i32) -> String {
+    format!("{}: {:>3} → {:>3} ({:+})", name, before, after, after - before)
+}
@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+
+use crate::art::{self, Animation};
+use crate::cat::Cat;
+use crate::rules::{ActionEffect, BehaviorRules};
+
+// 猫がひとりでにすること。上から順に条件を調べ、最初に当てはまったものをする
+pub const BEHAVIOR_RULES: &[BehaviorRule] = &[
+    // 疲れていると、寝かせなくても丸くなってひと眠りする（寝かせるより回復は少ない）
+    BehaviorRule {
+        behavior: Behavior::Nap,
+        when: |cat, rules| cat.health < rules.nap_health,
+        effect: ActionEffect { hunger: -2.0, happiness: 0.0, cleanliness: 0.0, health: 8.0 },
+        seconds: 300.0,
+        animation: art::napping,
+    },
+    BehaviorRule {
+        behavior: Behavior::Groom,
+        when: |cat, rules| cat.cleanliness < rules.groom_cleanliness,
+        effect: ActionEffect { hunger: 0.0, happiness: 2.0, cleanliness: 8.0, health: 0.0 },
+        seconds: 60.0,
+        animation: art::self_grooming,
+    },
+    // 退屈していると、棚の物を落として気を引こうとする
+    BehaviorRule {
+        behavior: Behavior::KnockOver,
+        when: |cat, rules| cat.happiness < rules.mischief_happiness,
+        effect: ActionEffect { hunger: -1.0, happiness: 6.0, cleanliness: -3.0, health: 0.0 },
+        seconds: 20.0,
+        animation: art::knocking_over,
+    },
+    // 絆が深まった猫が、起動したときに迎えに来る（自分からは選ばない）
+    BehaviorRule {
+        behavior: Behavior::Greet,
+        when: |_, _| false,
+        effect: ActionEffect { hunger: 0.0, happiness: 5.0, cleanliness: 0.0, health: 0.0 },
+        seconds: 20.0,
+        animation: art::greeting,
+    },
+    BehaviorRule {
+        behavior: Behavior::ChaseTail,
+        when: |_, _| true,
+        effect: ActionEffect { hunger: -4.0, happiness: 8.0, cleanliness: -2.0, health: 0.0 },
+        seconds: 30.0,
+        animation: art::chasing_tail,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Behavior {
+    Nap,       // ひと眠り
+    Groom,     // 毛づくろい
+    KnockOver, // 物を落とす
+    ChaseTail, // しっぽを追いかける
+    Greet,     // お出迎え
+}
+
+pub struct BehaviorRule {
+    pub behavior: Behavior,
+    pub when: fn(&Cat, &BehaviorRules) -> bool,
+    // 始めたときのステータスの増減
+    pub effect: ActionEffect,
+    // 続く時間（ゲーム内の秒）
+    pub seconds: f64,
+    pub animation: Animation,
+}
+
+impl Behavior {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Behavior::Nap => "ひと眠り",
+            Behavior::Groom => "毛づくろい",
+            Behavior::KnockOver => "いたずら",
+            Behavior::ChaseTail => "しっぽ追い",
+            Behavior::Greet => "お出迎え",
+        }
+    }
+
+    pub fn rule(&self) -> &'static BehaviorRule {
+        BEHAVIOR_RULES
+            .iter()
+            .find(|rule| rule.behavior == *self)
+            .expect("すべてのふるまいにルールがある")
+    }
+
+    pub fn message(&self, name: &str) -> String {
+        match self {
+            Behavior::Nap => format!("💤 {}は丸くなってひと眠りしています", name),
+            Behavior::Groom => format!("👅 {}が自分で毛づくろいしています", name),
+            Behavior::KnockOver => format!("💥 {}が棚の物を落としました！", name),
+            Behavior::ChaseTail => format!("🌀 {}がしっぽを追いかけてぐるぐる回っています", name),
+            Behavior::Greet => format!("🐈 {}が玄関までお出迎えしてくれました！", name),
+        }
+    }
+}
+
+// 今の様子に合ったふるまい
+pub fn choose(cat: &Cat, rules: &BehaviorRules) -> Behavior {
+    BEHAVIOR_RULES
+        .iter()
+        .find(|rule| (rule.when)(cat, rules))
+        .map(|rule| rule.behavior)
+        .unwrap_or(Behavior::ChaseTail)
+}
+
+// しているふるまいと、終わるまでの時間（秒）
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Activity {
+    pub behavior: Behavior,
+    pub remaining_seconds: f64,
+}
@@ -0,0 +1,116 @@
+use chrono::Duration;
+use crossterm::event::KeyCode;
+use neko_watch::achievements::{Achievement, Achievements, ACHIEVEMENT_RULES};
+use neko_watch::app::{App, Screen};
+use neko_watch::cat::{Cat, CatEvent};
+use neko_watch::clock::{ManualClock, today_at};
+use neko_watch::life::LifeStage;
+use neko_watch::rules::Rules;
+use neko_watch::save::{self, SaveData};
+use std::rc::Rc;
+
+fn main() {
+    println!("=== 実績テスト ===\n");
+
+    // 出来事で解除する
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut app = App::with_clock(Cat::with_clock("ミケ".to_string(), clock.clone()), clock.clone());
+    check("最初は何も解除していない", app.achievements.unlocked().is_empty() && app.toast.is_none());
+    app.cat_mut().feed();
+    app.tick();
+    let toast = app.toast.as_ref().map(|toast| toast.text.clone()).unwrap_or_default();
+    println!("{}", toast);
+    check("はじめて餌をあげると解除", app.achievements.unlocked_at(Achievement::FirstFeed) == Some(today_at(12)));
+    check("トーストで知らせる", toast.contains("はじめてのごはん"));
+    app.cat_mut().feed();
+    app.tick();
+    check("同じ実績は1度だけ", app.achievements.unlocked().len() == 1);
+    clock.advance(Duration::seconds(10));
+    app.tick();
+    check("トーストはしばらくで消える", app.toast.is_none());
+
+    // お風呂: 不機嫌にならなければ解除
+    println!("\n=== お風呂と瀕死 ===");
+    let mut cat = Cat::with_clock("ミケ".to_string(), clock.clone());
+    cat.set_status_for_test(90, 50, 90, 90);
+    cat.bathe();
+    check("不機嫌になったお風呂", cat.take_events().contains(&CatEvent::Bathed { calm: false }));
+    cat.set_status_for_test(90, 90, 90, 90);
+    cat.bathe();
+    let events = cat.take_events();
+    check("不機嫌にならなかったお風呂", events.contains(&CatEvent::Bathed { calm: true }));
+    let mut achievements = Achievements::default();
+    let unlocked: Vec<_> = events.iter().filter_map(|event| achievements.observe(event, today_at(12))).collect();
+    check("お風呂じょうずを解除", unlocked == vec![Achievement::CalmBath]);
+
+    // 瀕死から持ち直す
+    cat.set_status_for_test(5, 90, 90, 90);
+    cat.feed();
+    check("瀕死から持ち直す", cat.take_events().contains(&CatEvent::Revived));
+    cat.set_status_for_test(50, 90, 90, 90);
+    cat.feed();
+    check("瀕死でなければ持ち直さない", !cat.take_events().contains(&CatEvent::Revived));
+    cat.set_status_for_test(50, 90, 90, 5);
+    cat.sleep();
+    cat.decay(120.0);
+    check("寝かせて持ち直す", cat.take_events().contains(&CatEvent::Revived));
+
+    // 時間を積み上げる実績
+    println!("\n=== 進み具合 ===");
+    let mut achievements = Achievements::default();
+    let mut cats = vec![Cat::with_clock("ミケ".to_string(), clock.clone())];
+    check("進み具合は0から", achievements.progress(Achievement::HappyHour) == Some(0.0));
+    check("進み具合の無い実績", achievements.progress(Achievement::FirstFeed).is_none());
+    let mut unlocked = Vec::new();
+    for _ in 0..30 {
+        cats[0].set_status_for_test(90, 90, 90, 90);
+        unlocked.extend(achievements.step(&cats, 60.0, today_at(12)));
+    }
+    check("ご機嫌が30分続いた", achievements.progress(Achievement::HappyHour) == Some(1800.0));
+    cats[0].set_status_for_test(90, 50, 90, 90);
+    achievements.step(&cats, 60.0, today_at(12));
+    check("途切れても最高記録は残る", achievements.progress(Achievement::HappyHour) == Some(1800.0));
+    for _ in 0..60 {
+        cats[0].set_status_for_test(90, 90, 90, 90);
+        unlocked.extend(achievements.step(&cats, 60.0, today_at(12)));
+    }
+    check("1時間続くと解除", unlocked == vec![Achievement::HappyHour]);
+    check("進み具合は目標で止まる", achievements.progress(Achievement::HappyHour) == Some(3600.0));
+
+    cats[0].set_life_stage_for_test(LifeStage::Adult);
+    achievements.step(&cats, 0.0, today_at(12));
+    let hours = achievements.progress(Achievement::Survived).unwrap_or_default() / 3600.0;
+    println!("一緒に過ごした時間: {}時間", hours);
+    check("成猫までの時間を数える", hours == 12.0 && achievements.unlocked_at(Achievement::Survived).is_none());
+    cats[0].set_life_stage_for_test(LifeStage::Senior);
+    check("24時間で解除", achievements.step(&cats, 0.0, today_at(12)) == vec![Achievement::Survived]);
+
+    // 一覧画面
+    println!("\n=== 一覧画面 ===");
+    app.handle_key(KeyCode::Char('t'));
+    check("[t] で実績の一覧", app.screen == Screen::Achievements);
+    app.handle_key(KeyCode::Esc);
+    check("[Esc] で戻る", app.screen == Screen::Main);
+    check("すべての実績にルールがある", ACHIEVEMENT_RULES.len() == 5);
+
+    // 解除した実績と時刻はセーブされる
+    println!("\n=== セーブ ===");
+    let path = std::env::temp_dir().join("neko-watch-test").join("achievements.json");
+    save::save_to(&path, &app.to_save()).expect("セーブに失敗");
+    let loaded = save::load_from(&path).expect("ロードに失敗");
+    let restored = App::from_save(loaded, clock.clone(), Rc::new(Rules::default()), None);
+    check("解除した実績を復元", restored.achievements == app.achievements);
+    check("解除した時刻を復元", restored.achievements.unlocked_at(Achievement::FirstFeed) == Some(today_at(12)));
+    let _ = std::fs::remove_file(&path);
+
+    // 実績の無い古いセーブデータは実績なし
+    let mut json = serde_json::to_value(SaveData::new(Cat::new("古猫".to_string()), today_at(12))).expect("変換に失敗");
+    json.as_object_mut().expect("オブジェクト").remove("achievements");
+    let data: SaveData = serde_json::from_value(json).expect("古いセーブデータを読めない");
+    check("古いセーブデータは実績なし", data.achievements.unlocked().is_empty());
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
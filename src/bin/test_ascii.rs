@@ -1,4 +1,4 @@
-use neko_watch::cat::{Cat, CatState};
+use neko_watch::cat::Cat;
 
 fn main() {
     println!("=== ASCII アート行数チェック ===\n");
@@ -50,7 +50,7 @@ fn test_ascii_lines(cat: &mut Cat, state_name: &str, hunger: i32, happiness: i32
 }
 
 // プライベート関数のテスト用
-fn get_animation_frame(cat: &Cat, frame: usize) -> String {
+fn get_animation_frame(_cat: &Cat, frame: usize) -> String {
     // Normal状態のアニメーションを手動で再現
     match frame % 4 {
         0 => "   /\\_/\\          \n  ( o.o )         \n   > ^ <     /)   \n  /|   |\\   / /   \n ( |___| ) /_/    ".to_string(),
@@ -0,0 +1,66 @@
+use chrono::Duration;
+use neko_watch::away;
+use neko_watch::cat::{Cat, CatState};
+use neko_watch::clock::{Clock, ManualClock, today_at};
+use std::rc::Rc;
+
+fn main() {
+    println!("=== 留守中の経過テスト ===\n");
+
+    // 20分の留守: 途中で空腹度が30を切り、健康度の減少が-4/分に切り替わる
+    let mut stepped = Cat::with_clock("留守番猫".to_string(), Rc::new(ManualClock::at_noon_today()));
+    stepped.set_status_for_test(50, 80, 80, 100);
+    let mut single = stepped.clone();
+
+    let from = today_at(12);
+    let to = from + Duration::minutes(20);
+    let summary = away::catch_up(&mut stepped, from, to);
+    single.decay(20.0 * 60.0);
+
+    println!("{}\n", summary.lines().join("\n"));
+    check("空腹度は一括計算と同じ", stepped.hunger == single.hunger);
+    check(
+        "健康度は途中から速く減る",
+        stepped.health < single.health,
+    );
+    check("空腹を通過", summary.states.contains(&CatState::Hungry));
+
+    // 睡眠中に留守にした場合、途中で目を覚ます
+    println!("\n=== 睡眠中の留守 ===");
+    let mut sleeper = Cat::with_clock("お昼寝猫".to_string(), Rc::new(ManualClock::at_noon_today()));
+    sleeper.sleep();
+    let now = today_at(12);
+    let summary = away::catch_up(&mut sleeper, now, now + Duration::minutes(30));
+    check("留守中に目を覚ました", summary.woke_up);
+
+    // 短い留守では目を覚まさない
+    let mut sleeper = Cat::with_clock("お昼寝猫".to_string(), Rc::new(ManualClock::at_noon_today()));
+    sleeper.sleep();
+    let now = today_at(12);
+    let summary = away::catch_up(&mut sleeper, now, now + Duration::minutes(5));
+    check("5分ではまだ寝ている", !summary.woke_up);
+
+    // 餌やり直後の満腹状態は30秒で終わる
+    println!("\n=== 餌やり直後の留守 ===");
+    let mut fed = Cat::with_clock("満腹猫".to_string(), Rc::new(ManualClock::at_noon_today()));
+    fed.set_status_for_test(95, 50, 80, 80);
+    fed.feed();
+    let now = today_at(12);
+    let summary = away::catch_up(&mut fed, now, now + Duration::minutes(2));
+    println!("様子: {:?}", summary.states);
+    check("満腹から普通へ", summary.states.starts_with(&[CatState::Full, CatState::Normal]));
+
+    // 時計は元に戻っている
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut cat = Cat::with_clock("時計猫".to_string(), clock.clone());
+    let now = clock.now();
+    away::catch_up(&mut cat, now, now + Duration::hours(3));
+    cat.sleep();
+    clock.advance(Duration::minutes(10));
+    check("留守後も元の時計を使う", !cat.is_sleeping());
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
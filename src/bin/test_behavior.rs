@@ -0,0 +1,118 @@
+use chrono::Duration;
+use neko_watch::app::App;
+use neko_watch::behavior::{self, Behavior};
+use neko_watch::cat::{Cat, CatEvent};
+use neko_watch::clock::ManualClock;
+use neko_watch::life::LifeStage;
+use neko_watch::rules::{BehaviorRules, EventRules, Rules};
+use neko_watch::save;
+use std::rc::Rc;
+
+fn main() {
+    println!("=== ひとりでするふるまいテスト ===\n");
+
+    // 今の様子に合ったふるまいを選ぶ
+    let rules = BehaviorRules::default();
+    let mut cat = Cat::with_clock("ミケ".to_string(), Rc::new(ManualClock::at_noon_today()));
+    cat.set_status_for_test(90, 90, 90, 50);
+    check("疲れているとひと眠り", behavior::choose(&cat, &rules) == Behavior::Nap);
+    cat.set_status_for_test(90, 90, 50, 90);
+    check("汚れていると毛づくろい", behavior::choose(&cat, &rules) == Behavior::Groom);
+    cat.set_status_for_test(90, 30, 90, 90);
+    check("退屈だと物を落とす", behavior::choose(&cat, &rules) == Behavior::KnockOver);
+    cat.set_status_for_test(90, 90, 90, 90);
+    check("元気ならしっぽを追いかける", behavior::choose(&cat, &rules) == Behavior::ChaseTail);
+
+    // ひとりで20分過ごすと始める
+    println!("\n=== 20分ごとに自分から ===");
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut cat = healthy_cat(&clock);
+    pass(&mut cat, &clock, 19 * 60 + 50);
+    check("19分50秒ではまだ", cat.behavior().is_none());
+    cat.set_status_for_test(90, 90, 50, 90);
+    pass(&mut cat, &clock, 10);
+    check("20分で毛づくろいを始める", cat.behavior() == Some(Behavior::Groom));
+    check("清潔度が少し上がる", cat.cleanliness >= 57);
+    check("お知らせのイベント", cat.take_events().contains(&CatEvent::Behaved(Behavior::Groom)));
+    let art = cat.get_ascii_art();
+    println!("{}", art);
+    check("毛づくろいのアニメーション", art.contains("ペロ") && art.split('\n').count() == 6);
+    pass(&mut cat, &clock, 60);
+    check("1分で終わる", cat.behavior().is_none());
+    check("終わると状態のアニメーション", !cat.get_ascii_art().contains("ペロ"));
+
+    // お世話すると中断し、数え直す
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut cat = healthy_cat(&clock);
+    pass(&mut cat, &clock, 19 * 60 + 50);
+    cat.set_status_for_test(90, 30, 90, 90);
+    pass(&mut cat, &clock, 10);
+    check("退屈して物を落とす", cat.behavior() == Some(Behavior::KnockOver));
+    cat.play();
+    check("遊ぶとやめる", cat.behavior().is_none());
+    pass(&mut cat, &clock, 19 * 60);
+    check("お世話から数え直す", cat.behavior().is_none());
+
+    // 寝ている間や瀕死のときは何もしない
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut cat = healthy_cat(&clock);
+    cat.sleep();
+    pass(&mut cat, &clock, 9 * 60);
+    check("寝ている間は何もしない", cat.behavior().is_none());
+    let mut cat = healthy_cat(&clock);
+    cat.set_status_for_test(5, 90, 90, 90);
+    pass(&mut cat, &clock, 25 * 60);
+    check("瀕死のときは何もしない", cat.behavior().is_none());
+
+    // App: ほかの猫のふるまいも名前付きでお知らせ
+    println!("\n=== お知らせ ===");
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut cat = Cat::with_clock("ミケ".to_string(), clock.clone());
+    // 偶然の出来事でふるまいが中断しないようにする
+    cat.set_rules(Rc::new(Rules { events: EventRules::quiet(), ..Rules::default() }));
+    let mut app = App::with_clock(cat, clock.clone());
+    for _ in 0..40 {
+        app.cat_mut().set_status_for_test(90, 90, 90, 90);
+        clock.advance(Duration::seconds(30));
+        app.tick();
+    }
+    let notice = app.notice.as_ref().map(|notice| notice.text.clone()).unwrap_or_default();
+    println!("{}", notice);
+    check("しっぽを追いかけるお知らせ", notice.contains("ミケ") && notice.contains("しっぽ"));
+
+    // ふるまいの途中でもセーブされる
+    let path = std::env::temp_dir().join("neko-watch-test").join("behavior.json");
+    save::save_to(&path, &app.to_save()).expect("セーブに失敗");
+    let loaded = save::load_from(&path).expect("ロードに失敗");
+    let restored = App::from_save(loaded, clock, Rc::new(Rules::default()), None);
+    check("ふるまいを復元", restored.cat().behavior() == Some(Behavior::ChaseTail));
+    let _ = std::fs::remove_file(&path);
+
+    // 設定ファイルで変えられる
+    let rules = Rules::parse("[behavior]\ninterval_minutes = 5\n").expect("読み込みに失敗");
+    check("間隔を変更", rules.behavior.interval_minutes == 5.0);
+    let error = Rules::parse("[behavior]\nnap_health = 120\n").expect_err("エラーになるはず");
+    println!("{}", error);
+    check("健康度の範囲をチェック", error.message.contains("nap_health") && error.line == Some(2));
+}
+
+// 20分ひとりで過ごしても瀕死にならない元気な成猫
+fn healthy_cat(clock: &Rc<ManualClock>) -> Cat {
+    let mut cat = Cat::with_clock("ミケ".to_string(), clock.clone());
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    cat.set_status_for_test(100, 100, 100, 100);
+    cat
+}
+
+// 10秒刻みで時間を進める
+fn pass(cat: &mut Cat, clock: &ManualClock, seconds: i64) {
+    for _ in 0..seconds / 10 {
+        clock.advance(Duration::seconds(10));
+        cat.decay(10.0);
+    }
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
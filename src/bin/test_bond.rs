@@ -0,0 +1,150 @@
+use chrono::Duration;
+use crossterm::event::KeyCode;
+use neko_watch::app::App;
+use neko_watch::behavior::Behavior;
+use neko_watch::bond::{Bond, Perk};
+use neko_watch::cat::{Cat, CatEvent};
+use neko_watch::clock::{Clock, ManualClock, today_at};
+use neko_watch::life::LifeStage;
+use neko_watch::personality::{Personality, Trait};
+use neko_watch::rules::Rules;
+use neko_watch::save::{self, SaveData};
+use std::rc::Rc;
+
+fn main() {
+    println!("=== 絆テスト ===\n");
+
+    // よい状態で過ごすとゆっくり深まる
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut cat = adult_cat(&clock);
+    check("最初はよそよそしい", cat.bond.value() == 0.0 && cat.bond.label() == "よそよそしい");
+    for _ in 0..6 * 60 {
+        cat.set_status_for_test(90, 90, 90, 90);
+        clock.advance(Duration::seconds(10));
+        cat.decay(10.0);
+    }
+    println!("上機嫌で1時間: {:.2}", cat.bond.value());
+    check("1時間で+2", (cat.bond.value() - 2.0).abs() < 1e-6);
+
+    // 放っておくと薄れる
+    cat.bond = Bond::new(10.0);
+    for _ in 0..6 * 60 {
+        cat.set_status_for_test(20, 90, 90, 90);
+        clock.advance(Duration::seconds(10));
+        cat.decay(10.0);
+    }
+    println!("空腹で1時間: {:.2}", cat.bond.value());
+    check("空腹のまま1時間で-4", (cat.bond.value() - 6.0).abs() < 1e-6);
+
+    // お世話で深まり、お風呂で薄れる
+    println!("\n=== お世話とお風呂 ===");
+    let mut cat = adult_cat(&clock);
+    cat.bond = Bond::new(20.0);
+    cat.set_status_for_test(30, 50, 50, 50);
+    cat.feed();
+    cat.play();
+    check("餌と遊びで+1", (cat.bond.value() - 21.0).abs() < 1e-6);
+    cat.bathe();
+    check("お風呂で-3", (cat.bond.value() - 18.0).abs() < 1e-6);
+    let mut swimmer = adult_cat(&clock);
+    swimmer.personality = Personality { seed: 0, traits: vec![Trait::WaterTolerant] };
+    swimmer.bond = Bond::new(20.0);
+    swimmer.bathe();
+    println!("水好きの猫のお風呂: {:.2}", swimmer.bond.value());
+    check("水好きならあまり薄れない", swimmer.bond.value() > 19.0);
+
+    // 絆が深まると新しいことができるようになる
+    println!("\n=== できるようになること ===");
+    let mut cat = adult_cat(&clock);
+    cat.bond = Bond::new(29.8);
+    check("絆30未満はお出迎えしない", !cat.has_perk(Perk::Greeting));
+    cat.set_status_for_test(30, 50, 50, 50);
+    cat.feed();
+    check("絆30でお出迎え", cat.has_perk(Perk::Greeting));
+    check("できるようになったイベント", cat.take_events().contains(&CatEvent::Bonded(Perk::Greeting)));
+    check("カーソルについてくるのは絆50から", !cat.has_perk(Perk::FollowCursor));
+    cat.bond = Bond::new(50.0);
+    check("絆50でカーソルについてくる", cat.has_perk(Perk::FollowCursor));
+
+    // カーソルを動かすと、猫が1行ずつ歩いて追いかける
+    let follow_clock = Rc::new(ManualClock::at_noon_today());
+    let mut app = App::with_clock(adult_cat(&follow_clock), follow_clock.clone());
+    app.handle_key(KeyCode::Char('s'));
+    for _ in 0..3 {
+        app.handle_key(KeyCode::Down);
+    }
+    app.tick();
+    check("すぐには追いつかない", app.selected() == Some(3) && app.follower == 0);
+    follow_clock.advance(Duration::milliseconds(150));
+    app.tick();
+    check("1行ずつ歩く", app.follower == 1);
+    follow_clock.advance(Duration::milliseconds(300));
+    app.tick();
+    check("カーソルに追いつく", app.follower == 3);
+    follow_clock.advance(Duration::milliseconds(300));
+    app.tick();
+    check("追いついたら止まる", app.follower == 3);
+    app.handle_key(KeyCode::Esc);
+    app.tick();
+    check("選択肢を閉じると先頭で待つ", app.follower == 0);
+
+    // 絆70でお風呂をがまんする
+    let mut loving = adult_cat(&clock);
+    let mut shy = adult_cat(&clock);
+    loving.bond = Bond::new(70.0);
+    loving.set_status_for_test(50, 80, 20, 80);
+    shy.set_status_for_test(50, 80, 20, 80);
+    loving.bathe();
+    shy.bathe();
+    println!("お風呂の後の幸福度: 絆70 {} / 絆0 {}", loving.happiness, shy.happiness);
+    check("お風呂の不機嫌が半分", loving.happiness == 65 && shy.happiness == 50);
+
+    // 起動するとお出迎え
+    println!("\n=== お出迎え ===");
+    let path = std::env::temp_dir().join("neko-watch-test").join("bond.json");
+    let mut cat = adult_cat(&clock);
+    cat.bond = Bond::new(40.0);
+    cat.set_status_for_test(90, 70, 90, 90);
+    save::save_to(&path, &SaveData::new(cat, clock.now())).expect("セーブに失敗");
+    let loaded = save::load_from(&path).expect("ロードに失敗");
+    let app = App::from_save(loaded, clock.clone(), Rc::new(Rules::default()), None);
+    let notice = app.notice.as_ref().map(|notice| notice.text.clone()).unwrap_or_default();
+    println!("{}", notice);
+    check("お出迎えのお知らせ", notice.contains("お出迎え"));
+    check("お出迎えのアニメーション", app.cat().behavior() == Some(Behavior::Greet));
+    println!("{}", app.cat().get_ascii_art());
+    check("絆を復元", app.cat().bond.value() == 40.0);
+
+    let mut cat = adult_cat(&clock);
+    cat.set_status_for_test(90, 70, 90, 90);
+    save::save_to(&path, &SaveData::new(cat, clock.now())).expect("セーブに失敗");
+    let loaded = save::load_from(&path).expect("ロードに失敗");
+    let app = App::from_save(loaded, clock.clone(), Rc::new(Rules::default()), None);
+    check("絆が浅いとお出迎えしない", app.cat().behavior().is_none());
+    let _ = std::fs::remove_file(&path);
+
+    // 絆の無い古いセーブデータは0から
+    let cat = Cat::new("古猫".to_string());
+    let mut json = serde_json::to_value(SaveData::new(cat, today_at(12))).expect("変換に失敗");
+    json["cats"][0].as_object_mut().expect("オブジェクト").remove("bond");
+    let data: SaveData = serde_json::from_value(json).expect("古いセーブデータを読めない");
+    check("古いセーブデータは絆0から", data.cats[0].bond.value() == 0.0);
+
+    // 設定ファイルで変えられる
+    let rules = Rules::parse("[bond]\ngreet_bond = 10\n").expect("読み込みに失敗");
+    check("お出迎えの絆を変更", rules.bond.greet_bond == 10.0);
+    let error = Rules::parse("[bond]\nbath_bond = 150\n").expect_err("エラーになるはず");
+    println!("{}", error);
+    check("絆の範囲をチェック", error.message.contains("bath_bond") && error.line == Some(2));
+}
+
+fn adult_cat(clock: &Rc<ManualClock>) -> Cat {
+    let mut cat = Cat::with_clock("ミケ".to_string(), clock.clone());
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    cat
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
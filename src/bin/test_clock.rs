@@ -0,0 +1,93 @@
+use chrono::Duration;
+use neko_watch::app::App;
+use neko_watch::cat::{Cat, CatState};
+use neko_watch::clock::{Clock, ManualClock, ScaledClock, today_at};
+use neko_watch::life::LifeStage;
+use neko_watch::rules::{EventRules, Rules};
+use std::rc::Rc;
+
+fn main() {
+    println!("=== 時計テスト ===\n");
+
+    // 手動の時計
+    let start = today_at(12);
+    let manual = ManualClock::new(start);
+    manual.advance(Duration::minutes(10));
+    check("手動の時計を10分進める", manual.now() - start == Duration::minutes(10));
+
+    // 倍速の時計
+    let base = Rc::new(ManualClock::new(start));
+    let scaled = ScaledClock::new(base.clone(), 60.0);
+    base.advance(Duration::seconds(10));
+    check("60倍速で10秒→10分", scaled.now() - start == Duration::minutes(10));
+
+    // App の時計を進めるとそのぶん猫（成猫）が変化する
+    println!("\n=== App の時間経過 ===");
+    let clock = Rc::new(ManualClock::new(start));
+    let mut app = App::with_clock(adult_cat("時計猫"), clock.clone());
+    for _ in 0..20 {
+        clock.advance(Duration::seconds(30));
+        app.tick();
+    }
+    println!("10分後: {}", app.cat().debug_status().replace('\n', " / "));
+    // 小刻みに進めた場合は浮動小数点の誤差で1ずれることがある
+    check("空腹度 80→約55", (54..=55).contains(&app.cat().hunger));
+    check("健康度 80→約60", (59..=60).contains(&app.cat().health));
+    check("留守扱いにならない", app.away.is_none());
+
+    // 長時間時計が飛んだら留守中の様子が出る
+    clock.advance(Duration::minutes(40));
+    app.tick();
+    check("40分飛ぶと留守中の様子", app.away.is_some());
+    check("瀕死になっている", app.cat().get_state() == CatState::Dying);
+
+    // 倍速: 実時間10秒で10分進み、睡眠も10秒で終わる
+    println!("\n=== 倍速 ===");
+    let clock = Rc::new(ManualClock::new(start));
+    let mut app = App::with_clock(adult_cat("倍速猫"), clock.clone());
+    app.set_speed(60.0);
+    app.cat_mut().sleep();
+    for _ in 0..100 {
+        clock.advance(Duration::milliseconds(100));
+        app.tick();
+    }
+    check("60倍速で10秒→空腹度 80→約55", (54..=55).contains(&app.cat().hunger));
+    check("60倍速で10秒→睡眠終了", !app.cat().is_sleeping());
+    check("倍速では留守扱いにならない", app.away.is_none());
+
+    // 倍速で進んだ時刻は実時刻に戻して保存する
+    app.cat_mut().sleep();
+    let saved = app.to_save();
+    check("保存時刻は実時刻", saved.saved_at == clock.now());
+
+    // 速度の切り替え
+    app.set_speed(1.0);
+    app.cycle_speed();
+    check("1倍→10倍", app.speed() == 10.0);
+    app.cycle_speed();
+    check("10倍→60倍", app.speed() == 60.0);
+    app.cycle_speed();
+    check("60倍→1倍", app.speed() == 1.0);
+
+    // 一時停止中は時間が進まない
+    app.toggle_pause();
+    let hunger = app.cat().hunger;
+    clock.advance(Duration::seconds(30));
+    app.tick();
+    check("一時停止中は変化しない", app.is_paused() && app.cat().hunger == hunger);
+    app.toggle_pause();
+    check("一時停止解除で元の速度", !app.is_paused() && app.speed() == 1.0);
+}
+
+fn adult_cat(name: &str) -> Cat {
+    let mut cat = Cat::new(name.to_string());
+    // 偶然の出来事でステータスが変わらないようにする
+    cat.set_rules(Rc::new(Rules { events: EventRules::quiet(), ..Rules::default() }));
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    cat
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
@@ -0,0 +1,155 @@
+use crossterm::event::KeyCode;
+use neko_watch::app::{App, Screen};
+use neko_watch::art;
+use neko_watch::cat::Cat;
+use neko_watch::clock::{ManualClock, today_at};
+use neko_watch::coat::{Coat, CoatColor, EyeColor, Pattern};
+use neko_watch::dice::Dice;
+use neko_watch::life::LifeStage;
+use neko_watch::rules::Rules;
+use neko_watch::save::{self, SaveData};
+use rand::RngCore;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+fn main() {
+    println!("=== 毛並みテスト ===\n");
+
+    // 同じ種なら同じ毛並み
+    let coat = Coat::generate(7);
+    println!("seed 7: {}", coat.label());
+    check("同じ種なら同じ毛並み", Coat::generate(7) == coat);
+    let coats: Vec<Coat> = (0..200).map(Coat::generate).collect();
+    for pattern in [Pattern::Solid, Pattern::Tabby, Pattern::Calico, Pattern::Tuxedo] {
+        check(
+            &format!("{}も生まれる", pattern.label()),
+            coats.iter().any(|coat| coat.pattern == pattern),
+        );
+    }
+
+    // 見た目: マスごとの色
+    println!("\n=== マスごとの色 ===");
+    let solid = Coat { color: CoatColor::Orange, pattern: Pattern::Solid, eyes: EyeColor::Green };
+    check("単色は全部同じ色", solid.cell_color(0, 3) == solid.cell_color(4, 9));
+    let tabby = Coat { pattern: Pattern::Tabby, ..solid };
+    check("トラはしま模様", tabby.cell_color(0, 3) != tabby.cell_color(0, 4));
+    let tuxedo = Coat { color: CoatColor::Black, pattern: Pattern::Tuxedo, eyes: EyeColor::Gold };
+    check("ハチワレは胸元が白", tuxedo.cell_color(4, 5) == CoatColor::White.color());
+    check("ハチワレの耳は黒", tuxedo.cell_color(0, 4) == CoatColor::Black.color());
+    let calico = Coat { pattern: Pattern::Calico, ..solid };
+    let colors: HashSet<_> = (0..5)
+        .flat_map(|row| (0..18).map(move |col| calico.cell_color(row, col)))
+        .collect();
+    check("三毛は3色", colors.len() == 3);
+
+    // 目の位置（成長段階で変わる）
+    let adult = adult_cat("成猫", solid);
+    let (row, col) = adult.eye_position(art::normal).expect("目がある");
+    let line = adult.art_with(art::normal).lines().nth(row).unwrap_or_default().to_string();
+    check("成猫の目の位置", line.chars().skip(col).take(7).collect::<String>() == "( o.o )");
+    let kitten = Cat::with_clock("子猫".to_string(), Rc::new(ManualClock::at_noon_today()));
+    let (row, col) = kitten.eye_position(art::normal).expect("目がある");
+    let line = kitten.art_with(art::normal).lines().nth(row).unwrap_or_default().to_string();
+    check("子猫の目の位置", line.chars().skip(col).take(7).collect::<String>() == "( o.o )");
+    check("家出中は目が無い", kitten.eye_position(art::ran_away).is_none());
+
+    // 両親から受け継ぐ
+    println!("\n=== 受け継ぎ ===");
+    let mother = Coat { color: CoatColor::Black, pattern: Pattern::Tuxedo, eyes: EyeColor::Green };
+    let father = Coat { color: CoatColor::Orange, pattern: Pattern::Tabby, eyes: EyeColor::Gold };
+    let kitten = Coat::inherit(&mother, &father, 42);
+    println!("{} × {} → {}", mother.label(), father.label(), kitten.label());
+    check("同じ両親と種なら同じ子猫", Coat::inherit(&mother, &father, 42) == kitten);
+    let kittens: Vec<Coat> = (0..1000).map(|seed| Coat::inherit(&mother, &father, seed)).collect();
+    let inherited = kittens
+        .iter()
+        .filter(|coat| [mother.color, father.color].contains(&coat.color))
+        .count();
+    println!("1000匹中 両親の毛色: {}匹", inherited);
+    check("ほとんどは両親のどちらかの毛色", inherited > 850);
+    check(
+        "母親の毛色も父親の毛色も受け継ぐ",
+        kittens.iter().any(|coat| coat.color == mother.color) && kittens.iter().any(|coat| coat.color == father.color),
+    );
+    check("ときどき突然変異", kittens.iter().any(|coat| ![mother.pattern, father.pattern].contains(&coat.pattern)));
+    let twins = Coat { color: CoatColor::White, pattern: Pattern::Solid, eyes: EyeColor::Blue };
+    let same = (0..1000).filter(|&seed| Coat::inherit(&twins, &twins, seed) == twins).count();
+    check("同じ毛並みの両親からはたいてい同じ毛並み", same > 700 && same < 1000);
+
+    // App: 迎えた猫の毛並みと子猫
+    println!("\n=== 子猫が生まれる ===");
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let app = App::from_save(None, clock.clone(), Rc::new(Rules::default()), Some(7));
+    // 番号・性格の次に引いた乱数で決まる
+    let mut dice = Dice::new(7);
+    dice.next_u64();
+    dice.next_u64();
+    check("--seed の毛並み", app.cat().coat == Coat::generate(dice.next_u64()));
+
+    let mut app = household(&clock, mother, father, 99);
+    app.handle_key(KeyCode::Char('l'));
+    check("[l] で相手を選ぶ", app.screen == Screen::Litter { selected: 0 });
+    app.handle_key(KeyCode::Enter);
+    let born: Vec<&Cat> = app.cats.iter().skip(2).collect();
+    for kitten in &born {
+        println!("{}: {}", kitten.name, kitten.coat.label());
+    }
+    check("子猫が生まれる", !born.is_empty() && born.len() <= 2 && app.screen == Screen::Main);
+    check("子猫は子猫", born.iter().all(|kitten| kitten.life_stage() == LifeStage::Kitten));
+    check(
+        "両親を覚えている",
+        born.iter().all(|kitten| kitten.parents == Some(["ミケ".to_string(), "トラ".to_string()])),
+    );
+    let coats: Vec<Coat> = born.iter().map(|kitten| kitten.coat).collect();
+    let mut again = household(&clock, mother, father, 99);
+    again.handle_key(KeyCode::Char('l'));
+    again.handle_key(KeyCode::Enter);
+    let coats_again: Vec<Coat> = again.cats.iter().skip(2).map(|kitten| kitten.coat).collect();
+    check("同じ種なら同じ子猫たち", coats == coats_again);
+
+    // 子猫どうしや子猫とは産めない
+    let mut app = household(&clock, mother, father, 1);
+    app.handle_key(KeyCode::Char('a'));
+    app.handle_key(KeyCode::Enter);
+    app.handle_key(KeyCode::Char('l'));
+    check("子猫は親になれない", app.screen == Screen::Main && app.notice.as_ref().is_some_and(|notice| notice.text.contains("大人")));
+
+    // 毛並みと両親はセーブされる
+    println!("\n=== セーブ ===");
+    let path = std::env::temp_dir().join("neko-watch-test").join("coat.json");
+    let mut again = household(&clock, mother, father, 99);
+    again.handle_key(KeyCode::Char('l'));
+    again.handle_key(KeyCode::Enter);
+    save::save_to(&path, &again.to_save()).expect("セーブに失敗");
+    let loaded = save::load_from(&path).expect("ロードに失敗").expect("セーブが無い");
+    check("毛並みを復元", loaded.cats[0].coat == mother && loaded.cats[2].coat == coats[0]);
+    check("両親を復元", loaded.cats[2].parents.is_some());
+    let _ = std::fs::remove_file(&path);
+
+    // 毛並みの無い古いセーブデータはグレーの単色
+    let mut cat = Cat::new("古猫".to_string());
+    cat.coat = Coat::generate(1);
+    let mut json = serde_json::to_value(SaveData::new(cat, today_at(12))).expect("変換に失敗");
+    json["cats"][0].as_object_mut().expect("オブジェクト").remove("coat");
+    let data: SaveData = serde_json::from_value(json).expect("古いセーブデータを読めない");
+    check("古いセーブデータはグレーの単色", data.cats[0].coat == Coat::default());
+}
+
+// 大人の2匹（ミケとトラ）が暮らす家。seed は次に生まれる子猫の種
+fn household(clock: &Rc<ManualClock>, mother: Coat, father: Coat, seed: u64) -> App {
+    let mut data = SaveData::new(adult_cat("ミケ", mother), today_at(12));
+    data.cats.push(adult_cat("トラ", father));
+    App::from_save(Some(data), clock.clone(), Rc::new(Rules::default()), Some(seed))
+}
+
+fn adult_cat(name: &str, coat: Coat) -> Cat {
+    let mut cat = Cat::with_clock(name.to_string(), Rc::new(ManualClock::at_noon_today()));
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    cat.coat = coat;
+    cat
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
@@ -0,0 +1,116 @@
+use chrono::Duration;
+use neko_watch::app::App;
+use neko_watch::away;
+use neko_watch::cat::{Cat, CatEvent, CatState};
+use neko_watch::clock::{ManualClock, today_at};
+use neko_watch::daytime::TimeOfDay;
+use neko_watch::life::LifeStage;
+use neko_watch::rules::{DaytimeRules, Rules};
+use std::rc::Rc;
+
+fn main() {
+    println!("=== 時間帯テスト ===\n");
+
+    let rules = DaytimeRules::default();
+    check("3時は夜", TimeOfDay::at(today_at(3), &rules) == TimeOfDay::Night);
+    check("6時は朝", TimeOfDay::at(today_at(6), &rules) == TimeOfDay::Morning);
+    check("12時は昼", TimeOfDay::at(today_at(12), &rules) == TimeOfDay::Day);
+    check("18時は夕方", TimeOfDay::at(today_at(18), &rules) == TimeOfDay::Dusk);
+    check("22時は夜", TimeOfDay::at(today_at(22), &rules) == TimeOfDay::Night);
+
+    // 夜はお腹が空きにくい
+    println!("\n=== 夜の減少量 ===");
+    let mut day = adult_cat(12);
+    let mut night = adult_cat(23);
+    day.set_status_for_test(80, 80, 80, 80);
+    night.set_status_for_test(80, 80, 80, 80);
+    day.decay(10.0 * 60.0);
+    night.decay(10.0 * 60.0);
+    println!("10分後の空腹度: 昼 {} / 夜 {}", day.hunger, night.hunger);
+    check("昼は10分で空腹度 80→55", day.hunger == 55);
+    check("夜は10分で空腹度 80→65", night.hunger == 65);
+    check("夜は汚れにくい", night.cleanliness > day.cleanliness);
+
+    // 夜は眠そう
+    println!("\n=== 夜は眠そう・夕方は遊びたい ===");
+    let mut day = adult_cat(12);
+    let mut night = adult_cat(23);
+    day.set_status_for_test(70, 60, 70, 60);
+    night.set_status_for_test(70, 60, 70, 60);
+    check("昼は普通", day.get_state() == CatState::Normal);
+    check("夜は疲労（眠そう）", night.get_state() == CatState::Tired);
+
+    // 夕方は遊びたがり、遊ぶと喜ぶ
+    let mut dusk = adult_cat(18);
+    dusk.set_status_for_test(70, 60, 70, 70);
+    check("夕方は遊びたい", dusk.get_state() == CatState::Playful);
+    println!("{}", dusk.get_ascii_art());
+    check("遊びたいアートは6行", dusk.get_ascii_art().split('\n').count() == 6);
+    dusk.set_status_for_test(70, 50, 70, 70);
+    dusk.play();
+    check("夕方に遊ぶと幸福度 +25×1.3", dusk.happiness == 82);
+    check("満たされると上機嫌", dusk.get_state() == CatState::Happy);
+
+    // 夜に起きているとうとうと眠る
+    println!("\n=== うとうと ===");
+    let clock = Rc::new(ManualClock::new(today_at(22)));
+    let mut cat = Cat::with_clock("夜ふかし猫".to_string(), clock.clone());
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    run(&mut cat, &clock, 29);
+    check("29分ではまだ起きている", !cat.is_sleeping());
+    run(&mut cat, &clock, 1);
+    check("30分でうとうと眠る", cat.is_sleeping() && cat.get_state() == CatState::Sleeping);
+    check("うとうとイベント", cat.take_events().contains(&CatEvent::DozedOff));
+    check("寝かせた回数には数えない", cat.care.slept == 0);
+    run(&mut cat, &clock, 20);
+    check("20分で目を覚ます", !cat.is_sleeping());
+
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut cat = Cat::with_clock("昼の猫".to_string(), clock.clone());
+    run(&mut cat, &clock, 60);
+    check("昼はうとうとしない", !cat.is_sleeping());
+
+    // App にお知らせが出る
+    let clock = Rc::new(ManualClock::new(today_at(22)));
+    let mut app = App::with_clock(adult_cat(22), clock.clone());
+    for _ in 0..60 {
+        clock.advance(Duration::seconds(30));
+        app.tick();
+    }
+    check("うとうとのお知らせ", app.notice.as_ref().is_some_and(|notice| notice.text.contains("うとうと")));
+
+    // 留守中も時間帯が反映される（21時から夜）
+    println!("\n=== 留守中の夜 ===");
+    let mut cat = adult_cat(21);
+    cat.set_status_for_test(100, 100, 100, 100);
+    let summary = away::catch_up(&mut cat, today_at(21), today_at(22));
+    println!("{}", summary.lines().join("\n"));
+    check("留守中の夜にうとうと眠った", summary.states.contains(&CatState::Sleeping));
+
+    // 設定ファイルで変えられる
+    let rules = Rules::parse("[daytime]\nnight_hour = 23\n").expect("読み込みに失敗");
+    check("22時を夕方に変更", TimeOfDay::at(today_at(22), &rules.daytime) == TimeOfDay::Dusk);
+    let error = Rules::parse("[daytime]\ndusk_hour = 18\nnight_hour = 17\n").expect_err("エラーになるはず");
+    println!("{}", error);
+    check("時間帯の順序をチェック", error.message.contains("night_hour") && error.line == Some(3));
+}
+
+fn adult_cat(hour: u32) -> Cat {
+    let mut cat = Cat::with_clock("時間帯テスト猫".to_string(), Rc::new(ManualClock::new(today_at(hour))));
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    cat
+}
+
+// ステータスを保ちながら分単位で時計を進める
+fn run(cat: &mut Cat, clock: &ManualClock, minutes: i64) {
+    for _ in 0..minutes * 6 {
+        cat.set_status_for_test(100, 100, 100, 100);
+        clock.advance(Duration::seconds(10));
+        cat.decay(10.0);
+    }
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
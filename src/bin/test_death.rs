@@ -0,0 +1,143 @@
+use chrono::{Duration, Local};
+use crossterm::event::KeyCode;
+use neko_watch::app::{App, Screen};
+use neko_watch::cat::{Cat, CatEvent, CatState, DeathCause, Outcome};
+use neko_watch::clock::ManualClock;
+use neko_watch::life::LifeStage;
+use neko_watch::rules::Rules;
+use neko_watch::save::{self, SaveData};
+use std::rc::Rc;
+
+fn main() {
+    println!("=== お別れテスト ===\n");
+
+    // 健康度 0 が30分続くとお別れ
+    let mut cat = adult_cat("お別れ猫", Rules::default());
+    cat.set_status_for_test(5, 50, 50, 0);
+    cat.feed();
+    check("餌をもらったイベント", cat.take_events() == vec![CatEvent::Fed]);
+    cat.decay(29.0 * 60.0);
+    check("29分ではまだ瀕死", cat.get_state() == CatState::Dying);
+    cat.decay(60.0);
+    check("30分でお別れ", cat.is_dead() && cat.get_state() == CatState::Dead);
+    check("死亡イベント", cat.take_events() == vec![CatEvent::Died(DeathCause::Starvation)]);
+    check("いちばん低い空腹度が死因", matches!(cat.outcome(), Some(Outcome::Died { cause: DeathCause::Starvation, .. })));
+    check("お世話できない", !cat.can_perform_action());
+    check("餌やりの回数を覚えている", cat.care.fed == 1);
+    let age = cat.age_seconds();
+    cat.decay(600.0);
+    check("お別れ後は年を取らない", cat.age_seconds() == age);
+
+    // 途中で回復すれば数え直し
+    let mut cat = adult_cat("回復猫", Rules::default());
+    cat.set_status_for_test(50, 50, 50, 0);
+    cat.decay(20.0 * 60.0);
+    cat.sleep();
+    cat.decay(1.0);
+    cat.set_status_for_test(50, 50, 50, 0);
+    cat.decay(20.0 * 60.0);
+    check("回復すると猶予は数え直し", !cat.is_dead());
+
+    // 1回の更新が長くても 0 になってからの時間だけ数える
+    let mut cat = adult_cat("長い更新猫", Rules::default());
+    cat.set_status_for_test(80, 80, 80, 40);
+    cat.decay(40.0 * 60.0);
+    check("20分で0になり残り20分では生きている", cat.health == 0 && !cat.is_dead());
+
+    // App: ゲームオーバー → 思い出 → 新しい猫
+    println!("\n=== ゲームオーバーと新しい猫 ===");
+    let clock = Rc::new(ManualClock::new(Local::now()));
+    let mut cat = adult_cat("思い出猫", Rules::default());
+    cat.set_status_for_test(100, 5, 100, 0);
+    let mut app = App::with_clock(cat, clock.clone());
+    for _ in 0..200 {
+        clock.advance(Duration::seconds(10));
+        app.tick();
+    }
+    check("ゲームオーバー画面", app.screen == Screen::GameOver);
+    check("思い出に残る", app.memorial.len() == 1);
+    if let Some(entry) = app.memorial.first() {
+        println!("{}", entry.lines().join("\n"));
+        check("死因は寂しさ", entry.summary().contains("寂しさ"));
+    }
+
+    app.handle_key(KeyCode::Char('m'));
+    check("思い出画面", app.screen == Screen::Memorial);
+    app.handle_key(KeyCode::Esc);
+    check("ゲームオーバーに戻る", app.screen == Screen::GameOver);
+    app.handle_key(KeyCode::Char('1'));
+    check("ゲームオーバー中は餌をあげられない", app.cat().care.fed == 0);
+
+    // セーブすると思い出とお別れした猫が残る
+    let path = std::env::temp_dir().join("neko-watch-test").join("death.json");
+    save::save_to(&path, &app.to_save()).expect("セーブに失敗");
+    let loaded = save::load_from(&path).expect("ロードに失敗");
+    let restored = App::from_save(loaded, clock.clone(), Rc::new(Rules::default()), None);
+    check("再起動してもゲームオーバー画面", restored.screen == Screen::GameOver);
+    check("思い出を復元", restored.memorial == app.memorial);
+    let _ = std::fs::remove_file(&path);
+
+    app.handle_key(KeyCode::Char('n'));
+    for c in "タマ".chars() {
+        app.handle_key(KeyCode::Char(c));
+    }
+    app.handle_key(KeyCode::Enter);
+    check("新しい猫を迎える", app.screen == Screen::Main && app.cat().name == "タマ");
+    check("新しい猫は子猫", app.cat().life_stage() == LifeStage::Kitten && !app.cat().is_dead());
+    check("思い出はそのまま", app.memorial.len() == 1);
+
+    // 思い出の無い古いセーブデータは思い出なし
+    let mut json = serde_json::to_value(SaveData::new(Cat::new("古猫".to_string()), Local::now())).expect("変換に失敗");
+    json.as_object_mut().expect("オブジェクト").remove("memorial");
+    let data: SaveData = serde_json::from_value(json).expect("古いセーブデータを読めない");
+    check("古いセーブデータは思い出なし", data.memorial.is_empty());
+
+    // やさしいモード: 家出して、呼ぶと帰ってくる
+    println!("\n=== やさしいモード ===");
+    let mut rules = Rules::default();
+    rules.death.gentle = true;
+    let clock = Rc::new(ManualClock::new(Local::now()));
+    let mut cat = adult_cat("家出猫", rules);
+    cat.set_status_for_test(50, 50, 5, 0);
+    let mut app = App::with_clock(cat, clock.clone());
+    for _ in 0..200 {
+        clock.advance(Duration::seconds(10));
+        app.tick();
+    }
+    check("家出中", app.cat().has_run_away() && app.cat().get_state() == CatState::RanAway);
+    check("画面はそのまま", app.screen == Screen::Main);
+    check("思い出にはまだ残らない", app.memorial.is_empty());
+    println!("{}", app.cat().get_ascii_art());
+
+    app.handle_key(KeyCode::Char('c'));
+    app.handle_key(KeyCode::Char('c'));
+    check("2回では帰らない", app.cat().has_run_away() && app.cat().coax_remaining() == 1);
+    app.handle_key(KeyCode::Char('c'));
+    app.tick();
+    check("3回呼ぶと帰ってくる", app.cat().outcome().is_none() && app.cat().can_perform_action());
+    check("近所でお世話されていた", app.cat().health == 50 && app.cat().hunger == 50);
+    check("帰ってきたお知らせ", app.notice.as_ref().is_some_and(|notice| notice.text.contains("帰ってきました")));
+
+    // 家出中に新しい猫を迎えると思い出に残る
+    app.cat_mut().set_status_for_test(50, 50, 50, 0);
+    for _ in 0..200 {
+        clock.advance(Duration::seconds(10));
+        app.tick();
+    }
+    app.handle_key(KeyCode::Char('n'));
+    app.handle_key(KeyCode::Enter);
+    check("名前を省略するとネコ", app.cat().name == "ネコ");
+    check("家出した猫も思い出に", app.memorial.len() == 1 && app.memorial[0].summary().contains("近所の家"));
+}
+
+fn adult_cat(name: &str, rules: Rules) -> Cat {
+    let mut cat = Cat::new(name.to_string());
+    cat.set_rules(Rc::new(rules));
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    cat
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
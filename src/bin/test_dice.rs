@@ -0,0 +1,116 @@
+use chrono::{DateTime, Duration, Local};
+use neko_watch::app::App;
+use neko_watch::cat::Cat;
+use neko_watch::clock::{ManualClock, today_at};
+use neko_watch::coat::Coat;
+use neko_watch::dice::Dice;
+use neko_watch::events::RandomEvent;
+use neko_watch::personality::Personality;
+use neko_watch::rules::Rules;
+use neko_watch::save::{self, SaveData};
+use rand::RngCore;
+use std::rc::Rc;
+
+fn main() {
+    println!("=== 乱数の種テスト ===\n");
+
+    // 同じ種なら同じ並び
+    let mut dice = Dice::new(42);
+    let first: Vec<u64> = (0..5).map(|_| dice.next_u64()).collect();
+    let mut again = Dice::new(42);
+    check("同じ種なら同じ並び", (0..5).map(|_| again.next_u64()).collect::<Vec<_>>() == first);
+    let mut other = Dice::new(43);
+    check("違う種なら違う並び", (0..5).map(|_| other.next_u64()).collect::<Vec<_>>() != first);
+    check("種を覚えている", dice.seed() == 42);
+    check("種が無ければランダム", Dice::random() != Dice::random());
+
+    // 途中の状態を保存すると同じ続きになる
+    let json = serde_json::to_string(&dice).expect("保存に失敗");
+    println!("{}", json);
+    let mut restored: Dice = serde_json::from_str(&json).expect("読み込みに失敗");
+    check("続きから同じ並び", restored.next_u64() == dice.next_u64() && restored.seed() == 42);
+
+    // App: 種で新しい猫が決まる
+    println!("\n=== 新しい猫 ===");
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut app = App::from_save(None, clock.clone(), Rc::new(Rules::default()), Some(7));
+    check("--seed の種", app.dice.seed() == 7);
+    let mut dice = Dice::new(7);
+    dice.next_u64();
+    check("最初の猫は種から", app.cat().personality == Personality::generate(dice.next_u64()));
+    check("毛並みは性格と別に引く", app.cat().coat == Coat::generate(dice.next_u64()));
+    let mut other = App::from_save(None, clock.clone(), Rc::new(Rules::default()), Some(7));
+    check("同じ種なら同じ猫", other.cat().id == app.cat().id);
+    app.adopt("タマ".to_string(), false);
+    other.adopt("タマ".to_string(), false);
+    check(
+        "迎える猫も同じ",
+        app.cat().id == other.cat().id
+            && app.cat().personality == other.cat().personality
+            && app.cat().coat == other.cat().coat,
+    );
+
+    // 途中でセーブ・ロードしても同じ続きになる
+    println!("\n=== セーブとロード ===");
+    let (straight, straight_events) = play(None, clock_at(today_at(12)), 360);
+    let (saved, mut happened) = play(None, clock_at(today_at(12)), 180);
+    let path = std::env::temp_dir().join("neko-watch-test").join("dice.json");
+    save::save_to(&path, &saved.to_save()).expect("セーブに失敗");
+    let loaded = save::load_from(&path).expect("ロードに失敗");
+    let (resumed, events) = play(loaded.clone(), clock_at(today_at(12) + Duration::seconds(1800)), 180);
+    happened.extend(events);
+    println!("1時間の出来事: {}回", straight_events.iter().flatten().count());
+    check("ロードしても同じ乱数の続き", resumed.dice == straight.dice);
+    check("ロードしても同じ出来事", happened == straight_events);
+    let reseeded = App::from_save(loaded, clock_at(today_at(12)), Rc::new(Rules::default()), Some(9));
+    check("--seed を指定するとその種からやり直す", reseeded.dice == Dice::new(9));
+    let _ = std::fs::remove_file(&path);
+
+    // 種の無い古いセーブデータは新しい種から
+    let mut json = serde_json::to_value(SaveData::new(Cat::new("古猫".to_string()), today_at(12))).expect("変換に失敗");
+    json.as_object_mut().expect("オブジェクト").remove("dice");
+    let first: SaveData = serde_json::from_value(json.clone()).expect("古いセーブデータを読めない");
+    let second: SaveData = serde_json::from_value(json).expect("古いセーブデータを読めない");
+    check("古いセーブデータは新しい種", first.dice != second.dice);
+
+    // 番号の無い古いセーブデータの猫は、ゲームの乱数から番号を決める
+    let mut old = SaveData::new(Cat::new("古猫".to_string()), today_at(12));
+    old.cats.push(Cat::new("クロ".to_string()));
+    let mut json = serde_json::to_value(old).expect("変換に失敗");
+    for cat in json["cats"].as_array_mut().expect("配列") {
+        cat.as_object_mut().expect("オブジェクト").remove("id");
+    }
+    let load = || {
+        let data: SaveData = serde_json::from_value(json.clone()).expect("古いセーブデータを読めない");
+        App::from_save(Some(data), clock_at(today_at(12)), Rc::new(Rules::default()), Some(5))
+    };
+    let (first, second) = (load(), load());
+    let ids: Vec<u64> = first.cats.iter().map(|cat| cat.id).collect();
+    let mut dice = Dice::new(5);
+    check("番号は乱数から", ids == vec![dice.next_u64(), dice.next_u64()]);
+    check("同じ種なら同じ番号", second.cats.iter().map(|cat| cat.id).collect::<Vec<_>>() == ids);
+}
+
+// 種3の家（data があればその続き）で、clock の時刻から ticks 回だけ10秒ずつ過ごす
+// 毎回の出来事を返す
+fn play(data: Option<SaveData>, clock: Rc<ManualClock>, ticks: usize) -> (App, Vec<Option<RandomEvent>>) {
+    let seed = if data.is_some() { None } else { Some(3) };
+    let mut app = App::from_save(data, clock.clone(), Rc::new(Rules::default()), seed);
+    let mut happened = Vec::new();
+    for _ in 0..ticks {
+        app.cat_mut().set_status_for_test(90, 90, 90, 90);
+        clock.advance(Duration::seconds(10));
+        app.tick();
+        happened.push(app.cat().incident());
+    }
+    (app, happened)
+}
+
+fn clock_at(time: DateTime<Local>) -> Rc<ManualClock> {
+    Rc::new(ManualClock::new(time))
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
@@ -0,0 +1,173 @@
+use chrono::Duration;
+use crossterm::event::KeyCode;
+use neko_watch::app::{App, Screen};
+use neko_watch::cat::{Cat, CatState};
+use neko_watch::cli::Options;
+use neko_watch::clock::{ManualClock, today_at};
+use neko_watch::difficulty::Difficulty;
+use neko_watch::life::LifeStage;
+use neko_watch::rules::{EventRules, Rules};
+use neko_watch::save::{self, SaveData};
+use std::rc::Rc;
+
+fn main() {
+    println!("=== 難易度テスト ===\n");
+
+    // 難易度ごとのゲームバランス
+    let base = Rules::default();
+    let relaxed = Difficulty::Relaxed.apply(&base);
+    let hardcore = Difficulty::Hardcore.apply(&base);
+    check("ふつうは設定のまま", Difficulty::Standard.apply(&base) == base);
+    check("既定はふつう", Difficulty::default() == Difficulty::Standard);
+    println!(
+        "空腹度の減少（1分あたり）: のんびり {} / ふつう {} / きびしい {}",
+        relaxed.decay.hunger, base.decay.hunger, hardcore.decay.hunger
+    );
+    check("のんびりは減りにくい", relaxed.decay.hunger < base.decay.hunger && relaxed.decay.health_penalty < base.decay.health_penalty);
+    check("きびしいは減りやすい", hardcore.decay.hunger > base.decay.hunger);
+    check(
+        "健康度が減り始めるしきい値",
+        relaxed.decay.penalty_threshold == 15 && hardcore.decay.penalty_threshold == 40,
+    );
+    check("睡眠時間", relaxed.sleep.duration_seconds == 300 && hardcore.sleep.duration_seconds == 900);
+    check("のんびりはお別れしない", !relaxed.death.mortal && base.death.mortal && hardcore.death.mortal);
+
+    // 猫の様子
+    println!("\n=== 猫の様子 ===");
+    let mut standard_cat = adult_cat(&base);
+    standard_cat.set_status_for_test(100, 100, 100, 100);
+    let mut relaxed_cat = adult_cat(&relaxed);
+    relaxed_cat.set_status_for_test(100, 100, 100, 100);
+    standard_cat.decay(30.0 * 60.0);
+    check("ふつうは30分で空腹", standard_cat.get_state() == CatState::Hungry);
+    relaxed_cat.decay(4.0 * 3600.0);
+    println!("のんびりで4時間後の空腹度: {}", relaxed_cat.hunger);
+    check("のんびりは4時間留守にしても空腹にならない", relaxed_cat.get_state() != CatState::Hungry && relaxed_cat.hunger >= 30);
+
+    let mut below = adult_cat(&hardcore);
+    below.set_status_for_test(35, 80, 80, 80);
+    below.decay(60.0);
+    let mut above = adult_cat(&hardcore);
+    above.set_status_for_test(45, 80, 80, 80);
+    above.decay(60.0);
+    println!("きびしいで1分後の健康度: 空腹度35 {} / 空腹度45 {}", below.health, above.health);
+    check("きびしいは空腹度40未満で健康度が減りやすい", below.health < above.health);
+
+    let mut sleepy = adult_cat(&hardcore);
+    sleepy.sleep();
+    sleepy.decay(600.0);
+    check("きびしいは15分眠る", sleepy.is_sleeping());
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut napper = Cat::with_clock("ミケ".to_string(), clock.clone());
+    napper.set_rules(Rc::new(relaxed.clone()));
+    napper.sleep();
+    clock.advance(Duration::seconds(301));
+    check("のんびりは5分で起きる", !napper.is_sleeping());
+
+    let mut dying = adult_cat(&relaxed);
+    dying.set_status_for_test(5, 50, 50, 0);
+    dying.decay(3.0 * 3600.0);
+    check("のんびりは健康度0が続いても瀕死のまま", !dying.is_dead() && dying.get_state() == CatState::Dying);
+
+    // App: 新しいゲームで選び、タイトルに出してセーブする
+    println!("\n=== 新しいゲーム ===");
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let rules = Rc::new(Rules { events: EventRules::quiet(), ..Rules::default() });
+    let mut app = App::new_game(clock.clone(), rules.clone(), Some(1), Difficulty::Hardcore);
+    check("選んだ難易度", app.difficulty == Difficulty::Hardcore);
+    check("猫に反映", app.cat().rules().decay.penalty_threshold == 40);
+    app.adopt("タマ".to_string(), false);
+    check("迎えた猫にも反映", app.cat().rules().sleep.duration_seconds == 900);
+    let fresh = App::from_save(None, clock.clone(), rules.clone(), Some(1));
+    check("セーブデータが無ければふつう", fresh.difficulty == Difficulty::Standard && fresh.cat().rules().decay == base.decay);
+
+    let path = std::env::temp_dir().join("neko-watch-test").join("difficulty.json");
+    save::save_to(&path, &app.to_save()).expect("セーブに失敗");
+    let loaded = save::load_from(&path).expect("ロードに失敗");
+    let restored = App::from_save(loaded, clock.clone(), rules.clone(), None);
+    check("難易度を復元", restored.difficulty == Difficulty::Hardcore);
+    check(
+        "復元した猫たちにも反映",
+        restored.cats.iter().all(|cat| cat.rules().decay.hunger == hardcore.decay.hunger),
+    );
+    let _ = std::fs::remove_file(&path);
+
+    // 最後の1匹とお別れしたら、やり直すときに難易度を選び直せる
+    println!("\n=== やり直し ===");
+    let mut app = App::new_game(clock.clone(), rules.clone(), Some(1), Difficulty::Hardcore);
+    app.handle_key(KeyCode::Char('a'));
+    check("家族を増やすときは選べない", matches!(app.screen, Screen::Adopt { difficulty: None, .. }));
+    app.handle_key(KeyCode::Esc);
+    app.cat_mut().set_life_stage_for_test(LifeStage::Adult);
+    app.cat_mut().set_status_for_test(100, 5, 100, 0);
+    for _ in 0..200 {
+        clock.advance(Duration::seconds(10));
+        app.tick();
+    }
+    check("ゲームオーバー画面", app.screen == Screen::GameOver);
+    app.handle_key(KeyCode::Char('n'));
+    check("今の難易度から選ぶ", matches!(app.screen, Screen::Adopt { difficulty: Some(Difficulty::Hardcore), .. }));
+    app.handle_key(KeyCode::Tab);
+    for c in "タマ".chars() {
+        app.handle_key(KeyCode::Char(c));
+    }
+    app.handle_key(KeyCode::Enter);
+    check("[Tab] でのんびりに変えた", app.difficulty == Difficulty::Relaxed);
+    check("新しい猫に反映", app.cat().name == "タマ" && *app.cat().rules() == Difficulty::Relaxed.apply(&rules));
+    check("セーブにも残る", app.to_save().difficulty == Difficulty::Relaxed);
+
+    // 難易度の無い古いセーブデータはふつう
+    let mut json = serde_json::to_value(SaveData::new(Cat::new("古猫".to_string()), today_at(12))).expect("変換に失敗");
+    json.as_object_mut().expect("オブジェクト").remove("difficulty");
+    let data: SaveData = serde_json::from_value(json).expect("古いセーブデータを読めない");
+    check("古いセーブデータはふつう", data.difficulty == Difficulty::Standard);
+
+    // コマンドライン引数
+    println!("\n=== 引数 ===");
+    let parse = |args: &[&str]| Options::parse(args.iter().map(|arg| arg.to_string()));
+    check(
+        "--difficulty",
+        parse(&["--difficulty", "relaxed"]).is_ok_and(|options| options.difficulty == Some(Difficulty::Relaxed)),
+    );
+    check(
+        "--difficulty=",
+        parse(&["--difficulty=hardcore"]).is_ok_and(|options| options.difficulty == Some(Difficulty::Hardcore)),
+    );
+    check("指定しなければ選ばない", parse(&[]).is_ok_and(|options| options.difficulty.is_none()));
+    let error = parse(&["--difficulty", "easy"]).expect_err("エラーになるはず");
+    println!("{}", error);
+    check("知らない難易度", error.contains("relaxed, standard, hardcore"));
+
+    // 設定ファイルで変えられる
+    let rules = Rules::parse("[difficulty.relaxed]\ndecay_scale = 0.5\n").expect("読み込みに失敗");
+    check("倍率を変更", Difficulty::Relaxed.apply(&rules).decay.hunger == base.decay.hunger * 0.5);
+    let error = Rules::parse("[difficulty.hardcore]\nsleep_scale = 0\n").expect_err("エラーになるはず");
+    println!("{}", error);
+    check("睡眠時間の倍率の範囲をチェック", error.message.contains("sleep_scale") && error.line == Some(2));
+
+    // 設定ファイルで変えた値にも倍率をかける
+    let custom = Rules::parse("[decay]\npenalty_threshold = 20\n[sleep]\nduration_seconds = 1200\n[death]\nmortal = false\n")
+        .expect("読み込みに失敗");
+    let relaxed = Difficulty::Relaxed.apply(&custom);
+    let hardcore = Difficulty::Hardcore.apply(&custom);
+    println!(
+        "しきい値: のんびり {} / きびしい {}  睡眠: のんびり {}秒 / きびしい {}秒",
+        relaxed.decay.penalty_threshold, hardcore.decay.penalty_threshold, relaxed.sleep.duration_seconds, hardcore.sleep.duration_seconds
+    );
+    check("しきい値の変更が生きる", relaxed.decay.penalty_threshold == 10 && hardcore.decay.penalty_threshold == 27);
+    check("睡眠時間の変更が生きる", relaxed.sleep.duration_seconds == 600 && hardcore.sleep.duration_seconds == 1800);
+    check("お別れしない設定はきびしいでも生きる", !hardcore.death.mortal);
+}
+
+// 時間帯で減り方が変わらないよう、昼12時の時計を使う成猫
+fn adult_cat(rules: &Rules) -> Cat {
+    let mut cat = Cat::with_clock("ミケ".to_string(), Rc::new(ManualClock::at_noon_today()));
+    cat.set_rules(Rc::new(rules.clone()));
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    cat
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
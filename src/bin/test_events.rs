@@ -0,0 +1,168 @@
+use chrono::Duration;
+use neko_watch::app::App;
+use neko_watch::art;
+use neko_watch::cat::{Cat, CatEvent};
+use neko_watch::clock::{ManualClock, today_at};
+use neko_watch::events::{self, RandomEvent};
+use neko_watch::life::LifeStage;
+use neko_watch::rules::{EventRules, Rules};
+use neko_watch::save;
+use rand::SeedableRng;
+use rand_pcg::Pcg32;
+use std::rc::Rc;
+
+fn main() {
+    println!("=== 偶然の出来事テスト ===\n");
+
+    // 同じ種なら同じ出来事
+    let rules = EventRules::default();
+    let cat = adult_cat(&Rc::new(ManualClock::at_noon_today()));
+    let sequence = |seed| {
+        let mut rng = Pcg32::seed_from_u64(seed);
+        (0..2000).map(|_| events::roll(&cat, &rules, 1.0, &mut rng)).collect::<Vec<_>>()
+    };
+    let first = sequence(7);
+    let count = first.iter().flatten().count();
+    println!("2000分で{}回", count);
+    check("同じ種なら同じ出来事", sequence(7) == first);
+    check("違う種なら違う出来事", sequence(8) != first);
+    check("ときどき起きる", count > 20 && count < 200);
+    check("0分なら起きない", sequence_of(&cat, &rules, 0.0).iter().all(Option::is_none));
+
+    // 様子と時間帯で確率が変わる
+    println!("\n=== 確率 ===");
+    let always = EventRules { gift_chance: 1.0, ..EventRules::quiet() };
+    let mut cat = adult_cat(&Rc::new(ManualClock::at_noon_today()));
+    check("ご機嫌ならプレゼント", once(&cat, &always) == Some(RandomEvent::GiftMouse));
+    cat.set_status_for_test(90, 40, 90, 90);
+    check("ご機嫌でなければプレゼントしない", once(&cat, &always).is_none());
+    let cups = EventRules { cup_chance: 0.5, ..EventRules::quiet() };
+    cat.set_status_for_test(90, 20, 90, 90);
+    let bored = sequence_of(&cat, &cups, 1.0).iter().flatten().count();
+    cat.set_status_for_test(90, 90, 90, 90);
+    let content = sequence_of(&cat, &cups, 1.0).iter().flatten().count();
+    println!("コップを落とした回数: 退屈 {} / ご機嫌 {}", bored, content);
+    check("退屈なほどコップを落とす", bored > content * 3);
+    let sunny = EventRules { sunbeam_chance: 1.0, ..EventRules::quiet() };
+    check("昼は日だまり", once(&cat, &sunny) == Some(RandomEvent::Sunbeam));
+    let night = adult_cat(&Rc::new(ManualClock::new(today_at(23))));
+    check("夜は日だまりが無い", sequence_of(&night, &sunny, 1.0).iter().all(Option::is_none));
+    let storms = EventRules { storm_chance: 0.05, ..EventRules::quiet() };
+    let day_storms = sequence_of(&cat, &storms, 1.0).iter().flatten().count();
+    let night_storms = sequence_of(&night, &storms, 1.0).iter().flatten().count();
+    println!("雷: 昼 {} / 夜 {}", day_storms, night_storms);
+    check("雷は夜のほうが多い", night_storms > day_storms * 2);
+
+    // 寝ている間は起きない
+    let mut sleepy = adult_cat(&Rc::new(ManualClock::at_noon_today()));
+    sleepy.sleep();
+    check("寝ている間は起きない", once(&sleepy, &always).is_none());
+
+    // 起きたときの様子
+    println!("\n=== 起きたとき ===");
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut cat = adult_cat(&clock);
+    cat.set_status_for_test(90, 70, 90, 60);
+    cat.encounter(RandomEvent::Sunbeam);
+    check("ステータスが変わる", cat.happiness == 78 && cat.health == 65);
+    check("お知らせのイベント", cat.take_events().contains(&CatEvent::Encountered(RandomEvent::Sunbeam)));
+    check("出来事の最中", cat.incident() == Some(RandomEvent::Sunbeam));
+    check("出来事の最中は次が起きない", once(&cat, &always).is_none());
+    let art = cat.get_ascii_art();
+    println!("{}", art);
+    check("出来事のアニメーション", art == cat.art_with(art::sunbeam) && art.split('\n').count() == 6);
+    let effects: Vec<String> = (0..4)
+        .map(|frame| art::sunbeam(frame).effect.clone())
+        .collect();
+    check("6行目のメッセージが変わる", effects.iter().any(|effect| effect.contains("ひなたぼっこ")) && effects[0] != effects[1]);
+    pass(&mut cat, &clock, 110);
+    check("しばらく続く", cat.incident().is_some());
+    pass(&mut cat, &clock, 10);
+    check("2分で終わる", cat.incident().is_none() && cat.get_ascii_art() != cat.art_with(art::sunbeam));
+    cat.encounter(RandomEvent::Thunderstorm);
+    cat.play();
+    check("お世話すると終わる", cat.incident().is_none());
+    for event in [RandomEvent::GiftMouse, RandomEvent::KnockedCup, RandomEvent::Thunderstorm, RandomEvent::Sunbeam] {
+        let animation = event.rule().animation;
+        check(
+            &format!("{}のアニメーションは4コマ", event.label()),
+            (0..4).all(|frame| animation(frame).effect != animation(frame + 1).effect),
+        );
+    }
+
+    // App: 種が同じなら同じ出来事が起きる
+    println!("\n=== App ===");
+    let run = || {
+        let clock = Rc::new(ManualClock::at_noon_today());
+        let mut app = App::from_save(None, clock.clone(), Rc::new(Rules::default()), Some(3));
+        let mut happened = Vec::new();
+        for _ in 0..360 {
+            app.cat_mut().set_status_for_test(90, 90, 90, 90);
+            clock.advance(Duration::seconds(10));
+            app.tick();
+            if let Some(event) = app.cat().incident()
+                && happened.last() != Some(&event)
+            {
+                happened.push(event);
+            }
+        }
+        (happened, app)
+    };
+    let (happened, app) = run();
+    println!("1時間の出来事: {:?}", happened);
+    check("同じ種なら同じ出来事が起きる", run().0 == happened);
+    let notice = app.notice.as_ref().map(|notice| notice.text.clone()).unwrap_or_default();
+    println!("{}", notice);
+
+    // 出来事の最中でもセーブされる
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut app = App::with_clock(adult_cat(&clock), clock.clone());
+    app.cat_mut().encounter(RandomEvent::GiftMouse);
+    app.tick();
+    let notice = app.notice.as_ref().map(|notice| notice.text.clone()).unwrap_or_default();
+    check("名前付きのお知らせ", notice.contains("ネズミをプレゼント"));
+    let path = std::env::temp_dir().join("neko-watch-test").join("events.json");
+    save::save_to(&path, &app.to_save()).expect("セーブに失敗");
+    let loaded = save::load_from(&path).expect("ロードに失敗");
+    let restored = App::from_save(loaded, clock, Rc::new(Rules::default()), None);
+    check("出来事を復元", restored.cat().incident() == Some(RandomEvent::GiftMouse));
+    let _ = std::fs::remove_file(&path);
+
+    // 設定ファイルで変えられる
+    let rules = Rules::parse("[events]\nstorm_chance = 0.1\n").expect("読み込みに失敗");
+    check("確率を変更", rules.events.storm_chance == 0.1);
+    let error = Rules::parse("[events]\ngift_chance = 2\n").expect_err("エラーになるはず");
+    println!("{}", error);
+    check("確率の範囲をチェック", error.message.contains("gift_chance") && error.line == Some(2));
+}
+
+// 1分ごとに1000回
+fn sequence_of(cat: &Cat, rules: &EventRules, minutes: f64) -> Vec<Option<RandomEvent>> {
+    let mut rng = Pcg32::seed_from_u64(1);
+    (0..1000).map(|_| events::roll(cat, rules, minutes, &mut rng)).collect()
+}
+
+fn once(cat: &Cat, rules: &EventRules) -> Option<RandomEvent> {
+    events::roll(cat, rules, 1.0, &mut Pcg32::seed_from_u64(1))
+}
+
+// ご機嫌な成猫
+fn adult_cat(clock: &Rc<ManualClock>) -> Cat {
+    let mut cat = Cat::with_clock("ミケ".to_string(), clock.clone());
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    cat.set_status_for_test(90, 90, 90, 90);
+    cat
+}
+
+// 10秒刻みで時間を進める
+fn pass(cat: &mut Cat, clock: &ManualClock, seconds: i64) {
+    for _ in 0..seconds / 10 {
+        clock.advance(Duration::seconds(10));
+        cat.decay(10.0);
+    }
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
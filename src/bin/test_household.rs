@@ -0,0 +1,118 @@
+use chrono::Duration;
+use crossterm::event::KeyCode;
+use neko_watch::app::{App, Screen, MAX_CATS};
+use neko_watch::cat::{Cat, CatState};
+use neko_watch::clock::ManualClock;
+use neko_watch::life::LifeStage;
+use neko_watch::rules::Rules;
+use neko_watch::save;
+use std::rc::Rc;
+
+fn main() {
+    println!("=== 多頭飼いテスト ===\n");
+
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut app = App::with_clock(adult_cat("ミケ"), clock.clone());
+    check("最初は1匹", app.cats.len() == 1 && app.focus == 0);
+
+    // [a] で2匹目を迎える
+    adopt(&mut app, "クロ");
+    check("2匹目を迎える", app.cats.len() == 2 && app.cat().name == "クロ");
+    check("迎えた猫を選択", app.focus == 1 && app.screen == Screen::Main);
+
+    // Tab / Shift+Tab で切り替え
+    app.handle_key(KeyCode::Tab);
+    check("Tab で次の猫（先頭に戻る）", app.focus == 0 && app.cat().name == "ミケ");
+    app.handle_key(KeyCode::BackTab);
+    check("Shift+Tab で前の猫", app.focus == 1);
+
+    // お世話は選択中の猫だけ
+    println!("\n=== お世話は選択中の猫だけ ===");
+    app.handle_key(KeyCode::Char('2'));
+    app.handle_key(KeyCode::Enter);
+    check("クロと遊んだ", app.cats[1].care.played == 1);
+    check("ミケとは遊んでいない", app.cats[0].care.played == 0);
+
+    // 全員の時間が進む
+    for cat in &mut app.cats {
+        cat.set_status_for_test(80, 80, 80, 80);
+    }
+    for _ in 0..20 {
+        clock.advance(Duration::seconds(30));
+        app.tick();
+    }
+    println!("10分後の空腹度: ミケ {} / クロ {}", app.cats[0].hunger, app.cats[1].hunger);
+    check("選択していない猫もお腹が空く", app.cats[0].hunger < 80 && app.cats[1].hunger < 80);
+
+    // 上限まで
+    adopt(&mut app, "シロ");
+    adopt(&mut app, "トラ");
+    check("4匹まで迎えられる", app.cats.len() == MAX_CATS);
+    app.handle_key(KeyCode::Char('a'));
+    check("それ以上は迎えられない", app.screen == Screen::Main && app.cats.len() == MAX_CATS);
+    check("上限のお知らせ", app.notice.as_ref().is_some_and(|notice| notice.text.contains("4匹まで")));
+
+    // セーブ・ロードで全員と選択中の猫を復元
+    println!("\n=== セーブ ===");
+    app.handle_key(KeyCode::Tab);
+    app.handle_key(KeyCode::Tab);
+    let path = std::env::temp_dir().join("neko-watch-test").join("household.json");
+    save::save_to(&path, &app.to_save()).expect("セーブに失敗");
+    let loaded = save::load_from(&path).expect("ロードに失敗");
+    let restored = App::from_save(loaded, clock.clone(), Rc::new(Rules::default()), None);
+    let names: Vec<&str> = restored.cats.iter().map(|cat| cat.name.as_str()).collect();
+    check("全員を復元", names == vec!["ミケ", "クロ", "シロ", "トラ"]);
+    check("選択中の猫を復元", restored.focus == 1 && restored.cat().name == "クロ");
+    let _ = std::fs::remove_file(&path);
+
+    // 選択していない猫とのお別れ: その猫に切り替わり、見送るとほかの猫へ戻る
+    println!("\n=== お別れ ===");
+    app.cats[2].set_status_for_test(100, 5, 100, 0);
+    for _ in 0..200 {
+        for (index, cat) in app.cats.iter_mut().enumerate() {
+            if index != 2 {
+                cat.set_status_for_test(100, 100, 100, 100);
+            }
+        }
+        clock.advance(Duration::seconds(10));
+        app.tick();
+    }
+    check("お別れした猫に切り替わる", app.screen == Screen::GameOver && app.cat().name == "シロ");
+    check("思い出に残る", app.memorial.len() == 1);
+    check("ほかの猫は元気", app.cats[0].outcome().is_none() && app.cats[3].outcome().is_none());
+    app.handle_key(KeyCode::Esc);
+    check("Esc で見送る", app.cats.len() == 3 && app.screen == Screen::Main);
+    check("残った猫を選択", app.cat().name == "トラ");
+
+    // 最後の1匹は見送れず、新しい猫と入れ替える
+    let mut app = App::with_clock(adult_cat("ひとり猫"), clock.clone());
+    app.cat_mut().set_status_for_test(100, 5, 100, 0);
+    for _ in 0..200 {
+        clock.advance(Duration::seconds(10));
+        app.tick();
+    }
+    app.handle_key(KeyCode::Esc);
+    check("最後の1匹は見送れない", app.screen == Screen::GameOver && app.cats.len() == 1);
+    app.handle_key(KeyCode::Char('n'));
+    app.handle_key(KeyCode::Enter);
+    check("新しい猫と入れ替える", app.cats.len() == 1 && app.cat().get_state() != CatState::Dead);
+}
+
+fn adult_cat(name: &str) -> Cat {
+    let mut cat = Cat::with_clock(name.to_string(), Rc::new(ManualClock::at_noon_today()));
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    cat
+}
+
+fn adopt(app: &mut App, name: &str) {
+    app.handle_key(KeyCode::Char('a'));
+    for c in name.chars() {
+        app.handle_key(KeyCode::Char(c));
+    }
+    app.handle_key(KeyCode::Enter);
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
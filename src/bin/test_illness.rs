@@ -0,0 +1,213 @@
+use chrono::{Duration, Local};
+use crossterm::event::KeyCode;
+use neko_watch::app::App;
+use neko_watch::cat::{Cat, CatEvent, CatState};
+use neko_watch::clock::ManualClock;
+use neko_watch::illness::{Illness, Severity};
+use neko_watch::items::ItemKind;
+use neko_watch::life::LifeStage;
+use neko_watch::rules::{EventRules, Rules};
+use neko_watch::save::{self, SaveData};
+use std::rc::Rc;
+
+fn main() {
+    println!("=== 病気テスト ===\n");
+
+    // 健康度の低い状態が30分続くと風邪（瀕死にならないよう5分ごとに戻す）
+    let mut cat = adult_cat("風邪猫", Rules::default());
+    for _ in 0..5 {
+        cat.set_status_for_test(100, 100, 100, 35);
+        steps(&mut cat, 5.0 * 60.0);
+    }
+    check("25分ではまだ元気", cat.illness().is_none());
+    cat.set_status_for_test(100, 100, 100, 35);
+    steps(&mut cat, 5.0 * 60.0);
+    check("30分で風邪", cat.illness() == Some(Illness::Cold) && cat.get_state() == CatState::Cold);
+    check("風邪をひいたイベント", illness_events(&mut cat) == vec![CatEvent::FellIll(Illness::Cold)]);
+    println!("{}", cat.get_ascii_art());
+    check("症状は6行のアート", cat.get_ascii_art().split('\n').count() == 6);
+
+    // 時間が経つと重くなる
+    check("最初は軽い", cat.severity() == Some(Severity::Mild));
+    cared_steps(&mut cat, 3);
+    check("30分で中くらい", cat.severity() == Some(Severity::Moderate));
+    check("重くなったイベント", illness_events(&mut cat) == vec![CatEvent::Worsened(Illness::Cold, Severity::Moderate)]);
+    cared_steps(&mut cat, 6);
+    check("90分で重い", cat.severity() == Some(Severity::Severe));
+
+    // 病気だとステータスが速く減る
+    let mut healthy = adult_cat("元気猫", Rules::default());
+    healthy.set_status_for_test(100, 100, 100, 100);
+    cat.set_status_for_test(100, 100, 100, 100);
+    healthy.decay(60.0);
+    cat.decay(60.0);
+    println!("1分後の健康度: 元気 {} / 風邪(重い) {}", healthy.health, cat.health);
+    check("重い風邪は健康度が速く減る", cat.health < healthy.health);
+
+    // 薬: 重い病気は中くらいまで和らぐだけ
+    cat.take_events();
+    cat.medicate();
+    check("重い風邪は薬で中くらいに", cat.severity() == Some(Severity::Moderate));
+    check("和らいだイベント", illness_events(&mut cat) == vec![CatEvent::Eased(Illness::Cold)]);
+    cat.medicate();
+    check("もう一度飲めば治る", cat.illness().is_none() && cat.get_state() != CatState::Cold);
+    check("治ったイベント", illness_events(&mut cat) == vec![CatEvent::Cured(Illness::Cold)]);
+
+    // 食べすぎで腹痛
+    println!("\n=== 腹痛 ===");
+    let mut cat = adult_cat("食べすぎ猫", Rules::default());
+    cat.set_status_for_test(95, 80, 80, 80);
+    cat.feed();
+    check("満腹で1回ならまだ平気", cat.illness().is_none());
+    cat.feed();
+    check("続けて食べすぎると腹痛", cat.illness() == Some(Illness::UpsetStomach));
+    check("腹痛の状態", cat.get_state() == CatState::UpsetStomach);
+
+    let mut cat = adult_cat("間をあける猫", Rules::default());
+    cat.set_status_for_test(95, 80, 80, 80);
+    cat.feed();
+    steps(&mut cat, 11.0 * 60.0);
+    cat.set_status_for_test(95, 80, 80, 80);
+    cat.feed();
+    check("10分以上あければ腹痛にならない", cat.illness().is_none());
+
+    // 病気は1つずつ
+    let mut cat = adult_cat("ひとつだけ猫", Rules::default());
+    overfeed(&mut cat);
+    cat.set_status_for_test(100, 100, 100, 10);
+    steps(&mut cat, 40.0 * 60.0);
+    check("病気中は別の病気にかからない", cat.illness() == Some(Illness::UpsetStomach));
+
+    // 不潔な状態が続くと皮膚炎（風邪にならないよう健康度は保つ）
+    println!("\n=== 皮膚炎・毛玉 ===");
+    let mut cat = adult_cat("不潔猫", Rules::default());
+    for _ in 0..6 {
+        cat.set_status_for_test(100, 100, 15, 100);
+        steps(&mut cat, 10.0 * 60.0);
+    }
+    check("不潔が60分続くと皮膚炎", cat.illness() == Some(Illness::SkinTrouble));
+    cat.set_status_for_test(100, 100, 15, 100);
+    check("皮膚炎の状態（瀕死でなければ症状が見える）", cat.get_state() == CatState::SkinTrouble);
+
+    // お風呂に入らないと毛玉。お風呂に入れば数え直し
+    let mut rules = Rules::default();
+    rules.illness.hairball_hours = 1.0;
+    let mut cat = adult_cat("毛玉猫", rules);
+    cared_steps(&mut cat, 5);
+    cat.bathe();
+    cared_steps(&mut cat, 1);
+    check("お風呂に入れば毛玉にならない", cat.illness().is_none());
+    cared_steps(&mut cat, 5);
+    check("1時間お風呂に入らないと毛玉", cat.illness() == Some(Illness::Hairball));
+
+    // 動物病院
+    println!("\n=== 動物病院 ===");
+    let clock = Rc::new(ManualClock::at_noon_today());
+    // 偶然の出来事のお知らせで上書きされないよう、出来事は起きないようにする
+    let quiet = Rules { events: EventRules::quiet(), ..Rules::default() };
+    let mut app = App::with_clock(adult_cat("通院猫", quiet), clock.clone());
+    app.handle_key(KeyCode::Char('v'));
+    check("病気でなければ行かない", app.notice.as_ref().is_some_and(|notice| notice.text.contains("病気ではありません")));
+
+    overfeed(app.cat_mut());
+    app.tick();
+    check("病気のお知らせ", app.notice.as_ref().is_some_and(|notice| notice.text.contains("腹痛")));
+    app.coins = 10;
+    app.handle_key(KeyCode::Char('v'));
+    check("診察代が足りなければ行けない", !app.cat().is_at_vet() && app.coins == 10);
+
+    app.coins = 100;
+    app.cat_mut().set_status_for_test(50, 50, 50, 20);
+    app.handle_key(KeyCode::Char('v'));
+    check("診察代を払って病院へ", app.cat().is_at_vet() && app.coins == 20);
+    check("通院中の状態", app.cat().get_state() == CatState::AtVet);
+    check("通院中はお世話できない", !app.cat().can_perform_action());
+    advance(&mut app, &clock, 20);
+    check("20分ではまだ病院", app.cat().is_at_vet() && app.cat().health == 20);
+    advance(&mut app, &clock, 10);
+    check("30分で治って帰ってくる", !app.cat().is_at_vet() && app.cat().illness().is_none());
+    check("健康度が回復", app.cat().health >= 60);
+    check("治ったお知らせ", app.notice.as_ref().is_some_and(|notice| notice.text.contains("治りました")));
+
+    // [5] の薬でも治る
+    app.inventory.add(ItemKind::Medicine, 1);
+    app.cat_mut().set_status_for_test(50, 50, 50, 50);
+    overfeed(app.cat_mut());
+    app.handle_key(KeyCode::Char('5'));
+    check("[5] の薬で軽い腹痛が治る", app.cat().illness().is_none());
+    check("治ったお知らせ（薬）", app.notice.as_ref().is_some_and(|notice| notice.text.contains("治りました")));
+
+    // 病気はセーブされる
+    println!("\n=== セーブ ===");
+    let mut cat = adult_cat("セーブ猫", Rules::default());
+    overfeed(&mut cat);
+    let path = std::env::temp_dir().join("neko-watch-test").join("illness.json");
+    save::save_to(&path, &SaveData::new(cat, Local::now())).expect("セーブに失敗");
+    let loaded = save::load_from(&path).expect("ロードに失敗").expect("セーブが無い");
+    check("病気を復元", loaded.cats[0].illness() == Some(Illness::UpsetStomach));
+    let _ = std::fs::remove_file(&path);
+
+    // 設定ファイルで変えられる
+    let rules = Rules::parse("[illness]\nvet_price = 5\n").expect("読み込みに失敗");
+    check("診察代を変更", rules.illness.vet_price == 5);
+    let error = Rules::parse("[illness]\nmoderate_minutes = 60\nsevere_minutes = 30\n").expect_err("エラーになるはず");
+    println!("{}", error);
+    check("重くなる時間の順序をチェック", error.message.contains("severe_minutes") && error.line == Some(3));
+}
+
+fn adult_cat(name: &str, rules: Rules) -> Cat {
+    let mut cat = Cat::with_clock(name.to_string(), Rc::new(ManualClock::at_noon_today()));
+    cat.set_rules(Rc::new(rules));
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    cat
+}
+
+// 留守中と同じく10秒ずつ進める
+fn steps(cat: &mut Cat, seconds: f64) {
+    let mut remaining = seconds;
+    while remaining > 0.0 {
+        let step = remaining.min(10.0);
+        cat.decay(step);
+        remaining -= step;
+    }
+}
+
+// 10分ごとにステータスを満タンに戻しながら進める
+fn cared_steps(cat: &mut Cat, count: usize) {
+    for _ in 0..count {
+        cat.set_status_for_test(100, 100, 100, 100);
+        steps(cat, 10.0 * 60.0);
+    }
+}
+
+// 満腹で2回食べて腹痛にする
+fn overfeed(cat: &mut Cat) {
+    cat.set_status_for_test(95, cat.happiness, cat.cleanliness, cat.health);
+    cat.feed();
+    cat.feed();
+}
+
+// 留守中の様子が出ないよう30秒ずつ進める
+fn advance(app: &mut App, clock: &ManualClock, minutes: i64) {
+    for _ in 0..minutes * 2 {
+        clock.advance(Duration::seconds(30));
+        app.tick();
+    }
+}
+
+fn illness_events(cat: &mut Cat) -> Vec<CatEvent> {
+    cat.take_events()
+        .into_iter()
+        .filter(|event| {
+            matches!(
+                event,
+                CatEvent::FellIll(_) | CatEvent::Worsened(..) | CatEvent::Eased(_) | CatEvent::Cured(_)
+            )
+        })
+        .collect()
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
@@ -0,0 +1,126 @@
+use chrono::Local;
+use crossterm::event::KeyCode;
+use neko_watch::app::{App, Screen};
+use neko_watch::cat::Cat;
+use neko_watch::clock::ManualClock;
+use neko_watch::items::{Category, Inventory, ItemKind};
+use neko_watch::life::LifeStage;
+use neko_watch::rules::Rules;
+use neko_watch::save::{self, SaveData};
+use std::rc::Rc;
+
+fn main() {
+    println!("=== 持ち物テスト ===\n");
+
+    // 在庫と消費
+    let mut inventory = Inventory::empty();
+    check("最初は空", inventory.quantity(ItemKind::WetFood) == 0);
+    check("在庫が無ければ使えない", !inventory.consume(ItemKind::WetFood));
+    inventory.add(ItemKind::WetFood, 2);
+    check("ウェットフード1回で1個減る", inventory.consume(ItemKind::WetFood) && inventory.quantity(ItemKind::WetFood) == 1);
+
+    inventory.add(ItemKind::DryFood, 1);
+    for _ in 0..9 {
+        inventory.consume(ItemKind::DryFood);
+    }
+    println!("{}", inventory.describe(ItemKind::DryFood));
+    check("ドライフードは1袋10回分", inventory.quantity(ItemKind::DryFood) == 1 && inventory.remaining_uses(ItemKind::DryFood) == 1);
+    inventory.consume(ItemKind::DryFood);
+    check("10回で1袋なくなる", inventory.quantity(ItemKind::DryFood) == 0);
+
+    inventory.add(ItemKind::FeatherToy, 1);
+    for _ in 0..4 {
+        inventory.consume(ItemKind::FeatherToy);
+    }
+    check("ねこじゃらしは5回で壊れる（4回ではまだ使える）", inventory.quantity(ItemKind::FeatherToy) == 1);
+
+    // アイテムごとの効果
+    println!("\n=== アイテムの効果 ===");
+    let mut cat = adult_cat();
+    cat.feed_item(ItemKind::WetFood);
+    check("ウェットフード: 空腹度+50", cat.hunger == 100);
+    check("ウェットフード: 幸福度+20", cat.happiness == 70);
+    check("餌をあげた回数", cat.care.fed == 1 && cat.is_recently_fed());
+
+    let mut cat = adult_cat();
+    cat.feed();
+    check("ドライフード: [feed] の効果", cat.hunger == 90 && cat.happiness == 60 && cat.cleanliness == 40);
+
+    let mut cat = adult_cat();
+    cat.feed_with(None);
+    check("ありあわせのごはん: 空腹度+25", cat.hunger == 75 && cat.happiness == 50 && cat.care.fed == 1);
+
+    let mut cat = adult_cat();
+    cat.play_with(Some(ItemKind::Catnip));
+    check("またたび: 幸福度+45", cat.happiness == 95);
+    let mut cat = adult_cat();
+    cat.play();
+    check("手で遊ぶ: 幸福度+25", cat.happiness == 75 && cat.care.played == 1);
+
+    // 選択画面
+    println!("\n=== 選択画面 ===");
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut app = App::with_clock(adult_cat(), clock);
+    app.inventory = Inventory::empty();
+    app.inventory.add(ItemKind::WetFood, 1);
+
+    app.handle_key(KeyCode::Char('1'));
+    check("[1] でごはん選択", app.screen == Screen::Picker { category: Category::Food, selected: 0 });
+    app.handle_key(KeyCode::Enter);
+    check("在庫の無いドライフードは選べない", matches!(app.screen, Screen::Picker { .. }) && app.cat().care.fed == 0);
+    check("在庫切れのお知らせ", app.notice.as_ref().is_some_and(|notice| notice.text.contains("ありません")));
+    app.handle_key(KeyCode::Down);
+    app.handle_key(KeyCode::Enter);
+    check("ウェットフードをあげた", app.screen == Screen::Main && app.cat().care.fed == 1);
+    check("ウェットフードを消費", app.inventory.quantity(ItemKind::WetFood) == 0);
+    app.handle_key(KeyCode::Char('1'));
+    for _ in 0..3 {
+        app.handle_key(KeyCode::Down);
+    }
+    app.handle_key(KeyCode::Enter);
+    check("ごはんが無くてもありあわせのごはんをあげられる", app.screen == Screen::Main && app.cat().care.fed == 2);
+
+    app.handle_key(KeyCode::Char('2'));
+    check("[2] で遊び方選択", app.screen == Screen::Picker { category: Category::Toy, selected: 0 });
+    app.handle_key(KeyCode::Up);
+    app.handle_key(KeyCode::Enter);
+    check("手で遊ぶのはいつでもできる", app.screen == Screen::Main && app.cat().care.played == 1);
+
+    app.handle_key(KeyCode::Char('2'));
+    app.handle_key(KeyCode::Esc);
+    check("Esc で戻る", app.screen == Screen::Main && app.cat().care.played == 1);
+
+    // 持ち物はセーブされる
+    let path = std::env::temp_dir().join("neko-watch-test").join("items.json");
+    app.inventory.add(ItemKind::Ball, 2);
+    save::save_to(&path, &app.to_save()).expect("セーブに失敗");
+    let loaded = save::load_from(&path).expect("ロードに失敗").expect("セーブが無い");
+    check("持ち物を復元", loaded.inventory == app.inventory);
+    let _ = std::fs::remove_file(&path);
+
+    // 持ち物の無い古いセーブデータは最初の持ち物
+    let old = SaveData::new(Cat::new("古猫".to_string()), Local::now());
+    let mut json: serde_json::Value = serde_json::to_value(&old).expect("変換に失敗");
+    json.as_object_mut().expect("オブジェクト").remove("inventory");
+    let data: SaveData = serde_json::from_value(json).expect("古いセーブデータを読めない");
+    check("古いセーブデータは最初の持ち物", data.inventory == Inventory::default());
+
+    // 設定ファイルで効果を変えられる
+    let rules = Rules::parse("[items.catnip]\nhappiness = 10\n").expect("読み込みに失敗");
+    let mut cat = adult_cat();
+    cat.set_rules(Rc::new(rules));
+    cat.play_with(Some(ItemKind::Catnip));
+    check("またたびの効果を変更", cat.happiness == 60);
+}
+
+fn adult_cat() -> Cat {
+    let mut cat = Cat::with_clock("持ち物テスト猫".to_string(), Rc::new(ManualClock::at_noon_today()));
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    cat.set_status_for_test(50, 50, 50, 50);
+    cat
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
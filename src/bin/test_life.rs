@@ -0,0 +1,83 @@
+use chrono::Duration;
+use neko_watch::app::App;
+use neko_watch::cat::{Cat, CatEvent};
+use neko_watch::clock::{ManualClock, today_at};
+use neko_watch::life::LifeStage;
+use neko_watch::rules::Rules;
+use neko_watch::save::SaveData;
+use std::rc::Rc;
+
+fn main() {
+    println!("=== 成長段階テスト ===\n");
+
+    let mut cat = Cat::with_clock("成長テスト猫".to_string(), Rc::new(ManualClock::at_noon_today()));
+    check("最初は子猫", cat.life_stage() == LifeStage::Kitten);
+
+    // 子猫はお腹が空きやすい（2.5/分 × 1.5）
+    cat.set_status_for_test(80, 80, 80, 80);
+    cat.decay(60.0 * 4.0);
+    check("子猫は4分で空腹度 80→65", cat.hunger == 65);
+
+    // 世話をしながら12時間で成猫（10分ごとに世話をして病気にならないようにする）
+    for _ in 0..72 {
+        cat.set_status_for_test(80, 80, 80, 80);
+        cat.decay(10.0 * 60.0);
+    }
+    check("12時間で成猫", cat.life_stage() == LifeStage::Adult);
+    check("成長イベント", stage_events(&mut cat) == vec![CatEvent::StageUp(LifeStage::Adult)]);
+    check("イベントは一度だけ", cat.take_events().is_empty());
+
+    // シニア猫は体調を崩しやすい（2/分 × 1.5）
+    cat.set_life_stage_for_test(LifeStage::Senior);
+    check("シニア猫", cat.life_stage() == LifeStage::Senior);
+    cat.set_status_for_test(80, 80, 80, 80);
+    cat.decay(60.0 * 4.0);
+    check("シニア猫は4分で健康度 80→68", cat.health == 68);
+
+    // 段階ごとのアート（6行）
+    println!("\n=== 段階ごとのアート ===");
+    for stage in [LifeStage::Kitten, LifeStage::Adult, LifeStage::Senior] {
+        let mut cat = Cat::new("アート猫".to_string());
+        cat.set_life_stage_for_test(stage);
+        let art = cat.get_ascii_art();
+        println!("{}:\n{}\n---", stage.label(), art);
+        check(&format!("{}は6行", stage.label()), art.split('\n').count() == 6);
+    }
+
+    // 成長すると App にお知らせが出る
+    println!("\n=== 成長のお知らせ ===");
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut cat = Cat::new("お知らせ猫".to_string());
+    // 12時間放置してもお別れにならないよう猶予を長くする
+    let mut rules = Rules::default();
+    rules.death.grace_minutes = 24.0 * 60.0;
+    cat.set_rules(Rc::new(rules));
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    let mut app = App::with_clock(cat, clock.clone());
+    app.cat_mut().set_life_stage_for_test(LifeStage::Kitten);
+    clock.advance(Duration::hours(12));
+    app.tick();
+    let notice = app.notice.as_ref().map(|notice| notice.text.clone());
+    println!("お知らせ: {:?}", notice);
+    check("成猫になったお知らせ", notice.is_some_and(|text| text.contains("成猫")));
+
+    // 育てた時間の無い古いセーブデータの猫は成猫から
+    println!("\n=== 古いセーブデータ ===");
+    let mut json = serde_json::to_value(SaveData::new(Cat::new("古猫".to_string()), today_at(12))).expect("変換に失敗");
+    json["cats"][0].as_object_mut().expect("オブジェクト").remove("age_seconds");
+    let data: SaveData = serde_json::from_value(json).expect("古いセーブデータを読めない");
+    check("古いセーブデータの猫は成猫", data.cats[0].life_stage() == LifeStage::Adult);
+}
+
+// コインなど他の出来事は除く
+fn stage_events(cat: &mut Cat) -> Vec<CatEvent> {
+    cat.take_events()
+        .into_iter()
+        .filter(|event| matches!(event, CatEvent::StageUp(_)))
+        .collect()
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
@@ -0,0 +1,103 @@
+use neko_watch::app::App;
+use neko_watch::cat::Cat;
+use neko_watch::clock::ManualClock;
+use neko_watch::dice::Dice;
+use neko_watch::life::LifeStage;
+use neko_watch::personality::{Personality, Trait};
+use neko_watch::rules::Rules;
+use rand::RngCore;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+fn main() {
+    println!("=== 性格テスト ===\n");
+
+    // 同じ種からは同じ性格
+    let a = Personality::generate(42);
+    let b = Personality::generate(42);
+    println!("seed 42: {}", a.label());
+    check("同じ種なら同じ性格", a == b);
+    check("性格は1つか2つ", (1..=2).contains(&a.traits.len()));
+
+    let mut seen = HashSet::new();
+    for seed in 0..200 {
+        let personality = Personality::generate(seed);
+        let unique: HashSet<Trait> = personality.traits.iter().copied().collect();
+        check_quiet(&format!("seed {} は重複しない", seed), unique.len() == personality.traits.len());
+        seen.extend(unique);
+    }
+    check("すべての性格が生まれうる", seen.len() == 5);
+
+    // 性格なし（古いセーブデータ）は補正なし
+    let mut cat = cat_with(&[]);
+    cat.set_status_for_test(50, 50, 50, 50);
+    cat.bathe();
+    check("性格なし: お風呂で幸福度-30", cat.happiness == 20);
+    check("性格なしは「おだやか」", cat.personality.label() == "おだやか");
+
+    // 水好きはお風呂が平気
+    let mut cat = cat_with(&[Trait::WaterTolerant]);
+    cat.set_status_for_test(50, 50, 50, 50);
+    cat.bathe();
+    check("水好き: お風呂で幸福度-9", cat.happiness == 41);
+    check("水好き: お風呂で健康度-5", cat.health == 45);
+
+    // 遊び好きは遊ぶと大喜び
+    let mut cat = cat_with(&[Trait::Playful]);
+    cat.set_status_for_test(50, 50, 50, 50);
+    cat.play();
+    check("遊び好き: 遊ぶと幸福度+37", cat.happiness == 87);
+    check("遊び好き: 空腹度-18", cat.hunger == 32);
+
+    // 怠け者はよく眠る
+    let mut cat = cat_with(&[Trait::Lazy]);
+    cat.set_status_for_test(50, 50, 50, 10);
+    cat.sleep();
+    check("怠け者: 睡眠で健康度 50×1.2=60 まで回復", cat.nap().map(|nap| nap.goal as i32) == Some(60));
+
+    // 食いしん坊はお腹が空きやすい（2.5/分 × 1.3）
+    let mut cat = cat_with(&[Trait::Glutton]);
+    cat.set_status_for_test(80, 80, 80, 80);
+    cat.decay(60.0 * 4.0);
+    check("食いしん坊: 4分で空腹度 80→67", cat.hunger == 67);
+
+    // 複数の性格は掛け合わせる
+    let mut cat = cat_with(&[Trait::Playful, Trait::Shy]);
+    cat.set_status_for_test(50, 50, 50, 50);
+    cat.play();
+    check("遊び好き・人見知り: 幸福度 +25×1.5×0.7≒26", cat.happiness == 76);
+
+    // 新しい猫は種から性格が決まる
+    println!("\n=== 新しい猫の性格 ===");
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let app = App::from_save(None, clock.clone(), Rc::new(Rules::default()), Some(7));
+    println!("seed 7: {}", app.cat().personality.label());
+    // 番号の次に引いた乱数で決まる
+    let mut dice = Dice::new(7);
+    dice.next_u64();
+    check("--seed の性格", app.cat().personality == Personality::generate(dice.next_u64()));
+    let other = App::from_save(None, clock, Rc::new(Rules::default()), Some(7));
+    check("同じ種なら同じ猫", other.cat().personality == app.cat().personality);
+}
+
+fn cat_with(traits: &[Trait]) -> Cat {
+    let mut cat = Cat::with_clock("性格テスト猫".to_string(), Rc::new(ManualClock::at_noon_today()));
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    cat.personality = Personality {
+        seed: 0,
+        traits: traits.to_vec(),
+    };
+    cat
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
+
+// 失敗したときだけ表示する
+fn check_quiet(name: &str, ok: bool) {
+    if !ok {
+        check(name, ok);
+    }
+}
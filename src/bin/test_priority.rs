@@ -1,9 +1,11 @@
 use neko_watch::cat::{Cat, CatState};
+use neko_watch::clock::ManualClock;
+use std::rc::Rc;
 
 fn main() {
     println!("=== 状態優先順位テスト ===\n");
     
-    let mut cat = Cat::new("優先度テスト猫".to_string());
+    let mut cat = Cat::with_clock("優先度テスト猫".to_string(), Rc::new(ManualClock::at_noon_today()));
     
     // 複数条件が同時に満たされた場合の優先順位テスト
     println!("1. 瀕死 vs 他の状態（瀕死が最優先）");
@@ -36,6 +38,10 @@ fn main() {
     test_priority(&mut cat, 9, 50, 50, 50, CatState::Dying, "空腹度=9→瀕死");
     test_priority(&mut cat, 30, 50, 50, 50, CatState::Normal, "空腹度=30→空腹ではない");
     test_priority(&mut cat, 29, 50, 50, 50, CatState::Hungry, "空腹度=29→空腹");
+    
+    println!("\n8. 睡眠中は最優先");
+    cat.sleep();
+    test_priority(&mut cat, 5, 5, 5, 5, CatState::Sleeping, "瀕死でも睡眠中なら睡眠中");
 }
 
 fn test_priority(cat: &mut Cat, hunger: i32, happiness: i32, cleanliness: i32, health: i32, expected: CatState, description: &str) {
@@ -47,4 +53,4 @@ fn test_priority(cat: &mut Cat, hunger: i32, happiness: i32, cleanliness: i32, h
     if actual != expected {
         println!("  期待値: {:?}", expected);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,177 @@
+use chrono::{Duration, NaiveDate};
+use crossterm::event::KeyCode;
+use neko_watch::app::{App, Screen};
+use neko_watch::cat::{Cat, CatEvent, CatState};
+use neko_watch::clock::{ManualClock, today_at};
+use neko_watch::quests::{self, Care, Goal, QuestBoard, QUEST_POOL};
+use neko_watch::rules::{EventRules, QuestRules, Rules};
+use neko_watch::save;
+use neko_watch::states::Stat;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+fn main() {
+    println!("=== 毎日のクエストテスト ===\n");
+
+    // 日付から決まる
+    let rules = QuestRules::default();
+    let date = NaiveDate::from_ymd_opt(2025, 4, 1).expect("日付");
+    let today = quests::generate(date, &rules);
+    for quest in &today {
+        println!("{}", quest.goal.label());
+    }
+    check("1日3つ", today.len() == 3);
+    check("同じ日付なら同じクエスト", quests::generate(date, &rules) == today);
+    let goals: HashSet<String> = today.iter().map(|quest| quest.goal.label()).collect();
+    check("同じ日に同じクエストは出ない", goals.len() == 3);
+    let week: Vec<_> = (1..=7).map(|day| quests::generate(date + Duration::days(day), &rules)).collect();
+    check("日によって変わる", week.iter().any(|quests| *quests != today));
+    check(
+        "文言",
+        Goal::Care { care: Care::Play, times: 3, without: Some(Care::Bathe) }.label() == "お風呂に入れずに3回遊ぶ"
+            && Goal::KeepAbove { stat: Stat::Cleanliness, value: 50, minutes: 120 }.label()
+                == "清潔度を50以上のまま2時間保つ"
+            && Goal::Reach { state: CatState::Full, times: 2 }.label() == "満腹に2回する",
+    );
+
+    // すべての候補を出して、それぞれの進み方を確かめる
+    let all = QuestRules { per_day: QUEST_POOL.len(), ..QuestRules::default() };
+    let play = Goal::Care { care: Care::Play, times: 3, without: Some(Care::Bathe) };
+    let clean = Goal::KeepAbove { stat: Stat::Cleanliness, value: 50, minutes: 120 };
+    let full = Goal::Reach { state: CatState::Full, times: 2 };
+
+    println!("\n=== お世話の回数 ===");
+    let mut board = QuestBoard::default();
+    board.observe(&CatEvent::Played, today_at(12), &all);
+    board.observe(&CatEvent::Played, today_at(12), &all);
+    check("2回遊んだ", progress(&board, play) == 2.0);
+    board.observe(&CatEvent::Bathed { calm: true }, today_at(12), &all);
+    check("お風呂に入れると数え直す", progress(&board, play) == 0.0);
+    let mut done = Vec::new();
+    for _ in 0..3 {
+        done.extend(board.observe(&CatEvent::Played, today_at(12), &all));
+    }
+    check("3回遊ぶと達成", done.iter().any(|quest| quest.goal == play));
+    board.observe(&CatEvent::Bathed { calm: true }, today_at(12), &all);
+    check("達成したら数え直さない", progress(&board, play) == 3.0);
+
+    println!("\n=== ステータスを保つ ===");
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut cats = vec![Cat::with_clock("ミケ".to_string(), clock.clone())];
+    let mut board = QuestBoard::default();
+    for _ in 0..60 {
+        cats[0].set_status_for_test(60, 70, 90, 90);
+        board.step(&cats, 60.0, today_at(12), &all);
+    }
+    check("1時間保った", progress(&board, clean) == 3600.0);
+    cats[0].set_status_for_test(60, 70, 40, 90);
+    board.step(&cats, 60.0, today_at(12), &all);
+    check("下回ると数え直す", progress(&board, clean) == 0.0);
+    let mut done = Vec::new();
+    for _ in 0..120 {
+        cats[0].set_status_for_test(60, 70, 90, 90);
+        done.extend(board.step(&cats, 60.0, today_at(12), &all));
+    }
+    check("2時間保つと達成", done.iter().any(|quest| quest.goal == clean));
+    let mut kitten = Cat::with_clock("クロ".to_string(), clock.clone());
+    kitten.set_status_for_test(60, 70, 40, 90);
+    cats.push(kitten);
+    let mut board = QuestBoard::default();
+    board.step(&cats, 60.0, today_at(12), &all);
+    check("家族みんなが保っていないと進まない", progress(&board, clean) == 0.0);
+
+    println!("\n=== 状態にする ===");
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut cats = vec![Cat::with_clock("ミケ".to_string(), clock.clone())];
+    let mut board = QuestBoard::default();
+    let mut done = Vec::new();
+    for _ in 0..2 {
+        cats[0].set_status_for_test(60, 70, 90, 90);
+        board.step(&cats, 1.0, today_at(12), &all);
+        cats[0].feed();
+        check("餌をあげると満腹", cats[0].get_state() == CatState::Full);
+        done.extend(board.step(&cats, 1.0, today_at(12), &all));
+        clock.advance(Duration::seconds(60));
+    }
+    check("満腹に2回すると達成", done.iter().any(|quest| quest.goal == full));
+
+    // 倍速で1回の更新が長くても、途中で通った状態を数える
+    let rules = Rules { quests: all.clone(), events: EventRules::quiet(), ..Rules::default() };
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut app = App::from_save(None, clock.clone(), Rc::new(rules), Some(1));
+    app.cat_mut().set_status_for_test(60, 70, 90, 90);
+    app.tick();
+    app.cat_mut().feed();
+    clock.advance(Duration::seconds(40));
+    app.tick();
+    check("更新のときには満腹が終わっている", app.cat().get_state() != CatState::Full);
+    check("途中の満腹を数える", progress_of(&app, full) == 1.0);
+
+    // App: 達成するとコインがもらえ、日付が変わると新しいクエストになる
+    println!("\n=== ごほうびと日付 ===");
+    let rules = Rules { quests: all.clone(), ..Rules::default() };
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut app = App::from_save(None, clock.clone(), Rc::new(rules.clone()), Some(1));
+    app.tick();
+    check("今日のクエスト", app.quests.date() == Some(today_at(12).date_naive()) && app.quests.quests().len() == 9);
+    let start = app.coins;
+    for _ in 0..3 {
+        app.cat_mut().set_status_for_test(20, 70, 90, 90);
+        app.cat_mut().feed();
+        clock.advance(Duration::seconds(1));
+        app.tick();
+    }
+    let feed = Goal::Care { care: Care::Feed, times: 3, without: None };
+    check("3回餌をあげると達成", progress_of(&app, feed) == 3.0);
+    println!("達成: {}  コイン: {} → {}", app.quests.done_count(), start, app.coins);
+    check("達成した数だけコイン", app.coins == start + rules.quests.reward * app.quests.done_count() as u32);
+    let toast = app.toast.as_ref().map(|toast| toast.text.clone()).unwrap_or_default();
+    println!("{}", toast);
+    check("達成のトースト", toast.contains("クエスト達成: 3回餌をあげる"));
+
+    app.handle_key(KeyCode::Char('g'));
+    check("[g] でクエストの一覧", app.screen == Screen::Quests);
+    app.handle_key(KeyCode::Esc);
+    check("[Esc] で戻る", app.screen == Screen::Main);
+
+    // 進み具合はセーブされる
+    let path = std::env::temp_dir().join("neko-watch-test").join("quests.json");
+    save::save_to(&path, &app.to_save()).expect("セーブに失敗");
+    let loaded = save::load_from(&path).expect("ロードに失敗");
+    let restored = App::from_save(loaded, clock.clone(), Rc::new(rules.clone()), None);
+    check(
+        "クエストを復元",
+        restored.quests.date() == app.quests.date() && restored.quests.quests() == app.quests.quests(),
+    );
+    let _ = std::fs::remove_file(&path);
+
+    clock.advance(Duration::hours(24));
+    app.tick();
+    check("日付が変わると新しいクエスト", app.quests.date() == Some((today_at(12) + Duration::hours(24)).date_naive()));
+    check("進み具合は0から", progress_of(&app, feed) == 0.0);
+
+    // 設定ファイルで変えられる
+    let rules = Rules::parse("[quests]\nper_day = 5\nreward = 50\n").expect("読み込みに失敗");
+    check("数とコインを変更", rules.quests.per_day == 5 && rules.quests.reward == 50);
+    let error = Rules::parse("[quests]\nper_day = 0\n").expect_err("エラーになるはず");
+    println!("{}", error);
+    check("数の範囲をチェック", error.message.contains("per_day") && error.line == Some(2));
+}
+
+fn progress(board: &QuestBoard, goal: Goal) -> f64 {
+    board
+        .quests()
+        .iter()
+        .find(|quest| quest.goal == goal)
+        .map(|quest| quest.progress)
+        .unwrap_or(-1.0)
+}
+
+fn progress_of(app: &App, goal: Goal) -> f64 {
+    progress(&app.quests, goal)
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
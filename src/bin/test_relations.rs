@@ -0,0 +1,166 @@
+use chrono::Duration;
+use crossterm::event::KeyCode;
+use neko_watch::app::App;
+use neko_watch::cat::Cat;
+use neko_watch::clock::ManualClock;
+use neko_watch::life::LifeStage;
+use neko_watch::relations::{Happening, Interaction, Relations};
+use neko_watch::rules::{EventRules, RelationRules, Rules};
+use neko_watch::save;
+use std::rc::Rc;
+
+fn main() {
+    println!("=== 猫どうしの関係テスト ===\n");
+
+    // 15分ごとに一緒に遊ぶ
+    let rules = RelationRules::default();
+    let mut cats = vec![adult_cat("ミケ"), adult_cat("クロ")];
+    let mut relations = Relations::default();
+    check("10分ではまだ", relations.step(&mut cats, 10.0 * 60.0, &rules).is_empty());
+    let happenings = relations.step(&mut cats, 5.0 * 60.0, &rules);
+    check("15分で一緒に遊ぶ", interactions(&happenings) == vec![Interaction::PlayTogether]);
+    check("2匹とも幸福度+10", cats[0].happiness == 70 && cats[1].happiness == 70);
+    check("仲良し度 50→53", relations.affinity(cats[0].id, cats[1].id) == Some(53.0));
+
+    // 仲が良ければ汚れているほうを毛づくろい
+    let groom_rules = RelationRules { start_affinity: 70.0, ..RelationRules::default() };
+    let mut cats = vec![adult_cat("ミケ"), adult_cat("クロ")];
+    cats[1].set_status_for_test(60, 60, 30, 60);
+    let mut relations = Relations::default();
+    let happenings = relations.step(&mut cats, 15.0 * 60.0, &groom_rules);
+    check(
+        "きれいなミケが汚れたクロを毛づくろい",
+        happenings
+            == vec![Happening { interaction: Interaction::Groom, actor: cats[0].id, partner: cats[1].id }],
+    );
+    check("クロの清潔度+15", cats[1].cleanliness == 45);
+
+    // 仲が悪いと距離を置き、少しずつ慣れる
+    let cold_rules = RelationRules { start_affinity: 20.0, ..RelationRules::default() };
+    let mut cats = vec![adult_cat("ミケ"), adult_cat("クロ")];
+    let mut relations = Relations::default();
+    check("仲が悪いと遊ばない", relations.step(&mut cats, 15.0 * 60.0, &cold_rules).is_empty());
+    check("少し慣れる", relations.affinity(cats[0].id, cats[1].id) == Some(22.0));
+
+    // 寝ている猫とは関わらない
+    let mut cats = vec![adult_cat("ミケ"), adult_cat("クロ")];
+    cats[1].sleep();
+    let mut relations = Relations::default();
+    check("寝ている間は遊ばない", relations.step(&mut cats, 15.0 * 60.0, &rules).is_empty());
+
+    // ごはんの取り合い
+    println!("\n=== ごはんの取り合い ===");
+    let mut cats = vec![adult_cat("ミケ"), adult_cat("クロ")];
+    cats[1].set_status_for_test(30, 60, 60, 60);
+    let mut relations = Relations::default();
+    let happenings = relations.fed(&mut cats, 0, &rules);
+    check(
+        "お腹を空かせたクロが取り合いに来る",
+        happenings
+            == vec![Happening { interaction: Interaction::FoodFight, actor: cats[1].id, partner: cats[0].id }],
+    );
+    check("クロが少し横取り", cats[1].hunger == 40 && cats[0].hunger == 50);
+    check("仲良し度が下がる", relations.affinity(cats[0].id, cats[1].id) == Some(42.0));
+
+    let share_rules = RelationRules { start_affinity: 90.0, ..RelationRules::default() };
+    let mut relations = Relations::default();
+    check("大の仲良しなら分け合う", relations.fed(&mut cats, 0, &share_rules).is_empty());
+    let mut cats = vec![adult_cat("ミケ"), adult_cat("クロ")];
+    let mut relations = Relations::default();
+    check("お腹が空いていなければ来ない", relations.fed(&mut cats, 0, &rules).is_empty());
+
+    // やきもち
+    println!("\n=== やきもち ===");
+    let mut cats = vec![adult_cat("ミケ"), adult_cat("クロ")];
+    let mut relations = Relations::default();
+    relations.cared(&mut cats, 0, &rules);
+    relations.cared(&mut cats, 1, &rules);
+    relations.cared(&mut cats, 0, &rules);
+    check("交互に構えばやきもちを焼かない", relations.cared(&mut cats, 1, &rules).is_empty());
+    relations.cared(&mut cats, 0, &rules);
+    relations.cared(&mut cats, 0, &rules);
+    let happenings = relations.cared(&mut cats, 0, &rules);
+    check("ミケばかり3回構うとクロがすねる", interactions(&happenings) == vec![Interaction::Jealousy]);
+    check("クロの幸福度-15", cats[1].happiness == 45 && cats[0].happiness == 60);
+    check("数え直し", relations.cared(&mut cats, 0, &rules).is_empty());
+
+    // 家族にいない猫との関係は忘れる
+    let id = cats[1].id;
+    cats.remove(1);
+    relations.retain(&cats);
+    check("見送った猫との関係を忘れる", relations.affinity(cats[0].id, id).is_none());
+
+    // App: 2匹が並んで遊ぶ
+    println!("\n=== 2匹並んで表示 ===");
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut app = App::with_clock(adult_cat("ミケ"), clock.clone());
+    app.handle_key(KeyCode::Char('a'));
+    for c in "クロ".chars() {
+        app.handle_key(KeyCode::Char(c));
+    }
+    app.handle_key(KeyCode::Enter);
+    app.handle_key(KeyCode::Tab);
+    for _ in 0..30 {
+        for cat in &mut app.cats {
+            cat.set_status_for_test(80, 60, 80, 80);
+        }
+        clock.advance(Duration::seconds(30));
+        app.tick();
+    }
+    check("一緒に遊んだお知らせ", app.notice.as_ref().is_some_and(|notice| notice.text.contains("一緒に遊んで")));
+    let scene = app.scene().map(|(actor, partner, interaction)| (actor.name.clone(), partner.name.clone(), interaction));
+    check(
+        "選択中の猫の関わり合い",
+        scene == Some(("ミケ".to_string(), "クロ".to_string(), Interaction::PlayTogether)),
+    );
+    if let Some((actor, partner, interaction)) = app.scene() {
+        let rule = interaction.rule();
+        let art = neko_watch::art::side_by_side(
+            &actor.art_with(rule.actor_animation),
+            &partner.art_with(rule.partner_animation),
+        );
+        println!("{}", art);
+        check("並べても6行", art.split('\n').count() == 6);
+    }
+
+    // 構いすぎるとやきもち
+    for _ in 0..3 {
+        app.handle_key(KeyCode::Char('2'));
+        app.handle_key(KeyCode::Enter);
+    }
+    check("やきもちのお知らせ", app.notice.as_ref().is_some_and(|notice| notice.text.contains("すねて")));
+
+    // 関係はセーブされる
+    let affinity = app.relations.affinity(app.cats[0].id, app.cats[1].id);
+    let path = std::env::temp_dir().join("neko-watch-test").join("relations.json");
+    save::save_to(&path, &app.to_save()).expect("セーブに失敗");
+    let loaded = save::load_from(&path).expect("ロードに失敗");
+    let restored = App::from_save(loaded, clock, Rc::new(Rules::default()), None);
+    check("仲良し度を復元", restored.relations.affinity(restored.cats[0].id, restored.cats[1].id) == affinity);
+    let _ = std::fs::remove_file(&path);
+
+    // 設定ファイルで変えられる
+    let rules = Rules::parse("[relations]\nstart_affinity = 90\n").expect("読み込みに失敗");
+    check("最初の仲良し度を変更", rules.relations.start_affinity == 90.0);
+    let error = Rules::parse("[relations]\njealousy_gap = 0\n").expect_err("エラーになるはず");
+    println!("{}", error);
+    check("やきもちの回数をチェック", error.message.contains("jealousy_gap") && error.line == Some(2));
+}
+
+fn adult_cat(name: &str) -> Cat {
+    let mut cat = Cat::with_clock(name.to_string(), Rc::new(ManualClock::at_noon_today()));
+    // 偶然の出来事でお知らせが変わらないようにする
+    cat.set_rules(Rc::new(Rules { events: EventRules::quiet(), ..Rules::default() }));
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    cat.set_status_for_test(60, 60, 60, 60);
+    cat
+}
+
+fn interactions(happenings: &[Happening]) -> Vec<Interaction> {
+    happenings.iter().map(|happening| happening.interaction).collect()
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
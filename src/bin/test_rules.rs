@@ -0,0 +1,74 @@
+use neko_watch::cat::Cat;
+use neko_watch::clock::ManualClock;
+use neko_watch::life::LifeStage;
+use neko_watch::rules::Rules;
+use std::rc::Rc;
+
+fn main() {
+    println!("=== ゲームバランス設定テスト ===\n");
+
+    // 設定例は既定値と同じ
+    let example = Rules::parse(include_str!("../../docs/rules.example.toml"));
+    check("設定例を読み込める", example.is_ok());
+    check("設定例は既定値と同じ", example.ok() == Some(Rules::default()));
+
+    // 空のファイルは既定値
+    check("空の設定は既定値", Rules::parse("") == Ok(Rules::default()));
+
+    // 一部だけ変更
+    let rules = Rules::parse("[decay]\nhunger = 5.0\n").expect("読み込みに失敗");
+    check("空腹度の減少量を変更", rules.decay.hunger == 5.0);
+    check("他の項目は既定値", rules.decay.happiness == 2.0);
+
+    let mut cat = Cat::with_clock("設定テスト猫".to_string(), Rc::new(ManualClock::at_noon_today()));
+    cat.set_rules(Rc::new(rules));
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    cat.set_status_for_test(80, 80, 80, 80);
+    cat.decay(60.0);
+    check("1分で空腹度 80→75", cat.hunger == 75);
+
+    // 検証を通らない大きな秒数でも、餌やり・睡眠で落ちない
+    let mut rules = Rules::default();
+    rules.feed.full_seconds = i64::MAX;
+    rules.sleep.duration_seconds = i64::MAX;
+    let mut cat = Cat::with_clock("大きな秒数の猫".to_string(), Rc::new(ManualClock::at_noon_today()));
+    cat.set_rules(Rc::new(rules));
+    cat.feed();
+    let _ = cat.get_state();
+    check("大きな満腹時間でも落ちない", cat.is_recently_fed());
+    cat.sleep();
+    check("大きな睡眠時間でも落ちない", cat.is_sleeping());
+
+    // エラーには行番号が付く
+    println!("\n=== エラー表示 ===");
+    expect_error("[decay]\nhunger = \"たくさん\"\n", 2);
+    expect_error("[decay]\nhungry = 1.0\n", 2);
+    expect_error("[feed]\nhunger = 40\n\n[sleep]\nduration_seconds = 0\n", 5);
+    expect_error("[play]\nhappiness = 250\n", 2);
+    expect_error("[scraps]\nhunger = 250\n", 2);
+    expect_error("[feed]\nfull_seconds = 9223372036854775807\n", 2);
+    expect_error("[sleep]\nduration_seconds = 86401\n", 2);
+    expect_error("[decay\nhunger = 1.0\n", 1);
+
+    // inf は数として読めても受け付けない
+    println!("\n=== 無限大 ===");
+    expect_error("[death]\ngrace_minutes = inf\n", 2);
+    expect_error("[daytime]\ndoze_after_minutes = inf\n", 2);
+    expect_error("[life]\nsenior_hours = inf\n", 2);
+    expect_error("[difficulty.relaxed]\ndecay_scale = inf\n", 2);
+}
+
+fn expect_error(source: &str, line: usize) {
+    match Rules::parse(source) {
+        Ok(_) => check(&format!("{}行目でエラー（エラーにならなかった）", line), false),
+        Err(err) => {
+            println!("  {}", err);
+            check(&format!("{}行目でエラー", line), err.line == Some(line));
+        }
+    }
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
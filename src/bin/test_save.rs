@@ -0,0 +1,50 @@
+use chrono::Local;
+use neko_watch::cat::Cat;
+use neko_watch::save::{self, SaveData};
+
+fn main() {
+    println!("=== セーブ/ロードテスト ===\n");
+
+    let path = std::env::temp_dir().join("neko-watch-test").join("save.json");
+
+    let mut cat = Cat::new("セーブテスト猫".to_string());
+    cat.set_status_for_test(42, 55, 66, 77);
+    cat.feed();
+    cat.sleep();
+
+    save::save_to(&path, &SaveData::new(cat.clone(), Local::now())).expect("セーブに失敗");
+    println!("保存先: {}", path.display());
+
+    let loaded = save::load_from(&path)
+        .expect("ロードに失敗")
+        .expect("セーブファイルが見つからない");
+    let restored = &loaded.cats[0];
+
+    check("名前", restored.name == cat.name);
+    check("空腹度", restored.hunger == cat.hunger);
+    check("幸福度", restored.happiness == cat.happiness);
+    check("清潔度", restored.cleanliness == cat.cleanliness);
+    check("健康度", restored.health == cat.health);
+    check("睡眠中のまま", restored.is_sleeping());
+    check("状態", restored.get_state() == cat.get_state());
+
+    println!("\n復元後: {}", restored.debug_status());
+
+    let missing = std::env::temp_dir().join("neko-watch-test").join("missing.json");
+    check("セーブ無しは None", matches!(save::load_from(&missing), Ok(None)));
+
+    let _ = std::fs::remove_file(&path);
+
+    // 1匹だけの古い形式で、後から増えた項目の無いセーブデータも読める（増えた項目の既定値はそれぞれのテストで確かめる）
+    let old = r#"{"saved_at":"2024-01-01T00:00:00+09:00","cat":{"name":"古猫","hunger":50,"happiness":50,"cleanliness":50,"health":50,"last_fed":null,"sleep_until":null,"hunger_f":50.0,"happiness_f":50.0,"cleanliness_f":50.0,"health_f":50.0}}"#;
+    let data: Result<SaveData, _> = serde_json::from_str(old);
+    check(
+        "古いセーブデータも読める",
+        data.is_ok_and(|data| data.cats.len() == 1 && data.cats[0].name == "古猫" && data.cats[0].hunger == 50),
+    );
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
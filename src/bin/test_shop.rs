@@ -0,0 +1,121 @@
+use chrono::{Duration, Local};
+use crossterm::event::KeyCode;
+use neko_watch::app::{App, Screen};
+use neko_watch::cat::{Cat, CatEvent};
+use neko_watch::clock::ManualClock;
+use neko_watch::items::ItemKind;
+use neko_watch::life::LifeStage;
+use neko_watch::rules::{CoinRules, Rules};
+use neko_watch::save;
+use neko_watch::shop::{Cosmetic, ShopEntry};
+use neko_watch::wallet::{Earning, Rewards};
+use std::rc::Rc;
+
+fn main() {
+    println!("=== コインテスト ===\n");
+
+    // 良い状態で過ごすと1分1コイン
+    let rules = CoinRules::default();
+    let mut rewards = Rewards::default();
+    check("30秒ではまだ", rewards.track(30.0, true, false, &rules).is_empty());
+    check("1分で1コイン", rewards.track(30.0, true, false, &rules) == vec![Earning::Care(1)]);
+    check("良くない状態では稼げない", rewards.track(120.0, false, false, &rules).is_empty());
+
+    // 連続記録のボーナス
+    let mut rewards = Rewards::default();
+    let earnings = rewards.track(3600.0, false, false, &rules);
+    check("1時間続くとボーナス", earnings == vec![Earning::Streak { hours: 1.0, coins: 10 }]);
+    rewards.track(1800.0, false, true, &rules);
+    check("空腹・不潔で記録が途切れる", rewards.streak_seconds() == 0.0);
+    let earnings = rewards.track(2.0 * 3600.0, false, false, &rules);
+    check("途切れた後は1時間目から", earnings.len() == 2);
+
+    // 猫の世話の結果としてコインが出る
+    let mut cat = adult_cat();
+    cat.set_status_for_test(100, 100, 100, 100);
+    cat.decay(60.0);
+    let coins: u32 = earned(&mut cat).iter().map(|earning| earning.coins()).sum();
+    check("上機嫌の猫は1分で1コイン", coins == 1);
+
+    let mut cat = adult_cat();
+    cat.set_status_for_test(20, 100, 100, 100);
+    cat.decay(60.0);
+    check("空腹の猫では稼げない", earned(&mut cat).is_empty());
+
+    // App: 起動中に稼ぐ
+    println!("\n=== 所持金 ===");
+    let clock = Rc::new(ManualClock::new(Local::now()));
+    let mut cat = adult_cat();
+    cat.set_status_for_test(100, 100, 100, 100);
+    let mut app = App::with_clock(cat, clock.clone());
+    check("最初の所持金は50", app.coins == 50);
+    for _ in 0..10 {
+        clock.advance(Duration::seconds(30));
+        app.tick();
+    }
+    println!("5分後: {}コイン", app.coins);
+    check("良い状態の5分で稼ぐ", app.coins > 50);
+
+    // ショップ
+    println!("\n=== ショップ ===");
+    app.coins = 60;
+    app.handle_key(KeyCode::Char('s'));
+    check("[s] でショップ", app.screen == Screen::Shop { selected: 0 });
+    let wet_before = app.inventory.quantity(ItemKind::WetFood);
+    app.handle_key(KeyCode::Down);
+    app.handle_key(KeyCode::Enter);
+    check("ウェットフードを買う", app.inventory.quantity(ItemKind::WetFood) == wet_before + 1 && app.coins == 45);
+
+    app.buy(ShopEntry::Cosmetic(Cosmetic::Bell));
+    check("足りなければ買えない", app.coins == 45 && !app.wardrobe.contains(&Cosmetic::Bell));
+    check("足りないお知らせ", app.notice.as_ref().is_some_and(|notice| notice.text.contains("足りません")));
+
+    app.coins = 200;
+    app.buy(ShopEntry::Cosmetic(Cosmetic::Ribbon));
+    check("リボンを買うと着ける", app.coins == 100 && app.cat().accessory == Some(Cosmetic::Ribbon));
+    app.buy(ShopEntry::Cosmetic(Cosmetic::Ribbon));
+    check("持っているリボンは外すだけ", app.coins == 100 && app.cat().accessory.is_none());
+    app.handle_key(KeyCode::Esc);
+    check("Esc で戻る", app.screen == Screen::Main);
+
+    // 薬
+    app.buy(ShopEntry::Item(ItemKind::Medicine));
+    app.cat_mut().set_status_for_test(50, 50, 50, 20);
+    app.handle_key(KeyCode::Char('5'));
+    check("[5] で薬: 健康度+30", app.cat().health == 50 && app.inventory.quantity(ItemKind::Medicine) == 0);
+    app.handle_key(KeyCode::Char('5'));
+    check("薬が無ければ何もしない", app.cat().health == 50 && app.cat().care.medicated == 1);
+
+    // 所持金と着せかえはセーブされる
+    let path = std::env::temp_dir().join("neko-watch-test").join("shop.json");
+    save::save_to(&path, &app.to_save()).expect("セーブに失敗");
+    let loaded = save::load_from(&path).expect("ロードに失敗").expect("セーブが無い");
+    check("所持金を復元", loaded.coins == app.coins);
+    check("着せかえを復元", loaded.wardrobe == vec![Cosmetic::Ribbon]);
+    let _ = std::fs::remove_file(&path);
+
+    // 値段は設定ファイルで変えられる
+    let rules = Rules::parse("[shop]\nbell = 10\n").expect("読み込みに失敗");
+    check("鈴の値段を変更", ShopEntry::Cosmetic(Cosmetic::Bell).price(&rules.shop) == 10);
+}
+
+fn adult_cat() -> Cat {
+    let mut cat = Cat::new("ショップテスト猫".to_string());
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    cat
+}
+
+fn earned(cat: &mut Cat) -> Vec<Earning> {
+    cat.take_events()
+        .into_iter()
+        .filter_map(|event| match event {
+            CatEvent::Earned(earning) => Some(earning),
+            _ => None,
+        })
+        .collect()
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
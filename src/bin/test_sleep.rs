@@ -1,4 +1,12 @@
+use chrono::Duration;
+use crossterm::event::KeyCode;
+use neko_watch::app::App;
 use neko_watch::cat::Cat;
+use neko_watch::clock::ManualClock;
+use neko_watch::life::LifeStage;
+use neko_watch::nap::Quality;
+use neko_watch::rules::{EventRules, Rules};
+use std::rc::Rc;
 
 fn main() {
     println!("=== 睡眠アニメーションテスト ===\n");
@@ -24,6 +32,138 @@ fn main() {
     
     println!("\n実際の睡眠アニメーション:");
     println!("{}", cat.get_ascii_art());
+
+    // 健康度は眠っている間に少しずつ回復する
+    println!("\n=== 眠っている間の回復 ===");
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut cat = adult_cat(&clock);
+    cat.set_status_for_test(90, 90, 90, 20);
+    cat.sleep();
+    check("寝かせてすぐは回復しない", cat.health == 20);
+    check("ぐっすり", cat.sleep_quality() == Some(Quality::Deep));
+    pass(&mut cat, &clock, 300);
+    let halfway = cat.health;
+    println!("5分後の健康度: {}", halfway);
+    check("少しずつ回復", halfway > 40 && halfway < 70);
+    check("半分眠った", cat.nap().is_some_and(|nap| (nap.progress() - 0.5).abs() < 0.01));
+    check("残り5分", cat.sleep_remaining_seconds() == Some(300));
+    pass(&mut cat, &clock, 300);
+    // 眠っている間も健康度はいつも通り減るので、目標の90には届かない
+    println!("10分後の健康度: {}", cat.health);
+    check("起きるころには回復", cat.health > halfway && cat.health >= 60);
+    check("起きると回復は終わり", !cat.is_sleeping() && cat.nap().is_none() && cat.sleep_quality().is_none());
+
+    // 空腹・不潔だったり、起こされかけたりすると眠りが浅くなる
+    println!("\n=== 眠りの質 ===");
+    let rested = |hunger, cleanliness, disturbances| {
+        let clock = Rc::new(ManualClock::at_noon_today());
+        let mut cat = adult_cat(&clock);
+        cat.set_status_for_test(hunger, 90, cleanliness, 20);
+        cat.sleep();
+        for _ in 0..disturbances {
+            cat.disturb();
+        }
+        let quality = cat.sleep_quality();
+        let goal = cat.nap().map(|nap| nap.goal).unwrap_or_default();
+        let before = cat.health as f64;
+        pass(&mut cat, &clock, 600);
+        // 減る分を除いた、目標までの回復の割合
+        (quality, (cat.health as f64 - before) / (goal - before))
+    };
+    let (deep, well) = rested(90, 90, 0);
+    let (light, hungry) = rested(20, 90, 0);
+    let (restless, both) = rested(20, 20, 0);
+    let (_, dirty) = rested(90, 20, 0);
+    let (disturbed_quality, disturbed) = rested(90, 90, 3);
+    println!(
+        "回復の割合: ぐっすり {:.2} / 空腹 {:.2} / 不潔 {:.2} / 空腹で不潔 {:.2} / 3回起こされかけた {:.2}",
+        well, hungry, dirty, both, disturbed
+    );
+    check("空腹だと回復が少ない", hungry < well);
+    check("不潔だと回復が少ない", dirty < well && dirty > hungry);
+    check("起こされかけると回復が少ない", disturbed < well);
+    check(
+        "眠りの質の段階",
+        deep == Some(Quality::Deep) && light == Some(Quality::Light) && restless == Some(Quality::Restless),
+    );
+    check("3回起こされかけると浅い", disturbed_quality == Some(Quality::Light));
+    let mut awake = adult_cat(&clock);
+    check("起きている猫は起こされない", !awake.disturb());
+
+    // 起こすと機嫌が悪くなる
+    println!("\n=== 起こす ===");
+    let mut cat = adult_cat(&clock);
+    cat.set_status_for_test(90, 90, 90, 20);
+    cat.sleep();
+    check("眠っている間はお世話できない", !cat.can_perform_action());
+    check("起こす", cat.wake());
+    check("幸福度-15", cat.happiness == 75);
+    check("起きてお世話できる", !cat.is_sleeping() && cat.can_perform_action() && cat.nap().is_none());
+    check("起きている猫は起こせない", !cat.wake());
+    cat.decay(600.0);
+    check("起こすと回復しない", cat.health <= 20);
+
+    // 眠っている間もセーブされる
+    let mut cat = adult_cat(&clock);
+    cat.set_status_for_test(90, 90, 90, 20);
+    cat.sleep();
+    cat.disturb();
+    pass(&mut cat, &clock, 60);
+    let json = serde_json::to_string(&cat).expect("保存に失敗");
+    let mut restored: Cat = serde_json::from_str(&json).expect("読み込みに失敗");
+    restored.set_clock(clock.clone());
+    check(
+        "眠りの途中を復元",
+        restored.nap().is_some_and(|nap| (nap.progress() - 0.1).abs() < 1e-9 && nap.goal == 90.0 && nap.disturbances() == 1),
+    );
+
+    // App: 眠っているときの [4] は起こす、ほかのお世話は眠りを浅くする
+    println!("\n=== App ===");
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let rules = Rules { events: EventRules { storm_chance: 1.0, ..EventRules::quiet() }, ..Rules::default() };
+    let mut app = App::from_save(None, clock.clone(), Rc::new(rules), Some(1));
+    app.adopt("タマ".to_string(), false);
+    app.cat_mut().set_status_for_test(90, 90, 90, 20);
+    app.handle_key(KeyCode::Char('4'));
+    check("[4] で寝かせる", app.cat().is_sleeping());
+    clock.advance(Duration::seconds(10));
+    app.tick();
+    check("雷で眠りが浅くなる", app.cat().nap().is_some_and(|nap| nap.disturbances() == 1));
+    app.handle_key(KeyCode::Char('2'));
+    let notice = app.notice.as_ref().map(|notice| notice.text.clone()).unwrap_or_default();
+    println!("{}", notice);
+    check("眠っている間は遊べない", notice.contains("起こすなら [4]") && app.cat().nap().is_some_and(|nap| nap.disturbances() == 2));
+    app.handle_key(KeyCode::Char('4'));
+    let notice = app.notice.as_ref().map(|notice| notice.text.clone()).unwrap_or_default();
+    println!("{}", notice);
+    check("[4] で起こす", !app.cat().is_sleeping() && notice.contains("幸福度-15"));
+
+    // 設定ファイルで変えられる
+    let rules = Rules::parse("[sleep]\nwake_happiness = 5\n").expect("読み込みに失敗");
+    check("起こしたときの減少を変更", rules.sleep.wake_happiness == 5.0);
+    let error = Rules::parse("[sleep]\nhungry_quality = 2\n").expect_err("エラーになるはず");
+    println!("{}", error);
+    check("眠りの質の範囲をチェック", error.message.contains("hungry_quality") && error.line == Some(2));
+}
+
+// 時間帯で減り方が変わらないよう、昼12時の時計を使う成猫
+fn adult_cat(clock: &Rc<ManualClock>) -> Cat {
+    let mut cat = Cat::with_clock("ミケ".to_string(), clock.clone());
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    cat
+}
+
+// 10秒刻みで時間を進める
+fn pass(cat: &mut Cat, clock: &ManualClock, seconds: i64) {
+    for _ in 0..seconds / 10 {
+        clock.advance(Duration::seconds(10));
+        cat.decay(10.0);
+    }
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
 }
 
 fn get_sleep_animation_test(frame: usize) -> String {
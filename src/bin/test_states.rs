@@ -1,9 +1,14 @@
+use chrono::Duration;
 use neko_watch::cat::{Cat, CatState};
+use neko_watch::clock::ManualClock;
+use std::rc::Rc;
 
 fn main() {
     println!("=== 猫の状態遷移テスト ===\n");
     
-    let mut cat = Cat::new("テスト猫".to_string());
+    // 時間を手動で進められる時計を使う
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut cat = Cat::with_clock("テスト猫".to_string(), clock.clone());
     
     // 各状態をテスト
     test_state(&mut cat, "瀕死", 5, 5, 5, 5, CatState::Dying);
@@ -21,8 +26,19 @@ fn main() {
     println!("餌やり前: {:?}", cat.get_state());
     cat.feed();
     println!("餌やり直後: {:?}", cat.get_state());
-    std::thread::sleep(std::time::Duration::from_secs(2));
+    clock.advance(Duration::seconds(2));
     println!("2秒後: {:?}", cat.get_state());
+    clock.advance(Duration::seconds(30));
+    println!("32秒後: {:?} (満腹状態は30秒で終わる)", cat.get_state());
+    
+    // 睡眠のテスト（10分待たずに時計を進める）
+    println!("\n=== 睡眠テスト ===");
+    cat.sleep();
+    println!("寝かせた直後: 睡眠中={} 行動可能={}", cat.is_sleeping(), cat.can_perform_action());
+    clock.advance(Duration::seconds(599));
+    println!("9分59秒後: 睡眠中={} 行動可能={}", cat.is_sleeping(), cat.can_perform_action());
+    clock.advance(Duration::seconds(1));
+    println!("10分後: 睡眠中={} 行動可能={}", cat.is_sleeping(), cat.can_perform_action());
     
     // アクション効果のテスト
     println!("\n=== アクション効果テスト ===");
@@ -47,4 +63,4 @@ fn test_state(cat: &mut Cat, name: &str, hunger: i32, happiness: i32, cleanlines
     let actual = cat.get_state();
     let result = if actual == expected { "✓" } else { "✗" };
     println!("{} {}: 期待値={:?}, 実際={:?}", result, name, expected, actual);
-}
\ No newline at end of file
+}
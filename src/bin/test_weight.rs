@@ -0,0 +1,139 @@
+use chrono::Duration;
+use neko_watch::cat::{Cat, CatEvent, CatState};
+use neko_watch::clock::{ManualClock, today_at};
+use neko_watch::life::LifeStage;
+use neko_watch::rules::Rules;
+use neko_watch::save::{self, SaveData};
+use neko_watch::weight::{Build, START_KG};
+use std::rc::Rc;
+
+fn main() {
+    println!("=== 体重テスト ===\n");
+
+    // 満腹を超えて食べると太る
+    let clock = Rc::new(ManualClock::at_noon_today());
+    let mut cat = adult_cat(&clock);
+    check("最初は標準体重", cat.weight == START_KG && cat.build() == Build::Normal);
+    cat.set_status_for_test(30, 80, 80, 80);
+    cat.feed();
+    check("お腹が空いていれば太らない", cat.weight == START_KG);
+    cat.feed();
+    println!("空腹度70で餌: {:.2}kg", cat.weight);
+    check("満腹を超えた分だけ太る（+10）", (cat.weight - (START_KG + 0.1)).abs() < 1e-9);
+
+    // [1] を連打すると太りすぎになる
+    println!("\n=== 餌の連打 ===");
+    for _ in 0..5 {
+        cat.set_status_for_test(100, 80, 80, 80);
+        cat.feed();
+    }
+    println!("5回連打: {:.2}kg", cat.weight);
+    check("太りすぎ", cat.build() == Build::Chubby);
+    check("食べすぎでお腹も壊す", cat.get_state() == CatState::UpsetStomach);
+    let mut chubby = adult_cat(&clock);
+    chubby.weight = 6.5;
+    check("病気でなければ太りすぎの状態", chubby.get_state() == CatState::Overweight);
+    check("体型が変わったイベント", cat.take_events().contains(&CatEvent::Reshaped(Build::Chubby)));
+    let art = cat.get_ascii_art();
+    println!("{}", art);
+    check("横に広い体", art.contains("|_____|") && art.split('\n').count() == 6);
+
+    // 太りすぎると健康度が回復しにくい
+    let mut slim = adult_cat(&clock);
+    slim.set_status_for_test(90, 90, 90, 20);
+    cat.set_status_for_test(90, 90, 90, 20);
+    slim.sleep();
+    cat.sleep();
+    slim.decay(600.0);
+    cat.decay(600.0);
+    println!("眠った後の健康度: 標準 {} / 太りすぎ {}", slim.health, cat.health);
+    check("睡眠の回復が少ない", cat.health < slim.health);
+    let mut slim = adult_cat(&clock);
+    slim.set_status_for_test(90, 90, 90, 20);
+    cat.set_status_for_test(90, 90, 90, 20);
+    slim.medicate();
+    cat.medicate();
+    check("薬の回復も少ない", cat.health < slim.health);
+
+    // 遊ぶと痩せる
+    let before = cat.weight;
+    cat.play();
+    check("遊ぶと痩せる", (before - cat.weight - 0.1).abs() < 1e-9);
+    while cat.build() == Build::Chubby {
+        cat.play();
+    }
+    check("遊び続けると標準に戻る", cat.take_events().contains(&CatEvent::Reshaped(Build::Normal)));
+
+    // 空腹が続くと痩せすぎになる
+    println!("\n=== 空腹が続く ===");
+    let mut cat = adult_cat(&clock);
+    for _ in 0..110 * 6 {
+        cat.set_status_for_test(15, 80, 80, 80);
+        clock.advance(Duration::seconds(10));
+        cat.decay(10.0);
+    }
+    println!("空腹度15で110分: {:.2}kg", cat.weight);
+    check("痩せすぎ", cat.build() == Build::Skinny && cat.get_state() == CatState::Underweight);
+    let art = cat.get_ascii_art();
+    println!("{}", art);
+    check("細い体", art.contains("(|_|)"));
+
+    // 痩せすぎると健康度が減りやすい
+    let mut normal = adult_cat(&clock);
+    normal.set_status_for_test(80, 80, 80, 80);
+    cat.set_status_for_test(80, 80, 80, 80);
+    normal.decay(600.0);
+    cat.decay(600.0);
+    println!("10分後の健康度: 標準 {} / 痩せすぎ {}", normal.health, cat.health);
+    check("健康度が速く減る", cat.health < normal.health);
+
+    // しっかり食べていれば戻る
+    for _ in 0..60 * 6 {
+        cat.set_status_for_test(90, 80, 80, 80);
+        clock.advance(Duration::seconds(10));
+        cat.decay(10.0);
+    }
+    println!("空腹度90で60分: {:.2}kg", cat.weight);
+    check("しっかり食べて標準に戻る", cat.build() == Build::Normal && cat.weight <= START_KG);
+
+    // 子猫の体型
+    let mut kitten = Cat::with_clock("子猫".to_string(), clock.clone());
+    kitten.weight = 7.0;
+    check("太った子猫", kitten.get_ascii_art().contains("(_u___u)"));
+
+    // 体重はセーブされる
+    println!("\n=== セーブ ===");
+    let mut cat = adult_cat(&clock);
+    cat.weight = 5.5;
+    let path = std::env::temp_dir().join("neko-watch-test").join("weight.json");
+    save::save_to(&path, &SaveData::new(cat, today_at(12))).expect("セーブに失敗");
+    let loaded = save::load_from(&path).expect("ロードに失敗").expect("セーブが無い");
+    check("体重を復元", loaded.cats[0].weight == 5.5);
+    let _ = std::fs::remove_file(&path);
+
+    // 体重の無い古いセーブデータは標準体重
+    let mut cat = Cat::new("古猫".to_string());
+    cat.weight = 5.5;
+    let mut json = serde_json::to_value(SaveData::new(cat, today_at(12))).expect("変換に失敗");
+    json["cats"][0].as_object_mut().expect("オブジェクト").remove("weight");
+    let data: SaveData = serde_json::from_value(json).expect("古いセーブデータを読めない");
+    check("古いセーブデータは標準体重", data.cats[0].weight == START_KG);
+
+    // 設定ファイルで変えられる
+    let rules = Rules::parse("[weight]\noverweight_kg = 5\n").expect("読み込みに失敗");
+    check("太りすぎの体重を変更", rules.weight.overweight_kg == 5.0);
+    let error = Rules::parse("[weight]\noverweight_kg = 2\n").expect_err("エラーになるはず");
+    println!("{}", error);
+    check("痩せすぎの体重より重い", error.message.contains("overweight_kg") && error.line == Some(2));
+}
+
+fn adult_cat(clock: &Rc<ManualClock>) -> Cat {
+    let mut cat = Cat::with_clock("ミケ".to_string(), clock.clone());
+    cat.set_life_stage_for_test(LifeStage::Adult);
+    cat
+}
+
+fn check(name: &str, ok: bool) {
+    let result = if ok { "✓" } else { "✗" };
+    println!("{} {}", result, name);
+}
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rules::BondRules;
+
+// 絆が深まるとできるようになること
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Perk {
+    Greeting,      // 起動すると迎えに来る
+    FollowCursor,  // 選択肢のカーソルについてくる
+    BathTolerance, // お風呂をがまんする
+}
+
+pub const PERKS: [Perk; 3] = [Perk::Greeting, Perk::FollowCursor, Perk::BathTolerance];
+
+impl Perk {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Perk::Greeting => "お出迎え",
+            Perk::FollowCursor => "カーソルについてくる",
+            Perk::BathTolerance => "お風呂をがまん",
+        }
+    }
+
+    // この絆でできるようになる
+    pub fn threshold(&self, rules: &BondRules) -> f64 {
+        match self {
+            Perk::Greeting => rules.greet_bond,
+            Perk::FollowCursor => rules.follow_bond,
+            Perk::BathTolerance => rules.bath_bond,
+        }
+    }
+
+    pub fn message(&self, name: &str) -> String {
+        match self {
+            Perk::Greeting => format!("💞 {}が起動したときにお出迎えしてくれるようになりました", name),
+            Perk::FollowCursor => format!("💞 {}がカーソルについてくるようになりました", name),
+            Perk::BathTolerance => format!("💞 {}がお風呂を少しがまんしてくれるようになりました", name),
+        }
+    }
+}
+
+// 飼い主との絆（0-100）。幸福度と違い、長いあいだのお世話でゆっくり深まる
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Bond {
+    value: f64,
+}
+
+impl Bond {
+    pub fn new(value: f64) -> Self {
+        Self {
+            value: value.clamp(0.0, 100.0),
+        }
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn label(&self) -> &'static str {
+        if self.value >= 80.0 {
+            "大好き"
+        } else if self.value >= 60.0 {
+            "甘えん坊"
+        } else if self.value >= 40.0 {
+            "なついている"
+        } else if self.value >= 20.0 {
+            "慣れてきた"
+        } else {
+            "よそよそしい"
+        }
+    }
+
+    pub fn has(&self, perk: Perk, rules: &BondRules) -> bool {
+        self.value >= perk.threshold(rules)
+    }
+
+    // 絆を増減し、新しくできるようになったことを返す
+    pub fn change(&mut self, amount: f64, rules: &BondRules) -> Vec<Perk> {
+        let before = *self;
+        self.value = (self.value + amount).clamp(0.0, 100.0);
+        PERKS
+            .into_iter()
+            .filter(|perk| !before.has(*perk, rules) && self.has(*perk, rules))
+            .collect()
+    }
+}
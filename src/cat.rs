@@ -1,26 +1,133 @@
-use std::time::{Duration, Instant};
+use chrono::{DateTime, Duration, Local};
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
 
-#[derive(Debug, Clone)]
+use crate::art;
+use crate::behavior::{self, Activity, Behavior};
+use crate::bond::{Bond, Perk};
+use crate::clock::{self, SharedClock};
+use crate::coat::Coat;
+use crate::daytime::TimeOfDay;
+use crate::events::{Incident, RandomEvent};
+use crate::illness::{self, Ailment, Illness, Severity};
+use crate::items::ItemKind;
+use crate::life::LifeStage;
+use crate::nap::{Nap, Quality};
+use crate::personality::Personality;
+use crate::shop::Cosmetic;
+use crate::wallet::{Earning, Rewards};
+use crate::weight::{self, Build};
+use crate::rules::{ActionEffect, DecayMultiplier, LifeRules, Rules};
+use crate::states::{self, StateRule};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Cat {
+    // 家族の中で猫を見分ける番号。家族に迎えるときに App が乱数から決める
+    // 0 はまだ決まっていない（古いセーブデータでは読み込み時に決める）
+    #[serde(default)]
+    pub id: u64,
     pub name: String,
     pub hunger: i32,      // 0-100 (100 = 満腹)
     pub happiness: i32,   // 0-100 (100 = 最高に幸せ)
     pub cleanliness: i32, // 0-100 (100 = 清潔)
     pub health: i32,      // 0-100 (100 = 健康)
+    #[serde(skip)]
     animation_frame: usize,
+    #[serde(skip)]
     frame_counter: u32,
-    last_fed: Option<Instant>,
-    sleep_until: Option<Instant>,
+    // 再起動後も有効なように実時刻で保持する
+    last_fed: Option<DateTime<Local>>,
+    sleep_until: Option<DateTime<Local>>,
     // 浮動小数点で累積計算
     hunger_f: f64,
     happiness_f: f64,
     cleanliness_f: f64,
     health_f: f64,
+    // 育てた時間（秒）。成長段階はここから決まる。古いセーブデータでは成猫になったところから
+    #[serde(default = "adult_age")]
+    age_seconds: f64,
+    // 体重（kg）。古いセーブデータでは迎えたときの体重
+    #[serde(default = "weight::start")]
+    pub weight: f64,
+    // 健康度 0 が続いている時間（秒）
+    #[serde(default)]
+    zero_health_seconds: f64,
+    // 性格（古いセーブデータでは性格なし）
+    #[serde(default)]
+    pub personality: Personality,
+    // 毛並み（古いセーブデータではグレーの単色）と、生まれた家の両親の名前
+    #[serde(default)]
+    pub coat: Coat,
+    #[serde(default)]
+    pub parents: Option<[String; 2]>,
+    // 飼い主との絆（古いセーブデータでは0から）
+    #[serde(default)]
+    pub bond: Bond,
+    // お世話の回数（思い出に残す）
+    #[serde(default)]
+    pub care: CareLog,
+    // コインのためのお世話記録
+    #[serde(default)]
+    rewards: Rewards,
+    // 着けている着せかえ
+    #[serde(default)]
+    pub accessory: Option<Cosmetic>,
+    // かかっている病気と、病気にかかるかどうかを決める履歴
+    #[serde(default)]
+    ailment: Option<Ailment>,
+    #[serde(default)]
+    health_history: illness::History,
+    // 動物病院から帰ってくる時刻
+    #[serde(default)]
+    vet_until: Option<DateTime<Local>>,
+    // 夜に起きている時間（秒）。長くなるとうとうと眠る
+    #[serde(default)]
+    awake_night_seconds: f64,
+    // ひとりでしているふるまいと、ひとりで過ごしている時間（秒）
+    #[serde(default)]
+    activity: Option<Activity>,
+    #[serde(default)]
+    idle_seconds: f64,
+    // 起きている偶然の出来事
+    #[serde(default)]
+    incident: Option<Incident>,
+    // 眠っている間の回復
+    #[serde(default)]
+    nap: Option<Nap>,
+    // お別れ（死亡・家出）。None なら元気に暮らしている
+    #[serde(default)]
+    outcome: Option<Outcome>,
+    // App に伝える出来事（保存しない）
+    #[serde(skip)]
+    events: Vec<CatEvent>,
+    // 時刻の取得元（保存しない）
+    #[serde(skip, default = "clock::real")]
+    clock: SharedClock,
+    // ゲームバランスの設定（保存しない）
+    #[serde(skip)]
+    rules: Rc<Rules>,
+}
+
+// 成長段階が無かったころから暮らしている猫は、子猫に戻さず成猫として扱う
+fn adult_age() -> f64 {
+    LifeStage::Adult.start_age(&LifeRules::default())
+}
+
+// App の乱数で決め直すまでの仮の番号（作った順）
+fn new_id() -> u64 {
+    static NEXT: AtomicU64 = AtomicU64::new(1);
+    NEXT.fetch_add(1, Ordering::Relaxed)
 }
 
 impl Cat {
     pub fn new(name: String) -> Self {
+        Self::with_clock(name, clock::real())
+    }
+
+    pub fn with_clock(name: String, clock: SharedClock) -> Self {
         Self {
+            id: new_id(),
             name,
             hunger: 80,
             happiness: 80,
@@ -34,215 +141,727 @@ impl Cat {
             happiness_f: 80.0,
             cleanliness_f: 80.0,
             health_f: 80.0,
+            age_seconds: 0.0,
+            weight: weight::START_KG,
+            zero_health_seconds: 0.0,
+            personality: Personality::default(),
+            coat: Coat::default(),
+            parents: None,
+            bond: Bond::default(),
+            care: CareLog::default(),
+            rewards: Rewards::default(),
+            accessory: None,
+            ailment: None,
+            health_history: illness::History::default(),
+            vet_until: None,
+            awake_night_seconds: 0.0,
+            activity: None,
+            idle_seconds: 0.0,
+            incident: None,
+            nap: None,
+            outcome: None,
+            events: Vec::new(),
+            clock,
+            rules: Rc::default(),
+        }
+    }
+
+    pub fn clock(&self) -> SharedClock {
+        self.clock.clone()
+    }
+
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.clock = clock;
+    }
+
+    pub fn rules(&self) -> Rc<Rules> {
+        self.rules.clone()
+    }
+
+    pub fn set_rules(&mut self, rules: Rc<Rules>) {
+        self.rules = rules;
+    }
+
+    pub fn life_stage(&self) -> LifeStage {
+        LifeStage::from_age(self.age_seconds, &self.rules.life)
+    }
+
+    pub fn age_seconds(&self) -> f64 {
+        self.age_seconds
+    }
+
+    pub fn time_of_day(&self) -> TimeOfDay {
+        TimeOfDay::at(self.clock.now(), &self.rules.daytime)
+    }
+
+    pub fn outcome(&self) -> Option<&Outcome> {
+        self.outcome.as_ref()
+    }
+
+    pub fn is_dead(&self) -> bool {
+        matches!(self.outcome, Some(Outcome::Died { .. }))
+    }
+
+    pub fn has_run_away(&self) -> bool {
+        matches!(self.outcome, Some(Outcome::RanAway { .. }))
+    }
+
+    // 溜まった出来事を取り出す
+    pub fn take_events(&mut self) -> Vec<CatEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    // 記録している時刻をずらす（倍速中の時刻を実時刻に戻して保存するときに使う）
+    pub fn shift_timestamps(&mut self, offset: Duration) {
+        self.last_fed = self.last_fed.map(|time| time + offset);
+        self.sleep_until = self.sleep_until.map(|time| time + offset);
+        self.vet_until = self.vet_until.map(|time| time + offset);
+        match &mut self.outcome {
+            Some(Outcome::Died { at, .. }) | Some(Outcome::RanAway { at, .. }) => *at += offset,
+            None => {}
         }
     }
 
     pub fn update(&mut self, delta_seconds: f64) {
+        self.decay(delta_seconds);
+
+        // アニメーションフレームの更新
+        self.frame_counter += 1;
+        if self.frame_counter.is_multiple_of(10) {  // 10フレームごとに更新
+            self.animation_frame = (self.animation_frame + 1) % 4;
+        }
+    }
+
+    // 時間経過によるステータス減少（アニメーションは進めない）
+    pub fn decay(&mut self, delta_seconds: f64) {
+        // お別れした猫の時間は止まる
+        if self.outcome.is_some() {
+            return;
+        }
+        let stage = self.life_stage();
+        // 動物病院にいる間はお世話してもらえるのでステータスは減らない
+        if self.vet_until.is_some() {
+            self.age(delta_seconds, stage);
+            self.finish_vet();
+            return;
+        }
         let delta = delta_seconds / 60.0; // 分単位に変換
+        let decay = &self.rules.decay;
+        let multiplier = stage
+            .multiplier(&self.rules.life)
+            .combine(&self.personality.decay())
+            .combine(&self.rules.daytime.multiplier(self.time_of_day()))
+            .combine(&match self.build() {
+                Build::Skinny => self.rules.weight.underweight,
+                Build::Normal | Build::Chubby => DecayMultiplier::default(),
+            });
+        // 病気の症状（1分あたり）
+        let symptoms = self.symptoms();
         
         // 浮動小数点で累積計算
-        self.hunger_f = (self.hunger_f - delta * 2.5).max(0.0);
-        self.happiness_f = (self.happiness_f - delta * 2.0).max(0.0);
-        self.cleanliness_f = (self.cleanliness_f - delta * 1.0).max(0.0);
+        self.hunger_f = (self.hunger_f - delta * (decay.hunger * multiplier.hunger - symptoms.hunger)).clamp(0.0, 100.0);
+        self.happiness_f =
+            (self.happiness_f - delta * (decay.happiness * multiplier.happiness - symptoms.happiness)).clamp(0.0, 100.0);
+        self.cleanliness_f =
+            (self.cleanliness_f - delta * (decay.cleanliness * multiplier.cleanliness - symptoms.cleanliness))
+                .clamp(0.0, 100.0);
         
-        // 健康度は他のステータスが30未満だと2倍の速度で減る
-        let health_penalty = if self.hunger < 30 || self.happiness < 30 || self.cleanliness < 30 {
-            4.0  // -4/分
+        // 健康度は他のステータスが閾値未満だと速く減る
+        let threshold = decay.penalty_threshold;
+        let health_penalty = if self.hunger < threshold || self.happiness < threshold || self.cleanliness < threshold {
+            decay.health_penalty
         } else {
-            2.0  // -2/分
+            decay.health
         };
-        self.health_f = (self.health_f - delta * health_penalty).max(0.0);
-        
-        // 整数値を更新
-        self.hunger = self.hunger_f as i32;
-        self.happiness = self.happiness_f as i32;
-        self.cleanliness = self.cleanliness_f as i32;
-        self.health = self.health_f as i32;
+        let health_rate = (health_penalty * multiplier.health - symptoms.health) / 60.0; // 1秒あたり
+        let health_before = self.health_f;
+        self.health_f = (self.health_f - delta_seconds * health_rate).clamp(0.0, 100.0);
         
-        // アニメーションフレームの更新
-        self.frame_counter += 1;
-        if self.frame_counter % 10 == 0 {  // 10フレームごとに更新
-            self.animation_frame = (self.animation_frame + 1) % 4;
+        self.sync_stats();
+        self.rest(delta_seconds);
+        self.age(delta_seconds, stage);
+        self.track_weight(delta);
+        self.track_bond(delta);
+
+        // 健康度 0 が続くとお別れ（0 になってからの時間だけ数える。mortal でなければ瀕死のまま）
+        if self.health_f <= 0.0 {
+            let reached_zero = if health_rate > 0.0 { health_before / health_rate } else { 0.0 };
+            self.zero_health_seconds += (delta_seconds - reached_zero).max(0.0);
+            if self.rules.death.mortal && self.zero_health_seconds >= self.rules.death.grace_minutes * 60.0 {
+                self.part();
+            }
+        } else {
+            self.zero_health_seconds = 0.0;
+        }
+
+        if self.outcome.is_none() {
+            self.progress_illness(delta_seconds);
+            self.doze(delta_seconds);
+            self.settle(delta_seconds);
+            self.behave(delta_seconds);
+            self.track_rewards(delta_seconds);
         }
     }
 
-    pub fn feed(&mut self) {
-        self.hunger_f = (self.hunger_f + 40.0).min(100.0);
-        self.happiness_f = (self.happiness_f + 10.0).min(100.0);
-        self.cleanliness_f = (self.cleanliness_f - 10.0).max(0.0);
-        self.hunger = self.hunger_f as i32;
-        self.happiness = self.happiness_f as i32;
-        self.cleanliness = self.cleanliness_f as i32;
-        self.last_fed = Some(Instant::now());
+    // 空腹が続くと痩せ、十分に食べていれば迎えたときの体重まで戻る
+    fn track_weight(&mut self, minutes: f64) {
+        let rules = &self.rules.weight;
+        let weight = if self.hunger < rules.starve_hunger {
+            self.weight - rules.starve_loss * minutes
+        } else if self.hunger >= rules.well_fed_hunger && self.weight < weight::START_KG {
+            (self.weight + rules.recover_gain * minutes).min(weight::START_KG)
+        } else {
+            self.weight
+        };
+        self.set_weight(weight);
     }
 
-    pub fn play(&mut self) {
-        self.happiness_f = (self.happiness_f + 25.0).min(100.0);
-        self.hunger_f = (self.hunger_f - 15.0).max(0.0);
-        self.cleanliness_f = (self.cleanliness_f - 5.0).max(0.0);
-        self.happiness = self.happiness_f as i32;
-        self.hunger = self.hunger_f as i32;
-        self.cleanliness = self.cleanliness_f as i32;
+    pub fn build(&self) -> Build {
+        Build::of(self.weight, &self.rules.weight)
     }
 
-    pub fn bathe(&mut self) {
-        self.cleanliness_f = (self.cleanliness_f + 60.0).min(100.0);
-        self.happiness_f = (self.happiness_f - 30.0).max(0.0);
-        self.health_f = (self.health_f - 10.0).max(0.0);
-        self.cleanliness = self.cleanliness_f as i32;
-        self.happiness = self.happiness_f as i32;
-        self.health = self.health_f as i32;
+    fn set_weight(&mut self, weight: f64) {
+        let build = self.build();
+        self.weight = weight::clamp(weight);
+        if self.build() != build {
+            self.events.push(CatEvent::Reshaped(self.build()));
+        }
     }
 
-    pub fn sleep(&mut self) {
-        let recovery = (self.hunger_f + self.happiness_f + self.cleanliness_f) / 3.0;
-        self.health_f = recovery;
-        self.health = self.health_f as i32;
-        self.sleep_until = Some(Instant::now() + Duration::from_secs(600)); // 10分間
-    }
-
-    pub fn get_mood(&self) -> CatMood {
-        if self.health < 20 {
-            CatMood::Sick
-        } else if self.hunger < 20 || self.happiness < 20 {
-            CatMood::Sad
-        } else if self.health < 50 && self.happiness < 50 {
-            CatMood::Sleepy
-        } else if self.happiness > 80 && self.hunger > 70 {
-            CatMood::Happy
-        } else {
-            CatMood::Normal
+    // よい状態で過ごすと絆が深まり、放っておかれると薄れる
+    fn track_bond(&mut self, minutes: f64) {
+        let rules = &self.rules.bond;
+        let amount = match self.get_state() {
+            CatState::Happy | CatState::Full | CatState::Playful | CatState::Normal => rules.good_gain,
+            CatState::Hungry | CatState::Dirty | CatState::Unhappy | CatState::Underweight | CatState::Dying => {
+                -rules.neglect_loss
+            }
+            _ => 0.0,
+        };
+        self.change_bond(amount * minutes / 60.0);
+    }
+
+    fn change_bond(&mut self, amount: f64) {
+        let perks = self.bond.change(amount, &self.rules.bond);
+        self.events.extend(perks.into_iter().map(CatEvent::Bonded));
+    }
+
+    pub fn has_perk(&self, perk: Perk) -> bool {
+        self.bond.has(perk, &self.rules.bond)
+    }
+
+    // 絆が深まっていれば、起動したときに迎えに来る
+    pub fn greet(&mut self) -> bool {
+        if !self.has_perk(Perk::Greeting) || !self.can_perform_action() {
+            return false;
         }
+        let rule = Behavior::Greet.rule();
+        self.apply(rule.effect);
+        self.activity = Some(Activity {
+            behavior: Behavior::Greet,
+            remaining_seconds: rule.seconds,
+        });
+        true
     }
 
-    pub fn get_ascii_art(&self) -> String {
-        let frame = self.animation_frame;
-        
-        // 睡眠中は専用の表示を優先
-        if self.is_sleeping() {
-            return self.get_sleeping_animation(frame);
+    // 成長
+    fn age(&mut self, delta_seconds: f64, stage: LifeStage) {
+        self.age_seconds += delta_seconds.max(0.0);
+        let new_stage = self.life_stage();
+        if new_stage != stage {
+            self.events.push(CatEvent::StageUp(new_stage));
         }
-        
-        let state = self.get_state();
-        match state {
-            CatState::Dying => self.get_dying_animation(frame),
-            CatState::Sick => self.get_sick_animation(frame),
-            CatState::Hungry => self.get_hungry_animation(frame),
-            CatState::Dirty => self.get_dirty_animation(frame),
-            CatState::Unhappy => self.get_unhappy_animation(frame),
-            CatState::Tired => self.get_tired_animation(frame),
-            CatState::Happy => self.get_happy_animation(frame),
-            CatState::Full => self.get_full_animation(frame),
-            CatState::Normal => self.get_normal_animation(frame),
+    }
+
+    pub fn ailment(&self) -> Option<&Ailment> {
+        self.ailment.as_ref()
+    }
+
+    pub fn illness(&self) -> Option<Illness> {
+        self.ailment.map(|ailment| ailment.illness)
+    }
+
+    pub fn severity(&self) -> Option<Severity> {
+        self.ailment.map(|ailment| ailment.severity(&self.rules.illness))
+    }
+
+    fn symptoms(&self) -> ActionEffect {
+        self.ailment
+            .map(|ailment| ailment.symptoms(&self.rules.illness))
+            .unwrap_or_default()
+    }
+
+    // 病気の進行と、ステータスの履歴からかかる病気
+    fn progress_illness(&mut self, delta_seconds: f64) {
+        let rules = &self.rules.illness;
+        let contracted = self.health_history.track(delta_seconds, self.health, self.cleanliness, rules);
+        match &mut self.ailment {
+            Some(ailment) => {
+                let before = ailment.severity(rules);
+                ailment.seconds += delta_seconds.max(0.0);
+                let after = ailment.severity(rules);
+                if after != before {
+                    self.events.push(CatEvent::Worsened(ailment.illness, after));
+                }
+            }
+            None => {
+                if let Some(illness) = contracted {
+                    self.fall_ill(illness);
+                }
+            }
         }
     }
-    
-    pub fn get_state(&self) -> CatState {
-        // 優先順位順にチェック
-        if self.hunger < 10 || self.happiness < 10 || self.cleanliness < 10 || self.health < 10 {
-            CatState::Dying
-        } else if self.health < 20 {
-            CatState::Sick
-        } else if self.hunger < 30 {
-            CatState::Hungry
-        } else if self.cleanliness < 30 {
-            CatState::Dirty
-        } else if self.happiness < 30 {
-            CatState::Unhappy
-        } else if self.health < 50 && self.happiness < 50 {
-            CatState::Tired
-        } else if self.hunger > 90 && self.is_recently_fed() {
-            CatState::Full
-        } else if self.happiness > 80 {
-            CatState::Happy
-        } else {
-            CatState::Normal
+
+    // 病気にかかる（かかっている間は別の病気にはかからない）
+    fn fall_ill(&mut self, illness: Illness) {
+        if self.ailment.is_some() || self.outcome.is_some() {
+            return;
         }
+        self.ailment = Some(Ailment::new(illness));
+        self.events.push(CatEvent::FellIll(illness));
     }
-    
-    fn get_normal_animation(&self, frame: usize) -> String {
-        match frame % 4 {
-            0 => "   /\\_/\\          \n  ( o.o )         \n   > ^ <     /)   \n  /|   |\\   / /   \n ( |___| ) /_/    \n                  ".to_string(),
-            1 => "   /\\_/\\          \n  ( -.o )         \n   > ^ <     /)   \n  /|   |\\   / /   \n ( |___| ) /_/    \n                  ".to_string(),
-            2 => "   /\\_/\\          \n  ( o.- )         \n   > ^ <     /)   \n  /|   |\\   / /   \n ( |___| ) /_/    \n                  ".to_string(),
-            _ => "   /\\_/\\          \n  ( o.o )         \n   > ^ <     /)   \n  /|   |\\   / /   \n ( |___| ) /_/    \n                  ".to_string(),
+
+    fn cure(&mut self) {
+        if let Some(ailment) = self.ailment.take() {
+            self.health_history.forget(ailment.illness);
+            self.events.push(CatEvent::Cured(ailment.illness));
         }
     }
 
-    fn get_dying_animation(&self, _frame: usize) -> String {
-        "   /\\_/\\          \n  ( x.x )         \n   > ^ <     /)   \n  /|   |\\   / /   \n ( |___| ) /_/    \n       ...        ".to_string()
+    // 動物病院へ連れて行く。診察が終わるまで戻らない
+    pub fn visit_vet(&mut self) -> bool {
+        if self.ailment.is_none() || !self.can_perform_action() {
+            return false;
+        }
+        let minutes = self.rules.illness.vet_minutes;
+        self.vet_until = Some(self.clock.now() + Duration::milliseconds((minutes * 60_000.0) as i64));
+        true
     }
-    
-    fn get_hungry_animation(&self, frame: usize) -> String {
-        match frame % 4 {
-            0 | 1 => "   /\\_/\\          \n  ( >.< )         \n   > ^ <     /)   \n  /|   |\\   / /   \n ( |___| ) /_/    \nおなかすいた...   ".to_string(),
-            _ => "   /\\_/\\          \n  ( >.< )         \n   > ^ <     /)   \n  /|   |\\   / /   \n ( |___| ) /_/    \n    グゥ～        ".to_string(),
+
+    pub fn is_at_vet(&self) -> bool {
+        self.vet_until.is_some_and(|until| self.clock.now() < until)
+    }
+
+    // 診察が終わっていれば病気が治って帰ってくる
+    fn finish_vet(&mut self) {
+        if self.vet_until.is_none() || self.is_at_vet() {
+            return;
         }
+        self.vet_until = None;
+        self.health_f = self.health_f.max(VET_HEALTH);
+        self.sync_stats();
+        self.cure();
     }
-    
-    fn get_happy_animation(&self, frame: usize) -> String {
-        match frame % 4 {
-            0 | 2 => "   /\\_/\\          \n  ( ^.^ )         \n   > ^ <     /)   \n  /|   |\\   / /   \n ( |___| ) /_/    \n       ♪         ".to_string(),
-            _ => "   /\\_/\\          \n  ( ^.^ )         \n   > ^ <     /)   \n  /|   |\\   / /   \n ( |___| ) /_/    \n      ♪♪         ".to_string(),
+
+    // 眠っている間は眠りの質に応じて健康度が少しずつ回復する
+    fn rest(&mut self, delta_seconds: f64) {
+        let Some(mut nap) = self.nap else {
+            return;
+        };
+        let dying = self.is_dying();
+        let quality = nap.quality(self.hunger, self.cleanliness, &self.rules.sleep);
+        self.health_f = (self.health_f + nap.rest(delta_seconds, quality)).clamp(0.0, 100.0);
+        self.nap = if nap.is_over() { None } else { Some(nap) };
+        self.sync_stats();
+        self.revive_if(dying);
+    }
+
+    // 夜に起きている時間が続くと、自分からうとうと眠る（回復はしない）
+    fn doze(&mut self, delta_seconds: f64) {
+        if self.time_of_day() != TimeOfDay::Night || !self.can_perform_action() {
+            self.awake_night_seconds = 0.0;
+            return;
+        }
+        self.awake_night_seconds += delta_seconds.max(0.0);
+        let daytime = &self.rules.daytime;
+        if self.awake_night_seconds >= daytime.doze_after_minutes * 60.0 {
+            let minutes = daytime.doze_minutes;
+            self.sleep_until = Some(self.clock.now() + Duration::milliseconds((minutes * 60_000.0) as i64));
+            self.nap = Some(Nap::new(self.health_f, self.health_f, minutes * 60.0));
+            self.awake_night_seconds = 0.0;
+            self.events.push(CatEvent::DozedOff);
         }
     }
-    
-    fn get_dirty_animation(&self, frame: usize) -> String {
-        let sweat = match frame % 4 {
-            0 => ";;           ",
-            1 => ";;;          ",
-            2 => ";;;;         ",
-            _ => ";;;;;;       ",
+
+    // ひとりで過ごす時間が続くと、今の様子に合ったふるまいを自分から始める。瀕死のときは弱っていて何もしない
+    fn behave(&mut self, delta_seconds: f64) {
+        if !self.can_perform_action() || self.is_dying() {
+            self.activity = None;
+            self.idle_seconds = 0.0;
+            return;
+        }
+        if self.incident.is_some() {
+            return;
+        }
+        if let Some(activity) = &mut self.activity {
+            activity.remaining_seconds -= delta_seconds.max(0.0);
+            if activity.remaining_seconds <= 0.0 {
+                self.activity = None;
+            }
+            return;
+        }
+        self.idle_seconds += delta_seconds.max(0.0);
+        if self.idle_seconds < self.rules.behavior.interval_minutes * 60.0 {
+            return;
+        }
+        let behavior = behavior::choose(self, &self.rules.behavior);
+        let rule = behavior.rule();
+        self.apply(rule.effect);
+        self.activity = Some(Activity {
+            behavior,
+            remaining_seconds: rule.seconds,
+        });
+        self.events.push(CatEvent::Behaved(behavior));
+    }
+
+    pub fn behavior(&self) -> Option<Behavior> {
+        self.activity.map(|activity| activity.behavior)
+    }
+
+    // 偶然の出来事が起きた
+    pub fn encounter(&mut self, event: RandomEvent) {
+        let rule = event.rule();
+        self.apply(rule.effect);
+        self.incident = Some(Incident {
+            event,
+            remaining_seconds: rule.seconds,
+        });
+        self.events.push(CatEvent::Encountered(event));
+    }
+
+    pub fn incident(&self) -> Option<RandomEvent> {
+        self.incident.map(|incident| incident.event)
+    }
+
+    // 出来事のアニメーションが終わるまで待つ。寝たり瀕死になったりしたらそこで終わる
+    fn settle(&mut self, delta_seconds: f64) {
+        let awake = self.can_perform_action() && !self.is_dying();
+        let Some(incident) = &mut self.incident else {
+            return;
         };
-        format!("   /\\_/\\          \n  ( >.< )         \n   > ^ <     /)   \n  /|   |\\   / /   \n ( |___| ) /_/    \n    {}", sweat)
+        incident.remaining_seconds -= delta_seconds.max(0.0);
+        if incident.remaining_seconds <= 0.0 || !awake {
+            self.incident = None;
+        }
     }
-    
-    fn get_tired_animation(&self, frame: usize) -> String {
-        let (eyes, sleep_effect) = match frame % 4 {
-            0 => ("( -.- )", "Zzz          "),
-            1 => ("( _._ )", "zZz          "),
-            2 => ("( -.- )", "zzZ          "),
-            _ => ("( _._ )", "ZzZ          "),
+
+    // お世話やほかの猫との関わり合いで、していたふるまいや出来事をやめる
+    fn interrupt(&mut self) {
+        self.activity = None;
+        self.incident = None;
+        self.idle_seconds = 0.0;
+    }
+
+    // 上機嫌・満腹・普通で過ごした時間と、空腹・不潔にならない連続記録でコインを稼ぐ
+    fn track_rewards(&mut self, delta_seconds: f64) {
+        let good = matches!(
+            self.get_state(),
+            CatState::Happy | CatState::Full | CatState::Playful | CatState::Normal
+        );
+        // 瀕死などに隠れていても空腹・不潔の条件を満たせば記録は途切れる
+        let broken = states::rule(CatState::Hungry).matches(self) || states::rule(CatState::Dirty).matches(self);
+        let earnings = self.rewards.track(delta_seconds, good, broken, &self.rules.coins);
+        self.events.extend(earnings.into_iter().map(CatEvent::Earned));
+    }
+
+    pub fn streak_seconds(&self) -> f64 {
+        self.rewards.streak_seconds()
+    }
+
+    fn part(&mut self) {
+        let at = self.clock.now();
+        self.sleep_until = None;
+        self.nap = None;
+        if self.rules.death.gentle {
+            self.outcome = Some(Outcome::RanAway { at, coaxed: 0 });
+            self.events.push(CatEvent::RanAway);
+        } else {
+            let cause = self.death_cause();
+            self.outcome = Some(Outcome::Died { cause, at });
+            self.events.push(CatEvent::Died(cause));
+        }
+    }
+
+    // いちばん足りていなかったものが原因。どれも足りていれば病気
+    fn death_cause(&self) -> DeathCause {
+        let (lowest, cause) = [
+            (self.hunger, DeathCause::Starvation),
+            (self.happiness, DeathCause::Loneliness),
+            (self.cleanliness, DeathCause::Filth),
+        ]
+        .into_iter()
+        .min_by_key(|(value, _)| *value)
+        .expect("ステータスは3つある");
+        if lowest < self.rules.decay.penalty_threshold {
+            cause
+        } else {
+            DeathCause::Illness
+        }
+    }
+
+    // 家出した猫を呼ぶ。coax_attempts 回呼ぶと帰ってくる
+    pub fn coax(&mut self) -> bool {
+        let Some(Outcome::RanAway { coaxed, .. }) = &mut self.outcome else {
+            return false;
         };
-        format!("   /\\_/\\          \n  {}         \n   > ^ <     /)   \n  /|   |\\   / /   \n ( |___| ) /_/    \n    {}", eyes, sleep_effect)
+        *coaxed += 1;
+        if *coaxed < self.rules.death.coax_attempts {
+            return false;
+        }
+        self.outcome = None;
+        self.zero_health_seconds = 0.0;
+        // 近所の家でお世話してもらっていた
+        self.hunger_f = self.hunger_f.max(RETURN_STAT);
+        self.happiness_f = self.happiness_f.max(RETURN_STAT);
+        self.cleanliness_f = self.cleanliness_f.max(RETURN_STAT);
+        self.health_f = self.health_f.max(RETURN_STAT);
+        self.sync_stats();
+        self.events.push(CatEvent::Returned);
+        true
     }
-    
-    fn get_unhappy_animation(&self, _frame: usize) -> String {
-        "   /\\_/\\          \n  ( -.~ )         \n   > ^ <     /)   \n  /|   |\\   / /   \n ( |___| ) /_/    \n    ムスッ       ".to_string()
+
+    // 帰ってくるまでにあと何回呼べばよいか
+    pub fn coax_remaining(&self) -> u32 {
+        match self.outcome {
+            Some(Outcome::RanAway { coaxed, .. }) => self.rules.death.coax_attempts.saturating_sub(coaxed),
+            _ => 0,
+        }
     }
 
-    fn get_full_animation(&self, _frame: usize) -> String {
-        "   /\\_/\\          \n  ( ^ω^ )         \n   > ^ <     /)   \n  /|   |\\   / /   \n ( |___| ) /_/    \nごちそうさま♪    ".to_string()
+    // いつものドライフード
+    pub fn feed(&mut self) {
+        self.feed_item(ItemKind::DryFood);
     }
 
-    fn get_sick_animation(&self, frame: usize) -> String {
-        match frame % 2 {
-            0 => "   /\\_/\\          \n  ( @.@ )         \n   > ^ <     /)   \n  /|   |\\   / /   \n ( |___| ) /_/    \n   ぐったり      ".to_string(),
-            _ => "   /\\_/\\          \n  ( x.x )         \n   > ^ <     /)   \n  /|   |\\   / /   \n ( |___| ) /_/    \n   ぐったり      ".to_string(),
+    pub fn feed_item(&mut self, food: ItemKind) {
+        self.feed_with(Some(food));
+    }
+
+    // None ならありあわせのごはん
+    pub fn feed_with(&mut self, food: Option<ItemKind>) {
+        let overfed = self.health_history.record_feed(self.hunger, &self.rules.illness);
+        let effect = match food {
+            Some(food) => self.rules.item_effect(food),
+            None => self.rules.scraps,
+        };
+        let effect = effect.scaled(&self.personality.feed());
+        // 満腹を超えて食べた分だけ太る
+        let excess = (self.hunger_f + effect.hunger - 100.0).max(0.0);
+        self.set_weight(self.weight + excess * self.rules.weight.overfeed_gain);
+        self.apply(effect);
+        self.last_fed = Some(self.clock.now());
+        self.care.fed += 1;
+        self.events.push(CatEvent::Fed);
+        self.change_bond(self.rules.bond.care_gain);
+        if let Some(illness) = overfed {
+            self.fall_ill(illness);
         }
     }
-    
-    fn get_sleeping_animation(&self, frame: usize) -> String {
-        let (eyes, sleep_effect) = match frame % 4 {
-            0 => ("( -.z )", "Zzz...       "),
-            1 => ("( z.- )", "zzZ...       "),
-            2 => ("( z.z )", "ZZZ...       "),
-            _ => ("( -.z )", "zzz...       "),
+
+    // 手で遊ぶ
+    pub fn play(&mut self) {
+        self.play_with(None);
+    }
+
+    pub fn play_with(&mut self, toy: Option<ItemKind>) {
+        let effect = match toy {
+            Some(toy) => self.rules.item_effect(toy),
+            None => self.rules.play,
         };
-        format!("   /\\_/\\          \n  {}         \n   > ^ <     /)   \n  /|   |\\   / /   \n ( |___| ) /_/    \n   {}", eyes, sleep_effect)
+        // 夕方は遊びたがるので喜びが大きい
+        let mut scale = self.personality.play();
+        if self.time_of_day() == TimeOfDay::Dusk {
+            scale = scale.combine(&DecayMultiplier {
+                happiness: self.rules.daytime.dusk_play,
+                ..DecayMultiplier::default()
+            });
+        }
+        self.apply(effect.scaled(&scale));
+        self.set_weight(self.weight - self.rules.weight.play_loss);
+        self.care.played += 1;
+        self.events.push(CatEvent::Played);
+        self.change_bond(self.rules.bond.care_gain);
+    }
+
+    // 薬は軽い・中くらいの病気を治す。重い病気は中くらいまで和らぐだけ
+    pub fn medicate(&mut self) {
+        self.apply(self.rules.item_effect(ItemKind::Medicine));
+        self.care.medicated += 1;
+        self.change_bond(self.rules.bond.care_gain);
+        match self.severity() {
+            Some(Severity::Severe) => {
+                if let Some(ailment) = &mut self.ailment {
+                    ailment.ease(&self.rules.illness);
+                    self.events.push(CatEvent::Eased(ailment.illness));
+                }
+            }
+            Some(_) => self.cure(),
+            None => {}
+        }
+    }
+
+    // 無理やり入れられるお風呂は絆を薄れさせる（水好きならあまり気にしない）
+    pub fn bathe(&mut self) {
+        let scale = self.personality.bathe();
+        let mut effect = self.rules.bathe.scaled(&scale);
+        if self.has_perk(Perk::BathTolerance) && effect.happiness < 0.0 {
+            effect.happiness *= self.rules.bond.bath_tolerance;
+        }
+        self.apply(effect);
+        self.change_bond(-self.rules.bond.bath_loss * scale.happiness);
+        self.care.bathed += 1;
+        self.health_history.record_bath();
+        let calm = !states::rule(CatState::Unhappy).matches(self);
+        self.events.push(CatEvent::Bathed { calm });
+    }
+
+    // 健康度はすぐには戻らず、眠っている間に少しずつ回復する
+    pub fn sleep(&mut self) {
+        let sleep = &self.rules.sleep;
+        let goal = (self.hunger_f + self.happiness_f + self.cleanliness_f) / sleep.recovery_divisor
+            * self.personality.sleep_recovery()
+            * self.recovery_scale();
+        self.nap = Some(Nap::new(self.health_f, goal.clamp(0.0, 100.0), sleep.duration_seconds as f64));
+        self.sleep_until = Some(self.clock.now() + rule_seconds(sleep.duration_seconds));
+        self.care.slept += 1;
+        self.change_bond(self.rules.bond.care_gain);
+        self.interrupt();
+    }
+
+    // 眠っている猫を起こす。機嫌が悪くなる
+    pub fn wake(&mut self) -> bool {
+        if !self.is_sleeping() {
+            return false;
+        }
+        self.sleep_until = None;
+        self.nap = None;
+        self.apply(ActionEffect { happiness: -self.rules.sleep.wake_happiness, ..ActionEffect::default() });
+        true
+    }
+
+    // 眠っているそばで騒ぐと、眠りが浅くなる
+    pub fn disturb(&mut self) -> bool {
+        if !self.is_sleeping() {
+            return false;
+        }
+        match &mut self.nap {
+            Some(nap) => {
+                nap.disturb();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn nap(&self) -> Option<&Nap> {
+        self.nap.as_ref().filter(|_| self.is_sleeping())
+    }
+
+    pub fn sleep_quality(&self) -> Option<Quality> {
+        self.nap()
+            .map(|nap| Quality::of(nap.quality(self.hunger, self.cleanliness, &self.rules.sleep)))
+    }
+
+    // 起きるまでの残り時間（秒）
+    pub fn sleep_remaining_seconds(&self) -> Option<i64> {
+        self.sleep_until
+            .map(|until| until.signed_duration_since(self.clock.now()).num_seconds())
+            .filter(|_| self.is_sleeping())
+    }
+
+    // ほかの猫との関わり合い
+    pub fn interact(&mut self, effect: ActionEffect) {
+        self.apply(effect);
+    }
+
+    fn apply(&mut self, mut effect: ActionEffect) {
+        let dying = self.is_dying();
+        self.interrupt();
+        if effect.health > 0.0 {
+            effect.health *= self.recovery_scale();
+        }
+        self.hunger_f = (self.hunger_f + effect.hunger).clamp(0.0, 100.0);
+        self.happiness_f = (self.happiness_f + effect.happiness).clamp(0.0, 100.0);
+        self.cleanliness_f = (self.cleanliness_f + effect.cleanliness).clamp(0.0, 100.0);
+        self.health_f = (self.health_f + effect.health).clamp(0.0, 100.0);
+        self.sync_stats();
+        self.revive_if(dying);
+    }
+
+    fn is_dying(&self) -> bool {
+        self.outcome.is_none() && states::rule(CatState::Dying).matches(self)
+    }
+
+    // ステータスが増える前は瀕死だった猫が持ち直した
+    fn revive_if(&mut self, was_dying: bool) {
+        if was_dying && !self.is_dying() {
+            self.events.push(CatEvent::Revived);
+        }
+    }
+
+    // 太りすぎると健康度が回復しにくい
+    fn recovery_scale(&self) -> f64 {
+        match self.build() {
+            Build::Chubby => self.rules.weight.overweight_recovery,
+            Build::Skinny | Build::Normal => 1.0,
+        }
+    }
+
+    // 整数値を更新
+    fn sync_stats(&mut self) {
+        self.hunger = self.hunger_f as i32;
+        self.happiness = self.happiness_f as i32;
+        self.cleanliness = self.cleanliness_f as i32;
+        self.health = self.health_f as i32;
+    }
+
+    pub fn get_ascii_art(&self) -> String {
+        self.art_with(self.animation())
+    }
+
+    // 出来事やふるまいの最中はそのアニメーション、それ以外は状態のアニメーション
+    pub fn animation(&self) -> art::Animation {
+        if let Some(incident) = self.incident {
+            return incident.event.rule().animation;
+        }
+        match self.activity {
+            Some(activity) => activity.behavior.rule().animation,
+            None => self.state_rule().animation,
+        }
     }
     
-    fn is_recently_fed(&self) -> bool {
+    // 状態とは別のアニメーション（ほかの猫との関わり合いなど）で描く
+    pub fn art_with(&self, animation: art::Animation) -> String {
+        art::compose(&animation(self.animation_frame), self.life_stage(), self.build())
+    }
+
+    // アートの中で目が描かれている位置（行, 列）。猫がいない姿勢なら None
+    pub fn eye_position(&self, animation: art::Animation) -> Option<(usize, usize)> {
+        art::eye_position(animation(self.animation_frame).pose, self.life_stage())
+    }
+
+    pub fn get_state(&self) -> CatState {
+        self.state_rule().state
+    }
+
+    // 現在の状態のルール（色・アニメーションを含む）
+    pub fn state_rule(&self) -> &'static StateRule {
+        states::evaluate(self)
+    }
+    
+    pub fn is_recently_fed(&self) -> bool {
         if let Some(last_fed) = self.last_fed {
-            last_fed.elapsed() < Duration::from_secs(30)
+            self.clock.now().signed_duration_since(last_fed) < rule_seconds(self.rules.feed.full_seconds)
         } else {
             false
         }
     }
     
     pub fn can_perform_action(&self) -> bool {
-        if let Some(sleep_until) = self.sleep_until {
-            Instant::now() >= sleep_until
+        if self.outcome.is_some() || self.is_at_vet() {
+            false
+        } else if let Some(sleep_until) = self.sleep_until {
+            self.clock.now() >= sleep_until
         } else {
             true
         }
@@ -250,7 +869,7 @@ impl Cat {
     
     pub fn is_sleeping(&self) -> bool {
         if let Some(sleep_until) = self.sleep_until {
-            Instant::now() < sleep_until
+            self.clock.now() < sleep_until
         } else {
             false
         }
@@ -269,24 +888,26 @@ impl Cat {
         self.health_f = self.health as f64;
     }
     
+    // テスト用関数: 成長段階を直接設定
+    #[cfg(debug_assertions)]
+    pub fn set_life_stage_for_test(&mut self, stage: LifeStage) {
+        self.age_seconds = stage.start_age(&self.rules.life);
+    }
+    
     // テスト用関数: 現在の状態と全ステータスを表示
     #[cfg(debug_assertions)]
     pub fn debug_status(&self) -> String {
-        let sleep_status = if let Some(sleep_until) = self.sleep_until {
-            if Instant::now() < sleep_until {
-                let remaining = sleep_until.duration_since(Instant::now());
-                format!("睡眠中 (残り: {}秒)", remaining.as_secs())
-            } else {
-                "睡眠終了".to_string()
-            }
-        } else {
-            "起きている".to_string()
+        let sleep_status = match (self.sleep_remaining_seconds(), self.sleep_quality()) {
+            (Some(remaining), Some(quality)) => format!("睡眠中 (残り: {}秒, {})", remaining, quality.label()),
+            (Some(remaining), None) => format!("睡眠中 (残り: {}秒)", remaining),
+            (None, _) if self.sleep_until.is_some() => "睡眠終了".to_string(),
+            (None, _) => "起きている".to_string(),
         };
         
         let fed_status = if self.is_recently_fed() {
             if let Some(last_fed) = self.last_fed {
-                let elapsed = last_fed.elapsed();
-                format!("餌やり後 ({}秒経過)", elapsed.as_secs())
+                let elapsed = self.clock.now().signed_duration_since(last_fed);
+                format!("餌やり後 ({}秒経過)", elapsed.num_seconds())
             } else {
                 "満腹".to_string()
             }
@@ -295,8 +916,12 @@ impl Cat {
         };
         
         format!(
-            "状態: {:?}\n空腹度: {}/100 ({:.2})\n幸福度: {}/100 ({:.2})\n清潔度: {}/100 ({:.2})\n健康度: {}/100 ({:.2})\nアニメーションフレーム: {}\n睡眠状態: {}\n餌やり状態: {}",
+            "状態: {:?}\n成長: {} ({:.1}時間)\n体重: {:.2}kg ({})\n空腹度: {}/100 ({:.2})\n幸福度: {}/100 ({:.2})\n清潔度: {}/100 ({:.2})\n健康度: {}/100 ({:.2})\nアニメーションフレーム: {}\n睡眠状態: {}\n餌やり状態: {}",
             self.get_state(),
+            self.life_stage().label(),
+            self.age_seconds / 3600.0,
+            self.weight,
+            self.build().label(),
             self.hunger, self.hunger_f,
             self.happiness, self.happiness_f,
             self.cleanliness, self.cleanliness_f,
@@ -308,24 +933,125 @@ impl Cat {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum CatMood {
-    Normal,
-    Happy,
-    Sad,
-    Sleepy,
-    Sick,
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum CatState {
+    Dead,         // お別れ
+    RanAway,      // 家出中
+    AtVet,        // 通院中
+    Sleeping,     // 睡眠中
+    Dying,        // 瀕死
+    Cold,         // 風邪
+    UpsetStomach, // 腹痛
+    SkinTrouble,  // 皮膚炎
+    Hairball,     // 毛玉
+    Sick,         // 病気
+    Underweight,  // 痩せすぎ
+    Hungry,       // 空腹
+    Dirty,        // 不潔
+    Unhappy,      // 不機嫌
+    Tired,        // 疲労
+    Overweight,   // 太りすぎ
+    Happy,        // 上機嫌
+    Full,         // 満腹
+    Playful,      // 遊びたい
+    Normal,       // 普通
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum CatState {
-    Dying,      // 瀕死
-    Sick,       // 病気
-    Hungry,     // 空腹
-    Dirty,      // 不潔
-    Unhappy,    // 不機嫌
-    Tired,      // 疲労
-    Happy,      // 上機嫌
-    Full,       // 満腹
-    Normal,     // 普通
+impl CatState {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CatState::Dead => "お別れ",
+            CatState::RanAway => "家出中",
+            CatState::AtVet => "通院中",
+            CatState::Sleeping => "睡眠中",
+            CatState::Dying => "瀕死",
+            CatState::Cold => "風邪",
+            CatState::UpsetStomach => "腹痛",
+            CatState::SkinTrouble => "皮膚炎",
+            CatState::Hairball => "毛玉",
+            CatState::Sick => "病気",
+            CatState::Underweight => "痩せすぎ",
+            CatState::Hungry => "空腹",
+            CatState::Dirty => "不潔",
+            CatState::Unhappy => "不機嫌",
+            CatState::Tired => "疲労",
+            CatState::Overweight => "太りすぎ",
+            CatState::Happy => "上機嫌",
+            CatState::Full => "満腹",
+            CatState::Playful => "遊びたい",
+            CatState::Normal => "普通",
+        }
+    }
+}
+
+// Cat から App に伝える出来事
+#[derive(Debug, Clone, PartialEq)]
+pub enum CatEvent {
+    StageUp(LifeStage),          // 成長した
+    Died(DeathCause),            // 亡くなった
+    RanAway,                     // 家出した
+    Returned,                    // 家出から帰ってきた
+    Earned(Earning),             // コインを稼いだ
+    FellIll(Illness),            // 病気にかかった
+    Worsened(Illness, Severity), // 病気が重くなった
+    Eased(Illness),              // 薬で症状が和らいだ
+    Cured(Illness),              // 病気が治った
+    DozedOff,                    // 夜にうとうと眠った
+    Reshaped(Build),             // 体型が変わった
+    Bonded(Perk),                // 絆が深まって新しいことができるようになった
+    Behaved(Behavior),           // ひとりでふるまいを始めた
+    Fed,                         // 餌をもらった
+    Played,                      // 遊んでもらった
+    Bathed { calm: bool },       // お風呂に入った（calm なら不機嫌にならなかった）
+    Revived,                     // 瀕死から持ち直した
+    Encountered(RandomEvent),    // 偶然の出来事が起きた
+}
+
+// 家出から帰ってきたときのステータス（最低値）
+const RETURN_STAT: f64 = 50.0;
+// 動物病院から帰ってきたときの健康度（最低値）
+const VET_HEALTH: f64 = 60.0;
+// 設定の秒数はこれより長くしない（難易度の倍率がかかっても落ちないように）
+const MAX_RULE_SECONDS: i64 = 365 * 24 * 60 * 60;
+
+fn rule_seconds(seconds: i64) -> Duration {
+    Duration::try_seconds(seconds.min(MAX_RULE_SECONDS)).unwrap_or_default()
+}
+
+// お世話の回数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CareLog {
+    pub fed: u32,
+    pub played: u32,
+    pub bathed: u32,
+    pub slept: u32,
+    pub medicated: u32,
+}
+
+// 猫とのお別れ
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Outcome {
+    Died { cause: DeathCause, at: DateTime<Local> },
+    // 近所の家へ家出（やさしいモード）。coaxed は呼んだ回数
+    RanAway { at: DateTime<Local>, coaxed: u32 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum DeathCause {
+    Starvation, // 空腹
+    Loneliness, // 寂しさ
+    Filth,      // 不衛生
+    Illness,    // 病気
+}
+
+impl DeathCause {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DeathCause::Starvation => "空腹",
+            DeathCause::Loneliness => "寂しさ",
+            DeathCause::Filth => "不衛生",
+            DeathCause::Illness => "病気",
+        }
+    }
 }
@@ -0,0 +1,95 @@
+use std::path::PathBuf;
+
+use crate::difficulty::{Difficulty, DIFFICULTIES};
+
+// コマンドライン引数
+#[derive(Debug, Clone)]
+pub struct Options {
+    // 時間の進む速さ（1.0 = 実時間）
+    pub speed: f64,
+    // ゲームバランス設定ファイル（TOML）
+    pub rules: Option<PathBuf>,
+    // やさしいモード（死なずに家出する）
+    pub gentle: bool,
+    // 新しいゲームの難易度（セーブデータがあるときは使わない）
+    pub difficulty: Option<Difficulty>,
+    // ゲームの乱数の種（同じ値なら同じ猫・同じ出来事）
+    pub seed: Option<u64>,
+    pub help: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            speed: 1.0,
+            rules: None,
+            gentle: false,
+            difficulty: None,
+            seed: None,
+            help: false,
+        }
+    }
+}
+
+pub const USAGE: &str = "\
+使い方: neko-watch [オプション]
+
+オプション:
+  --speed <倍率>    時間の進む速さ（例: 10, 60）。ゲーム中は [f] で切り替え
+  --rules <ファイル> ゲームバランス設定（TOML）を読み込む
+  --gentle          やさしいモード（猫は死なずに近所の家へ家出し、呼び戻せる）
+  --difficulty <難易度> 新しいゲームの難易度（relaxed: のんびり, standard: ふつう, hardcore: きびしい）
+  --seed <数値>     ゲームの乱数の種。猫の性格や偶然の出来事が同じ順に決まる
+  -h, --help        このヘルプを表示";
+
+impl Options {
+    // 引数の解析。エラー時はメッセージを返す
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (name, inline_value) = match arg.split_once('=') {
+                Some((name, value)) => (name.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            let value = || {
+                inline_value
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("{} には値が必要です", name))
+            };
+            match name.as_str() {
+                "--speed" => options.speed = parse_speed(&value()?)?,
+                "--rules" => options.rules = Some(PathBuf::from(value()?)),
+                "--gentle" => options.gentle = true,
+                "--difficulty" => options.difficulty = Some(parse_difficulty(&value()?)?),
+                "--seed" => options.seed = Some(parse_seed(&value()?)?),
+                "-h" | "--help" => options.help = true,
+                _ => return Err(format!("不明なオプション: {}\n\n{}", name, USAGE)),
+            }
+        }
+        Ok(options)
+    }
+}
+
+fn parse_speed(raw: &str) -> Result<f64, String> {
+    let speed: f64 = raw
+        .trim_end_matches('x')
+        .parse()
+        .map_err(|_| format!("--speed の値が不正です: {}", raw))?;
+    if !speed.is_finite() || speed <= 0.0 {
+        return Err(format!("--speed は正の数で指定してください: {}", raw));
+    }
+    Ok(speed)
+}
+
+fn parse_seed(raw: &str) -> Result<u64, String> {
+    raw.parse()
+        .map_err(|_| format!("--seed は0以上の整数で指定してください: {}", raw))
+}
+
+fn parse_difficulty(raw: &str) -> Result<Difficulty, String> {
+    Difficulty::from_name(raw).ok_or_else(|| {
+        let names: Vec<&str> = DIFFICULTIES.iter().map(|difficulty| difficulty.name()).collect();
+        format!("--difficulty は {} のどれかで指定してください: {}", names.join(", "), raw)
+    })
+}
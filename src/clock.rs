@@ -0,0 +1,129 @@
+use chrono::{DateTime, Duration, Local};
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+// 現在時刻の取得元。テストやシミュレーションでは時間を自由に進められる
+pub trait Clock: Debug {
+    fn now(&self) -> DateTime<Local>;
+}
+
+// Cat と App で同じ時計を共有する
+pub type SharedClock = Rc<dyn Clock>;
+
+pub fn real() -> SharedClock {
+    Rc::new(RealClock)
+}
+
+// 実際の時刻
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RealClock;
+
+impl Clock for RealClock {
+    fn now(&self) -> DateTime<Local> {
+        Local::now()
+    }
+}
+
+// 今日の hour 時ちょうど
+pub fn today_at(hour: u32) -> DateTime<Local> {
+    Local::now()
+        .date_naive()
+        .and_hms_opt(hour, 0, 0)
+        .and_then(|time| time.and_local_timezone(Local).single())
+        .expect("存在する時刻")
+}
+
+// 手動で進める時計（テスト用）
+#[derive(Debug)]
+pub struct ManualClock {
+    now: Cell<DateTime<Local>>,
+}
+
+impl ManualClock {
+    pub fn new(start: DateTime<Local>) -> Self {
+        Self { now: Cell::new(start) }
+    }
+
+    // テストで時間帯によって結果が変わらないよう、今日の昼12時から始める
+    pub fn at_noon_today() -> Self {
+        Self::new(today_at(12))
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+    }
+
+    pub fn advance_seconds(&self, seconds: f64) {
+        self.advance(Duration::milliseconds((seconds * 1000.0) as i64));
+    }
+
+    pub fn set(&self, now: DateTime<Local>) {
+        self.now.set(now);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Local> {
+        self.now.get()
+    }
+}
+
+// 元の時計の経過時間を倍率で伸び縮みさせる時計（倍率 0 で一時停止）
+#[derive(Debug)]
+pub struct ScaledClock {
+    inner: SharedClock,
+    inner_origin: Cell<DateTime<Local>>,
+    origin: Cell<DateTime<Local>>,
+    speed: Cell<f64>,
+}
+
+impl ScaledClock {
+    pub fn new(inner: SharedClock, speed: f64) -> Self {
+        let origin = inner.now();
+        Self {
+            inner,
+            inner_origin: Cell::new(origin),
+            origin: Cell::new(origin),
+            speed: Cell::new(speed),
+        }
+    }
+
+    pub fn speed(&self) -> f64 {
+        self.speed.get()
+    }
+
+    // 倍率の変更。それまでに進んだ時間はそのまま引き継ぐ
+    pub fn set_speed(&self, speed: f64) {
+        self.origin.set(self.now());
+        self.inner_origin.set(self.inner.now());
+        self.speed.set(speed);
+    }
+
+    // 倍率をかける前の時刻
+    pub fn inner_now(&self) -> DateTime<Local> {
+        self.inner.now()
+    }
+}
+
+impl Clock for ScaledClock {
+    fn now(&self) -> DateTime<Local> {
+        let elapsed = self.inner.now() - self.inner_origin.get();
+        let scaled_ms = elapsed.num_milliseconds() as f64 * self.speed.get();
+        self.origin.get() + Duration::milliseconds(scaled_ms as i64)
+    }
+}
+
+// 経過時間を「3時間5分」のように表示する
+pub fn format_duration(duration: Duration) -> String {
+    let minutes = duration.num_minutes();
+    if minutes >= 60 * 24 {
+        format!("{}日{}時間", minutes / (60 * 24), minutes / 60 % 24)
+    } else if minutes >= 60 {
+        format!("{}時間{}分", minutes / 60, minutes % 60)
+    } else if minutes >= 1 {
+        format!("{}分", minutes)
+    } else {
+        format!("{}秒", duration.num_seconds())
+    }
+}
@@ -0,0 +1,201 @@
+use rand::Rng;
+use rand_pcg::Pcg32;
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+// 子猫の毛並みが親のどちらにも似ず変わる確率（毛色・模様・目の色それぞれ）
+const MUTATION_CHANCE: f64 = 0.1;
+
+// 性格と同じ種から別の並びの乱数を使う
+const COAT_STREAM: u64 = 0xC0A7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CoatColor {
+    Black,
+    White,
+    Orange,
+    Gray,
+    Cream,
+    Brown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Pattern {
+    Solid,  // 単色
+    Tabby,  // トラ（しま模様）
+    Calico, // 三毛（白・茶・黒のぶち）
+    Tuxedo, // ハチワレ（胸元と足先が白い）
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EyeColor {
+    Green,
+    Gold,
+    Blue,
+    Copper,
+}
+
+const COLORS: [CoatColor; 6] = [
+    CoatColor::Black,
+    CoatColor::White,
+    CoatColor::Orange,
+    CoatColor::Gray,
+    CoatColor::Cream,
+    CoatColor::Brown,
+];
+const PATTERNS: [Pattern; 4] = [Pattern::Solid, Pattern::Tabby, Pattern::Calico, Pattern::Tuxedo];
+const EYES: [EyeColor; 4] = [EyeColor::Green, EyeColor::Gold, EyeColor::Blue, EyeColor::Copper];
+
+impl CoatColor {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CoatColor::Black => "黒",
+            CoatColor::White => "白",
+            CoatColor::Orange => "茶",
+            CoatColor::Gray => "グレー",
+            CoatColor::Cream => "クリーム",
+            CoatColor::Brown => "こげ茶",
+        }
+    }
+
+    // 毛の色（黒猫は黒い背景でも見えるよう暗い灰色）
+    pub fn color(&self) -> Color {
+        match self {
+            CoatColor::Black => Color::DarkGray,
+            CoatColor::White => Color::White,
+            CoatColor::Orange => Color::Indexed(208),
+            CoatColor::Gray => Color::Gray,
+            CoatColor::Cream => Color::LightYellow,
+            CoatColor::Brown => Color::Indexed(130),
+        }
+    }
+
+    // しま模様の色
+    fn stripe(&self) -> Color {
+        match self {
+            CoatColor::Black => Color::Gray,
+            CoatColor::White => Color::Gray,
+            CoatColor::Orange => Color::Indexed(166),
+            CoatColor::Gray => Color::DarkGray,
+            CoatColor::Cream => Color::Indexed(180),
+            CoatColor::Brown => Color::Indexed(94),
+        }
+    }
+}
+
+impl Pattern {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Pattern::Solid => "単色",
+            Pattern::Tabby => "トラ",
+            Pattern::Calico => "三毛",
+            Pattern::Tuxedo => "ハチワレ",
+        }
+    }
+}
+
+impl EyeColor {
+    pub fn label(&self) -> &'static str {
+        match self {
+            EyeColor::Green => "緑",
+            EyeColor::Gold => "金",
+            EyeColor::Blue => "青",
+            EyeColor::Copper => "銅",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            EyeColor::Green => Color::Green,
+            EyeColor::Gold => Color::Yellow,
+            EyeColor::Blue => Color::LightBlue,
+            EyeColor::Copper => Color::Indexed(172),
+        }
+    }
+}
+
+// 毛並み。同じ seed（子猫は両親と seed）からは同じ毛並みが生まれる
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Coat {
+    pub color: CoatColor,
+    pub pattern: Pattern,
+    pub eyes: EyeColor,
+}
+
+// 古いセーブデータの猫は、これまでの見た目に近いグレーの単色
+impl Default for Coat {
+    fn default() -> Self {
+        Self {
+            color: CoatColor::Gray,
+            pattern: Pattern::Solid,
+            eyes: EyeColor::Gold,
+        }
+    }
+}
+
+impl Coat {
+    pub fn generate(seed: u64) -> Self {
+        let mut rng = Pcg32::new(seed, COAT_STREAM);
+        Self {
+            color: COLORS[rng.gen_range(0..COLORS.len())],
+            pattern: PATTERNS[rng.gen_range(0..PATTERNS.len())],
+            eyes: EYES[rng.gen_range(0..EYES.len())],
+        }
+    }
+
+    // 両親の毛並みから子猫の毛並みを決める。毛色・模様・目の色をそれぞれどちらかの親から受け継ぎ、
+    // MUTATION_CHANCE の確率で親のどちらとも関係なく変わる
+    pub fn inherit(mother: &Coat, father: &Coat, seed: u64) -> Self {
+        let mut rng = Pcg32::new(seed, COAT_STREAM);
+        Self {
+            color: pick(&mut rng, mother.color, father.color, &COLORS),
+            pattern: pick(&mut rng, mother.pattern, father.pattern, &PATTERNS),
+            eyes: pick(&mut rng, mother.eyes, father.eyes, &EYES),
+        }
+    }
+
+    // 例: "茶トラ（金の目）"、"黒のハチワレ（緑の目）"、"三毛（青の目）"
+    pub fn label(&self) -> String {
+        let coat = match self.pattern {
+            Pattern::Solid => format!("{}猫", self.color.label()),
+            Pattern::Tabby => format!("{}トラ", self.color.label()),
+            Pattern::Calico => "三毛".to_string(),
+            Pattern::Tuxedo => format!("{}のハチワレ", self.color.label()),
+        };
+        format!("{}（{}の目）", coat, self.eyes.label())
+    }
+
+    // アートの体の部分（行0〜4）の (行, 列) のマスの色
+    pub fn cell_color(&self, row: usize, col: usize) -> Color {
+        match self.pattern {
+            Pattern::Solid => self.color.color(),
+            Pattern::Tabby if (row + col).is_multiple_of(3) => self.color.stripe(),
+            Pattern::Tabby => self.color.color(),
+            // 白地に茶と黒のぶち
+            Pattern::Calico => match (col / 4 + row) % 3 {
+                0 => CoatColor::Orange.color(),
+                1 => CoatColor::White.color(),
+                _ => CoatColor::Black.color(),
+            },
+            // 口元・胸元・足先は白（白猫なら黒）
+            Pattern::Tuxedo => {
+                let chest = (row == 2 && (3..=7).contains(&col)) || (row >= 3 && (3..=8).contains(&col));
+                match (chest, self.color) {
+                    (true, CoatColor::White) => CoatColor::Black.color(),
+                    (true, _) => CoatColor::White.color(),
+                    (false, color) => color.color(),
+                }
+            }
+        }
+    }
+}
+
+fn pick<T: Copy>(rng: &mut Pcg32, mother: T, father: T, all: &[T]) -> T {
+    if rng.gen_bool(MUTATION_CHANCE) {
+        all[rng.gen_range(0..all.len())]
+    } else if rng.gen_bool(0.5) {
+        mother
+    } else {
+        father
+    }
+}
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Local, Timelike};
+
+use crate::rules::DaytimeRules;
+
+// 時間帯。ゲーム内の時刻（倍速中は進んだ時刻）の「時」で決まる
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeOfDay {
+    Morning, // 朝
+    Day,     // 昼
+    Dusk,    // 夕方（遊びたがる）
+    Night,   // 夜（眠くなる）
+}
+
+impl TimeOfDay {
+    pub fn at(time: DateTime<Local>, rules: &DaytimeRules) -> Self {
+        let hour = time.hour();
+        if hour >= rules.night_hour || hour < rules.morning_hour {
+            TimeOfDay::Night
+        } else if hour >= rules.dusk_hour {
+            TimeOfDay::Dusk
+        } else if hour >= rules.day_hour {
+            TimeOfDay::Day
+        } else {
+            TimeOfDay::Morning
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimeOfDay::Morning => "朝",
+            TimeOfDay::Day => "昼",
+            TimeOfDay::Dusk => "夕方",
+            TimeOfDay::Night => "夜",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            TimeOfDay::Morning => "🌅",
+            TimeOfDay::Day => "☀️",
+            TimeOfDay::Dusk => "🌇",
+            TimeOfDay::Night => "🌙",
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use rand::{RngCore, SeedableRng};
+use rand_pcg::Pcg32;
+use serde::{Deserialize, Serialize};
+
+// ゲーム全体で使う乱数（新しい猫の種・偶然の出来事など）
+// 種と途中の状態をセーブするので、ロードしても同じ続きになる。不具合の報告には種を添える
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Dice {
+    seed: u64,
+    rng: Pcg32,
+}
+
+impl Dice {
+    pub fn new(seed: u64) -> Self {
+        Self { seed, rng: Pcg32::seed_from_u64(seed) }
+    }
+
+    // --seed が無いとき・古いセーブデータのとき
+    pub fn random() -> Self {
+        Self::new(rand::random())
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl RngCore for Dice {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rules::Rules;
+
+// 難易度。新しいゲームを始めるときに選び、セーブデータに保存する
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Difficulty {
+    Relaxed, // のんびり（何時間か留守にしても平気）
+    #[default]
+    Standard, // ふつう（30分でお世話が必要）
+    Hardcore, // きびしい
+}
+
+pub const DIFFICULTIES: [Difficulty; 3] = [Difficulty::Relaxed, Difficulty::Standard, Difficulty::Hardcore];
+
+impl Difficulty {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Difficulty::Relaxed => "のんびり",
+            Difficulty::Standard => "ふつう",
+            Difficulty::Hardcore => "きびしい",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Difficulty::Relaxed => "🐢",
+            Difficulty::Standard => "🐾",
+            Difficulty::Hardcore => "💀",
+        }
+    }
+
+    // --difficulty で指定する名前
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Relaxed => "relaxed",
+            Difficulty::Standard => "standard",
+            Difficulty::Hardcore => "hardcore",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        DIFFICULTIES.into_iter().find(|difficulty| difficulty.name() == name)
+    }
+
+    // やり直すときに [Tab] で選ぶ次の難易度
+    pub fn next(&self) -> Self {
+        match self {
+            Difficulty::Relaxed => Difficulty::Standard,
+            Difficulty::Standard => Difficulty::Hardcore,
+            Difficulty::Hardcore => Difficulty::Relaxed,
+        }
+    }
+
+    // この難易度で遊ぶときのゲームバランス
+    pub fn apply(&self, rules: &Rules) -> Rules {
+        let mut rules = rules.clone();
+        let preset = match self {
+            Difficulty::Relaxed => rules.difficulty.relaxed,
+            Difficulty::Standard => return rules,
+            Difficulty::Hardcore => rules.difficulty.hardcore,
+        };
+        let decay = &mut rules.decay;
+        for rate in [
+            &mut decay.hunger,
+            &mut decay.happiness,
+            &mut decay.cleanliness,
+            &mut decay.health,
+            &mut decay.health_penalty,
+        ] {
+            *rate *= preset.decay_scale;
+        }
+        decay.penalty_threshold = (decay.penalty_threshold as f64 * preset.threshold_scale).round().clamp(0.0, 100.0) as i32;
+        let sleep = &mut rules.sleep;
+        sleep.duration_seconds = ((sleep.duration_seconds as f64 * preset.sleep_scale).round() as i64).max(1);
+        rules.death.mortal &= preset.mortal;
+        rules
+    }
+}
@@ -0,0 +1,133 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::art::{self, Animation};
+use crate::cat::{Cat, CatState};
+use crate::daytime::TimeOfDay;
+use crate::rules::{ActionEffect, EventRules};
+use crate::states;
+
+// 偶然の出来事。上から順に、その時の様子で決まる確率で起きるか調べる
+pub const RANDOM_EVENT_RULES: &[RandomEventRule] = &[
+    // ご機嫌なときは狩りの成果を見せに来る
+    RandomEventRule {
+        event: RandomEvent::GiftMouse,
+        chance: |cat, rules| {
+            if cat.happiness >= rules.gift_happiness {
+                rules.gift_chance
+            } else {
+                0.0
+            }
+        },
+        effect: ActionEffect { hunger: 0.0, happiness: 5.0, cleanliness: -3.0, health: 0.0 },
+        seconds: 30.0,
+        animation: art::gift_mouse,
+        noisy: false,
+    },
+    // 退屈しているほどテーブルの物を落としやすい
+    RandomEventRule {
+        event: RandomEvent::KnockedCup,
+        chance: |cat, rules| rules.cup_chance * (100 - cat.happiness) as f64 / 100.0,
+        effect: ActionEffect { hunger: 0.0, happiness: 5.0, cleanliness: -8.0, health: 0.0 },
+        seconds: 20.0,
+        animation: art::knocked_cup,
+        noisy: true,
+    },
+    // 雷は夜のほうが多い
+    RandomEventRule {
+        event: RandomEvent::Thunderstorm,
+        chance: |cat, rules| match cat.time_of_day() {
+            TimeOfDay::Night => rules.storm_chance * rules.storm_night,
+            _ => rules.storm_chance,
+        },
+        effect: ActionEffect { hunger: 0.0, happiness: -15.0, cleanliness: 0.0, health: -3.0 },
+        seconds: 60.0,
+        animation: art::thunderstorm,
+        noisy: true,
+    },
+    // 日だまりは朝と昼だけ
+    RandomEventRule {
+        event: RandomEvent::Sunbeam,
+        chance: |cat, rules| match cat.time_of_day() {
+            TimeOfDay::Morning | TimeOfDay::Day => rules.sunbeam_chance,
+            _ => 0.0,
+        },
+        effect: ActionEffect { hunger: 0.0, happiness: 8.0, cleanliness: 0.0, health: 5.0 },
+        seconds: 120.0,
+        animation: art::sunbeam,
+        noisy: false,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RandomEvent {
+    GiftMouse,    // ネズミのプレゼント
+    KnockedCup,   // コップを落とす
+    Thunderstorm, // 雷
+    Sunbeam,      // 日だまり
+}
+
+pub struct RandomEventRule {
+    pub event: RandomEvent,
+    // 1分あたりに起きる確率
+    pub chance: fn(&Cat, &EventRules) -> f64,
+    // 起きたときのステータスの増減
+    pub effect: ActionEffect,
+    // アニメーションを表示する時間（ゲーム内の秒）
+    pub seconds: f64,
+    pub animation: Animation,
+    // 眠っているほかの猫の眠りを浅くする
+    pub noisy: bool,
+}
+
+impl RandomEvent {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RandomEvent::GiftMouse => "プレゼント",
+            RandomEvent::KnockedCup => "コップ落とし",
+            RandomEvent::Thunderstorm => "雷",
+            RandomEvent::Sunbeam => "日だまり",
+        }
+    }
+
+    pub fn rule(&self) -> &'static RandomEventRule {
+        RANDOM_EVENT_RULES
+            .iter()
+            .find(|rule| rule.event == *self)
+            .expect("すべての出来事にルールがある")
+    }
+
+    pub fn message(&self, name: &str) -> String {
+        match self {
+            RandomEvent::GiftMouse => format!("🐭 {}がネズミをプレゼントしてくれました！", name),
+            RandomEvent::KnockedCup => format!("☕ {}がテーブルのコップを落として水びたしに…", name),
+            RandomEvent::Thunderstorm => format!("⛈ 雷が鳴って{}がおびえています", name),
+            RandomEvent::Sunbeam => format!("☀ {}が日だまりでぬくぬくしています", name),
+        }
+    }
+}
+
+// minutes 分のあいだに起きた出来事。起きていて手が空いている猫にだけ起きる
+// 同じ乱数の種なら同じ出来事が起きるよう、猫の様子に関係なく出来事ごとに1つ乱数を引く
+pub fn roll(cat: &Cat, rules: &EventRules, minutes: f64, rng: &mut impl Rng) -> Option<RandomEvent> {
+    let idle = cat.can_perform_action()
+        && cat.incident().is_none()
+        && cat.behavior().is_none()
+        && !states::rule(CatState::Dying).matches(cat);
+    let mut happened = None;
+    for rule in RANDOM_EVENT_RULES {
+        let chance = (rule.chance)(cat, rules).clamp(0.0, 1.0);
+        let probability = 1.0 - (1.0 - chance).powf(minutes.max(0.0));
+        if rng.gen_bool(probability) && idle && happened.is_none() {
+            happened = Some(rule.event);
+        }
+    }
+    happened
+}
+
+// 起きている出来事と、アニメーションが終わるまでの時間（秒）
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Incident {
+    pub event: RandomEvent,
+    pub remaining_seconds: f64,
+}
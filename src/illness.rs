@@ -0,0 +1,203 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rules::{ActionEffect, IllnessRules};
+
+// 病気ごとの症状（軽いときの1分あたりの増減。重くなるほど倍率がかかる）
+pub const ILLNESS_RULES: &[IllnessRule] = &[
+    IllnessRule {
+        illness: Illness::Cold,
+        symptoms: ActionEffect { hunger: 0.0, happiness: -0.5, cleanliness: 0.0, health: -1.5 },
+    },
+    IllnessRule {
+        illness: Illness::UpsetStomach,
+        symptoms: ActionEffect { hunger: -2.0, happiness: -1.0, cleanliness: -0.5, health: -0.5 },
+    },
+    IllnessRule {
+        illness: Illness::SkinTrouble,
+        symptoms: ActionEffect { hunger: 0.0, happiness: -1.5, cleanliness: -1.0, health: -0.5 },
+    },
+    IllnessRule {
+        illness: Illness::Hairball,
+        symptoms: ActionEffect { hunger: -1.0, happiness: -1.0, cleanliness: 0.0, health: -0.5 },
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Illness {
+    Cold,         // 風邪（健康度が低い状態が続く）
+    UpsetStomach, // 腹痛（食べすぎ）
+    SkinTrouble,  // 皮膚炎（不潔な状態が続く）
+    Hairball,     // 毛玉（長いあいだお風呂に入っていない）
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct IllnessRule {
+    pub illness: Illness,
+    pub symptoms: ActionEffect,
+}
+
+impl Illness {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Illness::Cold => "風邪",
+            Illness::UpsetStomach => "腹痛",
+            Illness::SkinTrouble => "皮膚炎",
+            Illness::Hairball => "毛玉",
+        }
+    }
+
+    pub fn rule(&self) -> &'static IllnessRule {
+        ILLNESS_RULES
+            .iter()
+            .find(|rule| rule.illness == *self)
+            .expect("すべての病気にルールがある")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Mild,     // 軽い
+    Moderate, // 中くらい
+    Severe,   // 重い
+}
+
+impl Severity {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Mild => "軽い",
+            Severity::Moderate => "中くらい",
+            Severity::Severe => "重い",
+        }
+    }
+
+    // 「重く」なりました、のような通知用
+    pub fn worse_label(&self) -> &'static str {
+        match self {
+            Severity::Mild => "軽く",
+            Severity::Moderate => "少し重く",
+            Severity::Severe => "重く",
+        }
+    }
+
+    // 症状の倍率
+    pub fn factor(&self) -> f64 {
+        match self {
+            Severity::Mild => 1.0,
+            Severity::Moderate => 2.0,
+            Severity::Severe => 3.0,
+        }
+    }
+}
+
+// かかっている病気
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Ailment {
+    pub illness: Illness,
+    // かかってからの時間（秒）。長いほど重くなる
+    pub seconds: f64,
+}
+
+impl Ailment {
+    pub fn new(illness: Illness) -> Self {
+        Self { illness, seconds: 0.0 }
+    }
+
+    pub fn severity(&self, rules: &IllnessRules) -> Severity {
+        let minutes = self.seconds / 60.0;
+        if minutes >= rules.severe_minutes {
+            Severity::Severe
+        } else if minutes >= rules.moderate_minutes {
+            Severity::Moderate
+        } else {
+            Severity::Mild
+        }
+    }
+
+    // 今の重さでの1分あたりの増減
+    pub fn symptoms(&self, rules: &IllnessRules) -> ActionEffect {
+        let factor = self.severity(rules).factor();
+        let symptoms = self.illness.rule().symptoms;
+        ActionEffect {
+            hunger: symptoms.hunger * factor,
+            happiness: symptoms.happiness * factor,
+            cleanliness: symptoms.cleanliness * factor,
+            health: symptoms.health * factor,
+        }
+    }
+
+    // 薬で中くらいまで軽くする
+    pub fn ease(&mut self, rules: &IllnessRules) {
+        self.seconds = self.seconds.min(rules.moderate_minutes * 60.0);
+    }
+}
+
+// 病気にかかるかどうかを決めるステータスの履歴
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct History {
+    // 健康度が低い状態が続いている時間（秒）
+    low_health_seconds: f64,
+    // 清潔度が低い状態が続いている時間（秒）
+    dirty_seconds: f64,
+    // 最後にお風呂に入ってからの時間（秒）
+    since_bath_seconds: f64,
+    // 満腹なのに餌をもらった回数と、最後にそうなってからの時間（秒）
+    overfed: u32,
+    since_overfeed_seconds: f64,
+}
+
+impl History {
+    // 時間経過の記録。条件を満たした病気を返す
+    pub fn track(&mut self, delta_seconds: f64, health: i32, cleanliness: i32, rules: &IllnessRules) -> Option<Illness> {
+        let delta_seconds = delta_seconds.max(0.0);
+        if health < rules.low_health {
+            self.low_health_seconds += delta_seconds;
+        } else {
+            self.low_health_seconds = 0.0;
+        }
+        if cleanliness < rules.dirty_cleanliness {
+            self.dirty_seconds += delta_seconds;
+        } else {
+            self.dirty_seconds = 0.0;
+        }
+        self.since_bath_seconds += delta_seconds;
+        self.since_overfeed_seconds += delta_seconds;
+        if self.since_overfeed_seconds >= rules.overfeed_window_minutes * 60.0 {
+            self.overfed = 0;
+        }
+
+        if self.low_health_seconds >= rules.cold_minutes * 60.0 {
+            Some(Illness::Cold)
+        } else if self.dirty_seconds >= rules.skin_minutes * 60.0 {
+            Some(Illness::SkinTrouble)
+        } else if self.since_bath_seconds >= rules.hairball_hours * 3600.0 {
+            Some(Illness::Hairball)
+        } else {
+            None
+        }
+    }
+
+    // 餌やりの記録（hunger は食べる前の空腹度）。食べすぎたら腹痛
+    pub fn record_feed(&mut self, hunger: i32, rules: &IllnessRules) -> Option<Illness> {
+        if hunger < rules.overfeed_hunger {
+            return None;
+        }
+        self.overfed += 1;
+        self.since_overfeed_seconds = 0.0;
+        (self.overfed >= rules.overfeed_count).then_some(Illness::UpsetStomach)
+    }
+
+    pub fn record_bath(&mut self) {
+        self.since_bath_seconds = 0.0;
+    }
+
+    // 治ったら原因の記録をリセットする（すぐに再発しないように）
+    pub fn forget(&mut self, illness: Illness) {
+        match illness {
+            Illness::Cold => self.low_health_seconds = 0.0,
+            Illness::UpsetStomach => self.overfed = 0,
+            Illness::SkinTrouble => self.dirty_seconds = 0.0,
+            Illness::Hairball => self.since_bath_seconds = 0.0,
+        }
+    }
+}
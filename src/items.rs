@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+// アイテムの種類。効果はゲームバランス設定（ドライフードは [feed]、その他は [items.*]）で決まる
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ItemKind {
+    DryFood,    // ドライフード
+    WetFood,    // ウェットフード
+    Treat,      // おやつ
+    FeatherToy, // ねこじゃらし
+    Ball,       // ボール
+    Catnip,     // またたび
+    Medicine,   // 薬
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Food,     // [1] 餌をあげる
+    Toy,      // [2] 遊ぶ
+    Medicine, // [5] 薬を飲ませる
+}
+
+impl ItemKind {
+    pub const ALL: [ItemKind; 7] = [
+        ItemKind::DryFood,
+        ItemKind::WetFood,
+        ItemKind::Treat,
+        ItemKind::FeatherToy,
+        ItemKind::Ball,
+        ItemKind::Catnip,
+        ItemKind::Medicine,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ItemKind::DryFood => "ドライフード",
+            ItemKind::WetFood => "ウェットフード",
+            ItemKind::Treat => "おやつ",
+            ItemKind::FeatherToy => "ねこじゃらし",
+            ItemKind::Ball => "ボール",
+            ItemKind::Catnip => "またたび",
+            ItemKind::Medicine => "薬",
+        }
+    }
+
+    pub fn category(&self) -> Category {
+        match self {
+            ItemKind::DryFood | ItemKind::WetFood | ItemKind::Treat => Category::Food,
+            ItemKind::FeatherToy | ItemKind::Ball | ItemKind::Catnip => Category::Toy,
+            ItemKind::Medicine => Category::Medicine,
+        }
+    }
+
+    // 1個で使える回数（ドライフードは1袋10回分、おもちゃは壊れるまでの回数）
+    pub fn uses(&self) -> u32 {
+        match self {
+            ItemKind::DryFood => 10,
+            ItemKind::WetFood | ItemKind::Treat | ItemKind::Catnip | ItemKind::Medicine => 1,
+            ItemKind::FeatherToy => 5,
+            ItemKind::Ball => 20,
+        }
+    }
+
+    pub fn of(category: Category) -> impl Iterator<Item = ItemKind> {
+        Self::ALL.into_iter().filter(move |kind| kind.category() == category)
+    }
+}
+
+// 1種類ぶんの在庫
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Stock {
+    // 個数（使いかけを含む）
+    pub quantity: u32,
+    // 使いかけの1個で使った回数
+    pub used: u32,
+}
+
+// 持ち物（猫ではなく家にあるもの）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Inventory {
+    stocks: BTreeMap<ItemKind, Stock>,
+}
+
+impl Default for Inventory {
+    // 最初に持っているもの
+    fn default() -> Self {
+        let mut inventory = Self::empty();
+        inventory.add(ItemKind::DryFood, 3);
+        inventory.add(ItemKind::WetFood, 2);
+        inventory.add(ItemKind::Treat, 3);
+        inventory.add(ItemKind::FeatherToy, 1);
+        inventory.add(ItemKind::Ball, 1);
+        inventory.add(ItemKind::Catnip, 1);
+        inventory
+    }
+}
+
+impl Inventory {
+    pub fn empty() -> Self {
+        Self {
+            stocks: BTreeMap::new(),
+        }
+    }
+
+    pub fn add(&mut self, kind: ItemKind, quantity: u32) {
+        self.stocks.entry(kind).or_default().quantity += quantity;
+    }
+
+    pub fn quantity(&self, kind: ItemKind) -> u32 {
+        self.stocks.get(&kind).map_or(0, |stock| stock.quantity)
+    }
+
+    // あと何回使えるか
+    pub fn remaining_uses(&self, kind: ItemKind) -> u32 {
+        match self.stocks.get(&kind) {
+            Some(stock) if stock.quantity > 0 => stock.quantity * kind.uses() - stock.used,
+            _ => 0,
+        }
+    }
+
+    // 1回使う。在庫が無ければ false
+    pub fn consume(&mut self, kind: ItemKind) -> bool {
+        let Some(stock) = self.stocks.get_mut(&kind).filter(|stock| stock.quantity > 0) else {
+            return false;
+        };
+        stock.used += 1;
+        if stock.used >= kind.uses() {
+            stock.quantity -= 1;
+            stock.used = 0;
+        }
+        true
+    }
+
+    // 一覧表示用（例: "ドライフード ×3 (あと28回)"）
+    pub fn describe(&self, kind: ItemKind) -> String {
+        let quantity = self.quantity(kind);
+        if kind.uses() > 1 && quantity > 0 {
+            format!("{} ×{} (あと{}回)", kind.label(), quantity, self.remaining_uses(kind))
+        } else {
+            format!("{} ×{}", kind.label(), quantity)
+        }
+    }
+}
@@ -1,3 +1,29 @@
 pub mod cat;
+pub mod achievements;
 pub mod app;
-pub mod ui;
\ No newline at end of file
+pub mod art;
+pub mod away;
+pub mod behavior;
+pub mod bond;
+pub mod cli;
+pub mod illness;
+pub mod items;
+pub mod clock;
+pub mod coat;
+pub mod daytime;
+pub mod dice;
+pub mod difficulty;
+pub mod events;
+pub mod life;
+pub mod memorial;
+pub mod nap;
+pub mod personality;
+pub mod quests;
+pub mod relations;
+pub mod rules;
+pub mod save;
+pub mod shop;
+pub mod states;
+pub mod ui;
+pub mod wallet;
+pub mod weight;
\ No newline at end of file
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rules::{DecayMultiplier, LifeRules};
+
+// 成長段階
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LifeStage {
+    Kitten, // 子猫
+    Adult,  // 成猫
+    Senior, // シニア猫
+}
+
+impl LifeStage {
+    pub fn from_age(age_seconds: f64, rules: &LifeRules) -> Self {
+        let hours = age_seconds / 3600.0;
+        if hours >= rules.senior_hours {
+            LifeStage::Senior
+        } else if hours >= rules.adult_hours {
+            LifeStage::Adult
+        } else {
+            LifeStage::Kitten
+        }
+    }
+
+    // この段階になる年齢（秒）
+    pub fn start_age(&self, rules: &LifeRules) -> f64 {
+        match self {
+            LifeStage::Kitten => 0.0,
+            LifeStage::Adult => rules.adult_hours * 3600.0,
+            LifeStage::Senior => rules.senior_hours * 3600.0,
+        }
+    }
+
+    pub fn multiplier(&self, rules: &LifeRules) -> DecayMultiplier {
+        match self {
+            LifeStage::Kitten => rules.kitten,
+            LifeStage::Adult => rules.adult,
+            LifeStage::Senior => rules.senior,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LifeStage::Kitten => "子猫",
+            LifeStage::Adult => "成猫",
+            LifeStage::Senior => "シニア猫",
+        }
+    }
+}
@@ -1,8 +1,10 @@
-mod app;
-mod cat;
-mod ui;
-
-use app::App;
+use neko_watch::{
+    app::App,
+    cli::{self, Options},
+    clock,
+    rules::Rules,
+    save, ui,
+};
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
     execute,
@@ -12,9 +14,39 @@ use ratatui::{
     backend::CrosstermBackend,
     Terminal,
 };
-use std::{io, time::Duration};
+use std::{io, rc::Rc, time::Duration};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(2);
+        }
+    };
+    if options.help {
+        println!("{}", cli::USAGE);
+        return Ok(());
+    }
+
+    // ゲームバランス設定の読み込み（指定が無ければ既定値）
+    let mut rules = match &options.rules {
+        Some(path) => match Rules::load(path) {
+            Ok(rules) => rules,
+            Err(err) => {
+                eprintln!("ゲームバランス設定の読み込みに失敗しました: {}", err);
+                std::process::exit(2);
+            }
+        },
+        None => Rules::default(),
+    };
+    if options.gentle {
+        rules.death.gentle = true;
+    }
+
+    // セーブデータの読み込み（壊れている場合は上書きしないよう起動を中止）
+    let save_data = save::load()?;
+
     // ターミナルの初期化
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -23,7 +55,26 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut terminal = Terminal::new(backend)?;
 
     // アプリケーションの実行
-    let mut app = App::new();
+    // 難易度は新しいゲームを始めるときだけ選べる
+    let mut app = match save_data {
+        Some(data) => {
+            let mut app = App::from_save(Some(data), clock::real(), Rc::new(rules), options.seed);
+            if options.difficulty.is_some_and(|difficulty| difficulty != app.difficulty) {
+                app.notify(format!(
+                    "難易度は新しいゲームを始めるときだけ選べます（今は{}）",
+                    app.difficulty.label()
+                ));
+            }
+            app
+        }
+        None => App::new_game(
+            clock::real(),
+            Rc::new(rules),
+            options.seed,
+            options.difficulty.unwrap_or_default(),
+        ),
+    };
+    app.set_speed(options.speed);
     let res = run_app(&mut terminal, &mut app);
 
     // ターミナルのリセット
@@ -39,6 +90,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         eprintln!("Error: {:?}", err);
     }
 
+    // 終了時に保存
+    if let Err(err) = save::save(&app.to_save()) {
+        eprintln!("セーブに失敗しました: {}", err);
+    }
+
     Ok(())
 }
 
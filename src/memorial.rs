@@ -0,0 +1,79 @@
+use chrono::{DateTime, Duration, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::cat::{CareLog, Cat, Outcome};
+use crate::clock::format_duration;
+use crate::life::LifeStage;
+
+// 思い出に残したお別れした猫
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemorialEntry {
+    pub name: String,
+    pub age_seconds: f64,
+    pub stage: LifeStage,
+    pub care: CareLog,
+    pub outcome: Outcome,
+}
+
+impl MemorialEntry {
+    // お別れしていない猫は None
+    pub fn of(cat: &Cat) -> Option<Self> {
+        Some(Self {
+            name: cat.name.clone(),
+            age_seconds: cat.age_seconds(),
+            stage: cat.life_stage(),
+            care: cat.care,
+            outcome: *cat.outcome()?,
+        })
+    }
+
+    pub fn parted_at(&self) -> DateTime<Local> {
+        match self.outcome {
+            Outcome::Died { at, .. } | Outcome::RanAway { at, .. } => at,
+        }
+    }
+
+    // 一覧の1行
+    pub fn summary(&self) -> String {
+        let farewell = match self.outcome {
+            Outcome::Died { cause, .. } => format!("死因: {}", cause.label()),
+            Outcome::RanAway { .. } => "近所の家の子に".to_string(),
+        };
+        format!(
+            "{}  {} {}  {}  {}",
+            self.parted_at().format("%Y-%m-%d"),
+            self.name,
+            self.stage.label(),
+            format_duration(self.age()),
+            farewell
+        )
+    }
+
+    // ゲームオーバー画面に表示する一生のまとめ
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = vec![
+            format!("{} ({})", self.name, self.stage.label()),
+            String::new(),
+            format!("一緒に過ごした時間: {}", format_duration(self.age())),
+            format!("餌をあげた回数:     {}回", self.care.fed),
+            format!("遊んだ回数:         {}回", self.care.played),
+            format!("お風呂に入れた回数: {}回", self.care.bathed),
+            format!("寝かせた回数:       {}回", self.care.slept),
+            String::new(),
+        ];
+        match self.outcome {
+            Outcome::Died { cause, at } => {
+                lines.push(format!("死因: {}", cause.label()));
+                lines.push(format!("{} に虹の橋を渡りました", at.format("%Y-%m-%d %H:%M")));
+            }
+            Outcome::RanAway { at, .. } => {
+                lines.push(format!("{} に近所の家の子になりました", at.format("%Y-%m-%d %H:%M")));
+            }
+        }
+        lines
+    }
+
+    fn age(&self) -> Duration {
+        Duration::seconds(self.age_seconds as i64)
+    }
+}
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rules::SleepRules;
+
+// 眠りの質。空腹・不潔だったり、起こされかけたりすると浅くなる
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quality {
+    Deep,     // ぐっすり
+    Light,    // うとうと
+    Restless, // 眠りが浅い
+}
+
+impl Quality {
+    pub fn of(value: f64) -> Self {
+        if value >= 0.8 {
+            Quality::Deep
+        } else if value >= 0.5 {
+            Quality::Light
+        } else {
+            Quality::Restless
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Quality::Deep => "ぐっすり",
+            Quality::Light => "うとうと",
+            Quality::Restless => "眠りが浅い",
+        }
+    }
+}
+
+// 眠っている間の回復。健康度は寝かせたときではなく、眠っている間に少しずつ回復する
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Nap {
+    // 眠りの質がずっと最高なら、起きるときに届く健康度（時間経過で減る分は別）
+    pub goal: f64,
+    // 眠りの質が最高のときの1秒あたりの回復量
+    rate: f64,
+    // 眠る時間と、残りの時間（秒）
+    seconds: f64,
+    remaining_seconds: f64,
+    // 起こされかけた回数
+    disturbances: u32,
+}
+
+impl Nap {
+    // 今の健康度 health から goal まで seconds 秒かけて回復する（goal の方が低ければ回復しない）
+    pub fn new(health: f64, goal: f64, seconds: f64) -> Self {
+        let seconds = seconds.max(1.0);
+        Self {
+            goal,
+            rate: (goal - health).max(0.0) / seconds,
+            seconds,
+            remaining_seconds: seconds,
+            disturbances: 0,
+        }
+    }
+
+    // 眠った割合（0-1）
+    pub fn progress(&self) -> f64 {
+        1.0 - self.remaining_seconds / self.seconds
+    }
+
+    pub fn disturbances(&self) -> u32 {
+        self.disturbances
+    }
+
+    pub fn disturb(&mut self) {
+        self.disturbances += 1;
+    }
+
+    // 眠りの質（0-1）
+    pub fn quality(&self, hunger: i32, cleanliness: i32, rules: &SleepRules) -> f64 {
+        let mut quality = rules.disturbed_quality.powi(self.disturbances as i32);
+        if hunger < rules.restless_below {
+            quality *= rules.hungry_quality;
+        }
+        if cleanliness < rules.restless_below {
+            quality *= rules.dirty_quality;
+        }
+        quality.clamp(0.0, 1.0)
+    }
+
+    // delta_seconds 秒眠る。回復した健康度を返す
+    pub fn rest(&mut self, delta_seconds: f64, quality: f64) -> f64 {
+        let slept = delta_seconds.clamp(0.0, self.remaining_seconds);
+        self.remaining_seconds -= slept;
+        self.rate * slept * quality
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.remaining_seconds <= 0.0
+    }
+}
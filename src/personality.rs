@@ -0,0 +1,161 @@
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg32;
+use serde::{Deserialize, Serialize};
+
+use crate::rules::DecayMultiplier;
+
+// 性格ごとの補正。アクションの増減量と時間経過による減少量に倍率をかける
+pub const TRAIT_RULES: &[TraitRule] = &[
+    TraitRule {
+        trait_: Trait::Playful,
+        feed: ONE,
+        play: DecayMultiplier { happiness: 1.5, hunger: 1.2, ..ONE },
+        bathe: ONE,
+        sleep_recovery: 1.0,
+        decay: DecayMultiplier { happiness: 1.3, ..ONE },
+    },
+    TraitRule {
+        trait_: Trait::Glutton,
+        feed: DecayMultiplier { hunger: 1.25, happiness: 1.5, ..ONE },
+        play: ONE,
+        bathe: ONE,
+        sleep_recovery: 1.0,
+        decay: DecayMultiplier { hunger: 1.3, ..ONE },
+    },
+    TraitRule {
+        trait_: Trait::Lazy,
+        feed: ONE,
+        play: DecayMultiplier { happiness: 0.7, hunger: 0.5, ..ONE },
+        bathe: ONE,
+        sleep_recovery: 1.2,
+        decay: DecayMultiplier { hunger: 0.8, ..ONE },
+    },
+    TraitRule {
+        trait_: Trait::WaterTolerant,
+        feed: ONE,
+        play: ONE,
+        bathe: DecayMultiplier { happiness: 0.3, health: 0.5, ..ONE },
+        sleep_recovery: 1.0,
+        decay: ONE,
+    },
+    TraitRule {
+        trait_: Trait::Shy,
+        feed: DecayMultiplier { happiness: 0.8, ..ONE },
+        play: DecayMultiplier { happiness: 0.7, ..ONE },
+        bathe: DecayMultiplier { happiness: 1.3, ..ONE },
+        sleep_recovery: 1.0,
+        decay: DecayMultiplier { happiness: 0.7, ..ONE },
+    },
+];
+
+const ONE: DecayMultiplier = DecayMultiplier {
+    hunger: 1.0,
+    happiness: 1.0,
+    cleanliness: 1.0,
+    health: 1.0,
+};
+
+// 1匹あたりの性格の最大数
+const MAX_TRAITS: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Trait {
+    Playful,       // 遊び好き
+    Glutton,       // 食いしん坊
+    Lazy,          // 怠け者
+    WaterTolerant, // 水好き
+    Shy,           // 人見知り
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TraitRule {
+    pub trait_: Trait,
+    pub feed: DecayMultiplier,
+    pub play: DecayMultiplier,
+    pub bathe: DecayMultiplier,
+    // 寝かせたときの回復量の倍率
+    pub sleep_recovery: f64,
+    pub decay: DecayMultiplier,
+}
+
+impl Trait {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Trait::Playful => "遊び好き",
+            Trait::Glutton => "食いしん坊",
+            Trait::Lazy => "怠け者",
+            Trait::WaterTolerant => "水好き",
+            Trait::Shy => "人見知り",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Trait::Playful => "遊ぶと大喜びするが、退屈しやすい",
+            Trait::Glutton => "ごはんが大好きで、お腹が空きやすい",
+            Trait::Lazy => "遊ぶのは苦手。よく眠り、あまりお腹が空かない",
+            Trait::WaterTolerant => "お風呂がそれほど嫌いではない",
+            Trait::Shy => "構われるのが苦手だが、ひとりでも平気",
+        }
+    }
+
+    pub fn rule(&self) -> &'static TraitRule {
+        TRAIT_RULES
+            .iter()
+            .find(|rule| rule.trait_ == *self)
+            .expect("すべての性格にルールがある")
+    }
+}
+
+// 猫の性格。同じ seed からは同じ性格が生まれる
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Personality {
+    pub seed: u64,
+    pub traits: Vec<Trait>,
+}
+
+impl Personality {
+    pub fn generate(seed: u64) -> Self {
+        let mut rng = Pcg32::seed_from_u64(seed);
+        let all: Vec<Trait> = TRAIT_RULES.iter().map(|rule| rule.trait_).collect();
+        let count = rng.gen_range(1..=MAX_TRAITS);
+        let traits = all.choose_multiple(&mut rng, count).copied().collect();
+        Self { seed, traits }
+    }
+
+    pub fn label(&self) -> String {
+        if self.traits.is_empty() {
+            return "おだやか".to_string();
+        }
+        let labels: Vec<&str> = self.traits.iter().map(|t| t.label()).collect();
+        labels.join("・")
+    }
+
+    pub fn feed(&self) -> DecayMultiplier {
+        self.combined(|rule| rule.feed)
+    }
+
+    pub fn play(&self) -> DecayMultiplier {
+        self.combined(|rule| rule.play)
+    }
+
+    pub fn bathe(&self) -> DecayMultiplier {
+        self.combined(|rule| rule.bathe)
+    }
+
+    pub fn decay(&self) -> DecayMultiplier {
+        self.combined(|rule| rule.decay)
+    }
+
+    pub fn sleep_recovery(&self) -> f64 {
+        self.traits.iter().map(|t| t.rule().sleep_recovery).product()
+    }
+
+    // 複数の性格の倍率を掛け合わせる
+    fn combined(&self, pick: impl Fn(&TraitRule) -> DecayMultiplier) -> DecayMultiplier {
+        self.traits
+            .iter()
+            .fold(ONE, |total, t| total.combine(&pick(t.rule())))
+    }
+}
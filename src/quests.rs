@@ -0,0 +1,256 @@
+use chrono::{DateTime, Datelike, Local, NaiveDate};
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_pcg::Pcg32;
+use serde::{Deserialize, Serialize};
+
+use crate::cat::{Cat, CatEvent, CatState};
+use crate::rules::QuestRules;
+use crate::states::Stat;
+
+// 毎日のクエストの候補。日付から決まる順に並べ替え、先頭から1日の数だけ出す
+pub const QUEST_POOL: &[Goal] = &[
+    Goal::KeepAbove { stat: Stat::Cleanliness, value: 50, minutes: 120 },
+    Goal::KeepAbove { stat: Stat::Happiness, value: 60, minutes: 60 },
+    Goal::KeepAbove { stat: Stat::Hunger, value: 50, minutes: 90 },
+    Goal::Care { care: Care::Play, times: 3, without: Some(Care::Bathe) },
+    Goal::Care { care: Care::Feed, times: 3, without: None },
+    Goal::Care { care: Care::Bathe, times: 1, without: None },
+    Goal::Reach { state: CatState::Full, times: 2 },
+    Goal::Reach { state: CatState::Happy, times: 3 },
+    Goal::Reach { state: CatState::Playful, times: 1 },
+];
+
+// クエストで数えるお世話
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Care {
+    Feed,
+    Play,
+    Bathe,
+}
+
+impl Care {
+    // この出来事がこのお世話か
+    fn matches(&self, event: &CatEvent) -> bool {
+        matches!(
+            (self, event),
+            (Care::Feed, CatEvent::Fed) | (Care::Play, CatEvent::Played) | (Care::Bathe, CatEvent::Bathed { .. })
+        )
+    }
+
+    fn verb(&self) -> &'static str {
+        match self {
+            Care::Feed => "餌をあげる",
+            Care::Play => "遊ぶ",
+            Care::Bathe => "お風呂に入れる",
+        }
+    }
+
+    fn without(&self) -> &'static str {
+        match self {
+            Care::Feed => "餌をあげずに",
+            Care::Play => "遊ばずに",
+            Care::Bathe => "お風呂に入れずに",
+        }
+    }
+}
+
+// クエストの目標（家族みんなで達成する）
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Goal {
+    // 元気な猫全員のステータスを value 以上のまま minutes 分続ける（下回ると数え直す）
+    KeepAbove { stat: Stat, value: i32, minutes: u32 },
+    // times 回お世話する。without のお世話をすると数え直す
+    Care { care: Care, times: u32, without: Option<Care> },
+    // いずれかの猫を times 回 state にする
+    Reach { state: CatState, times: u32 },
+}
+
+impl Goal {
+    pub fn label(&self) -> String {
+        match self {
+            Goal::KeepAbove { stat, value, minutes } => {
+                format!("{}を{}以上のまま{}保つ", stat.label(), value, minutes_label(*minutes))
+            }
+            Goal::Care { care, times, without } => format!(
+                "{}{}回{}",
+                without.map(|without| without.without()).unwrap_or_default(),
+                times,
+                care.verb()
+            ),
+            Goal::Reach { state, times } => format!("{}に{}回する", state.label(), times),
+        }
+    }
+
+    // 達成に必要な進み具合（KeepAbove は秒、ほかは回数）
+    pub fn target(&self) -> f64 {
+        match self {
+            Goal::KeepAbove { minutes, .. } => *minutes as f64 * 60.0,
+            Goal::Care { times, .. } | Goal::Reach { times, .. } => *times as f64,
+        }
+    }
+
+    // 「45/120分」「1/3回」
+    pub fn progress_label(&self, progress: f64) -> String {
+        match self {
+            Goal::KeepAbove { minutes, .. } => format!("{}/{}分", (progress / 60.0) as u32, minutes),
+            Goal::Care { times, .. } | Goal::Reach { times, .. } => format!("{}/{}回", progress as u32, times),
+        }
+    }
+}
+
+fn minutes_label(minutes: u32) -> String {
+    if minutes >= 60 && minutes.is_multiple_of(60) {
+        format!("{}時間", minutes / 60)
+    } else {
+        format!("{}分", minutes)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Quest {
+    pub goal: Goal,
+    pub progress: f64,
+}
+
+impl Quest {
+    pub fn is_done(&self) -> bool {
+        self.progress >= self.goal.target()
+    }
+
+    // 進める。今回で達成したら true
+    fn advance(&mut self, amount: f64) -> bool {
+        if self.is_done() {
+            return false;
+        }
+        self.progress = (self.progress + amount).min(self.goal.target());
+        self.is_done()
+    }
+
+    fn reset(&mut self) {
+        if !self.is_done() {
+            self.progress = 0.0;
+        }
+    }
+}
+
+// その日のクエスト（ゲーム内の日付）。日付が変わると新しいクエストになる
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestBoard {
+    date: Option<NaiveDate>,
+    quests: Vec<Quest>,
+    // 前に見た猫の状態（状態が変わった回数を数える。セーブしない）
+    #[serde(skip)]
+    seen: Vec<(u64, CatState)>,
+    // 小刻みに進めた間に入った状態（次の step で数える。セーブしない）
+    #[serde(skip)]
+    entered: Vec<CatState>,
+}
+
+// 同じ日付なら誰が遊んでも同じクエストになる
+pub fn generate(date: NaiveDate, rules: &QuestRules) -> Vec<Quest> {
+    let mut rng = Pcg32::seed_from_u64(date.num_days_from_ce() as u64);
+    let mut pool = QUEST_POOL.to_vec();
+    pool.shuffle(&mut rng);
+    pool.into_iter()
+        .take(rules.per_day)
+        .map(|goal| Quest { goal, progress: 0.0 })
+        .collect()
+}
+
+impl QuestBoard {
+    pub fn date(&self) -> Option<NaiveDate> {
+        self.date
+    }
+
+    pub fn quests(&self) -> &[Quest] {
+        &self.quests
+    }
+
+    pub fn done_count(&self) -> usize {
+        self.quests.iter().filter(|quest| quest.is_done()).count()
+    }
+
+    // 日付が変わっていれば新しいクエストにする
+    fn refresh(&mut self, now: DateTime<Local>, rules: &QuestRules) {
+        let today = now.date_naive();
+        if self.date != Some(today) {
+            self.date = Some(today);
+            self.quests = generate(today, rules);
+        }
+    }
+
+    // お世話の出来事で進める。達成したクエストを返す
+    pub fn observe(&mut self, event: &CatEvent, now: DateTime<Local>, rules: &QuestRules) -> Vec<Quest> {
+        self.refresh(now, rules);
+        let mut done = Vec::new();
+        for quest in &mut self.quests {
+            let Goal::Care { care, without, .. } = quest.goal else {
+                continue;
+            };
+            if without.is_some_and(|without| without.matches(event)) {
+                quest.reset();
+            } else if care.matches(event) && quest.advance(1.0) {
+                done.push(*quest);
+            }
+        }
+        done
+    }
+
+    // 倍速で小刻みに進めた間に猫が通った状態を覚えておき、次の step で数える
+    pub fn pass(&mut self, cat: &Cat, states: &[CatState]) {
+        for &state in states {
+            self.see(cat.id, state);
+        }
+    }
+
+    // 前に見たときから状態が変わっていれば、入った状態として覚える
+    fn see(&mut self, id: u64, state: CatState) {
+        match self.seen.iter_mut().find(|(seen_id, _)| *seen_id == id) {
+            Some((_, seen)) => {
+                if *seen != state {
+                    self.entered.push(state);
+                }
+                *seen = state;
+            }
+            None => self.seen.push((id, state)),
+        }
+    }
+
+    // 時間経過でステータスと状態の変化を見て進める。達成したクエストを返す
+    // 留守中はステータスが保たれていたか分からないので delta_seconds を 0 にして呼ぶ
+    pub fn step(&mut self, cats: &[Cat], delta_seconds: f64, now: DateTime<Local>, rules: &QuestRules) -> Vec<Quest> {
+        self.refresh(now, rules);
+        let alive: Vec<&Cat> = cats.iter().filter(|cat| cat.outcome().is_none()).collect();
+        // 前に見たときから状態が変わった猫の、新しい状態
+        for cat in &alive {
+            self.see(cat.id, cat.get_state());
+        }
+        self.seen.retain(|(id, _)| alive.iter().any(|cat| cat.id == *id));
+        let entered = std::mem::take(&mut self.entered);
+
+        let mut done = Vec::new();
+        for quest in &mut self.quests {
+            let finished = match quest.goal {
+                Goal::KeepAbove { stat, value, .. } => {
+                    if !alive.is_empty() && alive.iter().all(|cat| stat.value(cat) >= value) {
+                        quest.advance(delta_seconds.max(0.0))
+                    } else {
+                        quest.reset();
+                        false
+                    }
+                }
+                Goal::Reach { state, .. } => {
+                    let count = entered.iter().filter(|entered| **entered == state).count();
+                    count > 0 && quest.advance(count as f64)
+                }
+                Goal::Care { .. } => false,
+            };
+            if finished {
+                done.push(*quest);
+            }
+        }
+        done
+    }
+}
@@ -0,0 +1,246 @@
+use serde::{Deserialize, Serialize};
+
+use crate::art::{self, Animation};
+use crate::cat::Cat;
+use crate::rules::{ActionEffect, RelationRules};
+
+// 猫どうしの関わり合い（actor が仕掛け、partner が受ける）ごとのステータスと仲良し度の増減
+pub const INTERACTION_RULES: &[InteractionRule] = &[
+    InteractionRule {
+        interaction: Interaction::PlayTogether,
+        actor: ActionEffect { hunger: -5.0, happiness: 10.0, cleanliness: -2.0, health: 0.0 },
+        partner: ActionEffect { hunger: -5.0, happiness: 10.0, cleanliness: -2.0, health: 0.0 },
+        affinity: 3.0,
+        actor_animation: art::playing_left,
+        partner_animation: art::playing_right,
+    },
+    InteractionRule {
+        interaction: Interaction::Groom,
+        actor: ActionEffect { hunger: 0.0, happiness: 3.0, cleanliness: 0.0, health: 0.0 },
+        partner: ActionEffect { hunger: 0.0, happiness: 5.0, cleanliness: 15.0, health: 0.0 },
+        affinity: 5.0,
+        actor_animation: art::grooming,
+        partner_animation: art::groomed,
+    },
+    // お腹を空かせた猫が、餌をもらった猫のごはんを横取りしようとする
+    InteractionRule {
+        interaction: Interaction::FoodFight,
+        actor: ActionEffect { hunger: 10.0, happiness: -10.0, cleanliness: 0.0, health: -2.0 },
+        partner: ActionEffect { hunger: -10.0, happiness: -10.0, cleanliness: 0.0, health: 0.0 },
+        affinity: -8.0,
+        actor_animation: art::fighting,
+        partner_animation: art::defending,
+    },
+    // 構われなかった猫が、構われた猫にやきもちを焼く
+    InteractionRule {
+        interaction: Interaction::Jealousy,
+        actor: ActionEffect { hunger: 0.0, happiness: -15.0, cleanliness: 0.0, health: 0.0 },
+        partner: ActionEffect { hunger: 0.0, happiness: 0.0, cleanliness: 0.0, health: 0.0 },
+        affinity: -5.0,
+        actor_animation: art::sulking,
+        partner_animation: art::pampered,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interaction {
+    PlayTogether, // 一緒に遊ぶ
+    Groom,        // 毛づくろい
+    FoodFight,    // ごはんの取り合い
+    Jealousy,     // やきもち
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct InteractionRule {
+    pub interaction: Interaction,
+    pub actor: ActionEffect,
+    pub partner: ActionEffect,
+    pub affinity: f64,
+    pub actor_animation: Animation,
+    pub partner_animation: Animation,
+}
+
+impl Interaction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Interaction::PlayTogether => "一緒に遊ぶ",
+            Interaction::Groom => "毛づくろい",
+            Interaction::FoodFight => "ごはんの取り合い",
+            Interaction::Jealousy => "やきもち",
+        }
+    }
+
+    pub fn rule(&self) -> &'static InteractionRule {
+        INTERACTION_RULES
+            .iter()
+            .find(|rule| rule.interaction == *self)
+            .expect("すべての関わり合いにルールがある")
+    }
+
+    pub fn message(&self, actor: &str, partner: &str) -> String {
+        match self {
+            Interaction::PlayTogether => format!("🐾 {}と{}が一緒に遊んでいます", actor, partner),
+            Interaction::Groom => format!("💕 {}が{}の毛づくろいをしています", actor, partner),
+            Interaction::FoodFight => format!("💢 {}が{}のごはんを狙ってケンカに！", actor, partner),
+            Interaction::Jealousy => format!("😾 {}ばかり構われて{}がすねています", partner, actor),
+        }
+    }
+}
+
+// 仲良し度の目安
+pub fn affinity_label(affinity: f64) -> &'static str {
+    if affinity >= 80.0 {
+        "大の仲良し"
+    } else if affinity >= 60.0 {
+        "仲良し"
+    } else if affinity >= 40.0 {
+        "ふつう"
+    } else if affinity >= 20.0 {
+        "よそよそしい"
+    } else {
+        "犬猿の仲"
+    }
+}
+
+// 起きた関わり合い（猫は Cat::id で指す）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Happening {
+    pub interaction: Interaction,
+    pub actor: u64,
+    pub partner: u64,
+}
+
+// 2匹の関係。cats は小さい id から並べる
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Relationship {
+    pub cats: [u64; 2],
+    pub affinity: f64,
+    // 前に関わり合ってからの時間（秒）
+    seconds: f64,
+    // お世話の回数の差（正なら cats[0] のほうが多く構われている）
+    attention: i32,
+}
+
+// 家族の猫どうしの関係
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Relations {
+    pairs: Vec<Relationship>,
+}
+
+impl Relations {
+    // まだ関わったことのない2匹は None
+    pub fn affinity(&self, a: u64, b: u64) -> Option<f64> {
+        let key = key(a, b);
+        self.pairs.iter().find(|pair| pair.cats == key).map(|pair| pair.affinity)
+    }
+
+    fn pair_mut(&mut self, a: u64, b: u64, rules: &RelationRules) -> &mut Relationship {
+        let key = key(a, b);
+        let index = match self.pairs.iter().position(|pair| pair.cats == key) {
+            Some(index) => index,
+            None => {
+                self.pairs.push(Relationship {
+                    cats: key,
+                    affinity: rules.start_affinity,
+                    seconds: 0.0,
+                    attention: 0,
+                });
+                self.pairs.len() - 1
+            }
+        };
+        &mut self.pairs[index]
+    }
+
+    // 家族にいない猫との関係を忘れる
+    pub fn retain(&mut self, cats: &[Cat]) {
+        self.pairs
+            .retain(|pair| pair.cats.iter().all(|id| cats.iter().any(|cat| cat.id == *id)));
+    }
+
+    // 時間経過。interval_minutes ごとに、起きている2匹が毛づくろいか一緒に遊ぶ
+    pub fn step(&mut self, cats: &mut [Cat], delta_seconds: f64, rules: &RelationRules) -> Vec<Happening> {
+        let mut happenings = Vec::new();
+        for first in 0..cats.len() {
+            for second in first + 1..cats.len() {
+                let pair = self.pair_mut(cats[first].id, cats[second].id, rules);
+                pair.seconds += delta_seconds.max(0.0);
+                if pair.seconds < rules.interval_minutes * 60.0
+                    || !cats[first].can_perform_action()
+                    || !cats[second].can_perform_action()
+                {
+                    continue;
+                }
+                pair.seconds = 0.0;
+                // 汚れているほうを、きれいなほうが毛づくろいする
+                let (clean, dirty) = if cats[first].cleanliness <= cats[second].cleanliness {
+                    (second, first)
+                } else {
+                    (first, second)
+                };
+                if pair.affinity >= rules.groom_affinity && cats[dirty].cleanliness < rules.groom_cleanliness {
+                    happenings.push(perform(pair, cats, clean, dirty, Interaction::Groom));
+                } else if pair.affinity >= rules.play_affinity {
+                    happenings.push(perform(pair, cats, first, second, Interaction::PlayTogether));
+                } else {
+                    pair.affinity = (pair.affinity + rules.settle).min(100.0);
+                }
+            }
+        }
+        happenings
+    }
+
+    // fed が餌をもらった。お腹を空かせた仲の良くない猫が取り合いに来る
+    pub fn fed(&mut self, cats: &mut [Cat], fed: usize, rules: &RelationRules) -> Vec<Happening> {
+        let mut happenings = Vec::new();
+        for other in 0..cats.len() {
+            if other == fed || !cats[other].can_perform_action() || cats[other].hunger >= rules.fight_hunger {
+                continue;
+            }
+            let pair = self.pair_mut(cats[fed].id, cats[other].id, rules);
+            if pair.affinity < rules.share_affinity {
+                happenings.push(perform(pair, cats, other, fed, Interaction::FoodFight));
+            }
+        }
+        happenings
+    }
+
+    // cared がお世話された。構われない猫はやきもちを焼く
+    pub fn cared(&mut self, cats: &mut [Cat], cared: usize, rules: &RelationRules) -> Vec<Happening> {
+        let mut happenings = Vec::new();
+        for other in 0..cats.len() {
+            if other == cared {
+                continue;
+            }
+            let pair = self.pair_mut(cats[cared].id, cats[other].id, rules);
+            pair.attention += if pair.cats[0] == cats[cared].id { 1 } else { -1 };
+            if pair.attention.unsigned_abs() >= rules.jealousy_gap && cats[other].can_perform_action() {
+                pair.attention = 0;
+                happenings.push(perform(pair, cats, other, cared, Interaction::Jealousy));
+            }
+        }
+        happenings
+    }
+}
+
+fn key(a: u64, b: u64) -> [u64; 2] {
+    [a.min(b), a.max(b)]
+}
+
+fn perform(
+    pair: &mut Relationship,
+    cats: &mut [Cat],
+    actor: usize,
+    partner: usize,
+    interaction: Interaction,
+) -> Happening {
+    let rule = interaction.rule();
+    cats[actor].interact(rule.actor);
+    cats[partner].interact(rule.partner);
+    pair.affinity = (pair.affinity + rule.affinity).clamp(0.0, 100.0);
+    Happening {
+        interaction,
+        actor: cats[actor].id,
+        partner: cats[partner].id,
+    }
+}
@@ -0,0 +1,1050 @@
+use serde::Deserialize;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::daytime::TimeOfDay;
+use crate::items::ItemKind;
+use crate::quests::QUEST_POOL;
+
+// 秒で指定する項目の上限
+const DAY_SECONDS: i64 = 24 * 60 * 60;
+
+// ゲームバランスの設定。--rules で TOML ファイルを読み込み、省略した項目は既定値を使う
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Rules {
+    pub decay: DecayRules,
+    pub feed: FeedRules,
+    // ごはんの在庫が無くてもあげられる、ありあわせのごはん
+    pub scraps: ActionEffect,
+    pub play: ActionEffect,
+    pub bathe: ActionEffect,
+    pub sleep: SleepRules,
+    pub life: LifeRules,
+    pub death: DeathRules,
+    pub items: ItemRules,
+    pub coins: CoinRules,
+    pub shop: ShopRules,
+    pub illness: IllnessRules,
+    pub daytime: DaytimeRules,
+    pub relations: RelationRules,
+    pub behavior: BehaviorRules,
+    pub weight: WeightRules,
+    pub bond: BondRules,
+    pub quests: QuestRules,
+    pub events: EventRules,
+    pub difficulty: DifficultyRules,
+}
+
+// 時間経過による減少量（1分あたり）
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DecayRules {
+    pub hunger: f64,
+    pub happiness: f64,
+    pub cleanliness: f64,
+    pub health: f64,
+    // 他のステータスが penalty_threshold 未満のときの健康度の減少量
+    pub health_penalty: f64,
+    pub penalty_threshold: i32,
+}
+
+// アクションによるステータスの増減
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ActionEffect {
+    pub hunger: f64,
+    pub happiness: f64,
+    pub cleanliness: f64,
+    pub health: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FeedRules {
+    pub hunger: f64,
+    pub happiness: f64,
+    pub cleanliness: f64,
+    pub health: f64,
+    // 餌やり後に満腹と判定する時間
+    pub full_seconds: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SleepRules {
+    pub duration_seconds: i64,
+    // 眠っている間に健康度が (空腹度 + 幸福度 + 清潔度) / recovery_divisor まで少しずつ回復する
+    pub recovery_divisor: f64,
+    // 空腹度・清潔度が restless_below 未満だと、眠りの質（回復の速さ）に hungry_quality / dirty_quality をかける
+    pub restless_below: i32,
+    pub hungry_quality: f64,
+    pub dirty_quality: f64,
+    // 起こされかけるたびに眠りの質にかける倍率
+    pub disturbed_quality: f64,
+    // 起こしたときの幸福度の減少
+    pub wake_happiness: f64,
+}
+
+// アイテムの効果（ドライフードは [feed]、ありあわせのごはんは [scraps]、手で遊ぶのは [play]）
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ItemRules {
+    pub wet_food: ActionEffect,
+    pub treat: ActionEffect,
+    pub feather_toy: ActionEffect,
+    pub ball: ActionEffect,
+    pub catnip: ActionEffect,
+    pub medicine: ActionEffect,
+}
+
+// コインの稼ぎ方
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CoinRules {
+    // 新しく始めたときの所持金
+    pub start: u32,
+    // 上機嫌・満腹・普通で過ごした1分ごとのコイン
+    pub per_good_minute: u32,
+    // 空腹・不潔にならずに streak_hours 時間続くごとのボーナス
+    pub streak_hours: f64,
+    pub streak_bonus: u32,
+}
+
+// ショップの値段
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ShopRules {
+    pub dry_food: u32,
+    pub wet_food: u32,
+    pub treat: u32,
+    pub feather_toy: u32,
+    pub ball: u32,
+    pub catnip: u32,
+    pub medicine: u32,
+    pub ribbon: u32,
+    pub bell: u32,
+    pub bandana: u32,
+}
+
+// 成長段階。育てた時間（時間単位）で子猫 → 成猫 → シニア猫と進む
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LifeRules {
+    pub adult_hours: f64,
+    pub senior_hours: f64,
+    // 段階ごとの減少量の倍率
+    pub kitten: DecayMultiplier,
+    pub adult: DecayMultiplier,
+    pub senior: DecayMultiplier,
+}
+
+// 健康度 0 が grace_minutes 続くとお別れ。gentle では近所の家へ家出し、呼び戻せる
+// mortal が false なら瀕死のままお別れにならない
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DeathRules {
+    pub grace_minutes: f64,
+    pub gentle: bool,
+    pub mortal: bool,
+    // 家出した猫が戻るまでに呼ぶ回数
+    pub coax_attempts: u32,
+}
+
+// 時間帯（時）と、時間帯ごとのふるまい
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DaytimeRules {
+    pub morning_hour: u32,
+    pub day_hour: u32,
+    pub dusk_hour: u32,
+    pub night_hour: u32,
+    // 夕方の減少量の倍率と、遊んだときの幸福度の倍率
+    pub dusk: DecayMultiplier,
+    pub dusk_play: f64,
+    // 夜の減少量の倍率
+    pub night: DecayMultiplier,
+    // 夜に doze_after_minutes 起きていると、doze_minutes うとうと眠る
+    pub doze_after_minutes: f64,
+    pub doze_minutes: f64,
+}
+
+// 病気のかかりやすさと動物病院
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct IllnessRules {
+    // 健康度 low_health 未満が cold_minutes 続くと風邪
+    pub low_health: i32,
+    pub cold_minutes: f64,
+    // 清潔度 dirty_cleanliness 未満が skin_minutes 続くと皮膚炎
+    pub dirty_cleanliness: i32,
+    pub skin_minutes: f64,
+    // 空腹度 overfeed_hunger 以上で餌をもらうのが overfeed_window_minutes 以内に overfeed_count 回で腹痛
+    pub overfeed_hunger: i32,
+    pub overfeed_count: u32,
+    pub overfeed_window_minutes: f64,
+    // お風呂に hairball_hours 入らないと毛玉
+    pub hairball_hours: f64,
+    // かかってからこの時間で症状が重くなる
+    pub moderate_minutes: f64,
+    pub severe_minutes: f64,
+    // 動物病院の費用と、帰ってくるまでの時間
+    pub vet_price: u32,
+    pub vet_minutes: f64,
+}
+
+// 同じ家で暮らす猫どうしの関係（仲良し度は 0-100）
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RelationRules {
+    // 初めて一緒に暮らす2匹の仲良し度
+    pub start_affinity: f64,
+    // 2匹が関わり合う間隔
+    pub interval_minutes: f64,
+    // 仲良し度 play_affinity 以上なら一緒に遊ぶ。それ未満なら距離を置いて settle ずつ慣れていく
+    pub play_affinity: f64,
+    pub settle: f64,
+    // 仲良し度 groom_affinity 以上なら、清潔度 groom_cleanliness 未満の相手を毛づくろいする
+    pub groom_affinity: f64,
+    pub groom_cleanliness: i32,
+    // 餌をもらった猫のそばに空腹度 fight_hunger 未満の猫がいると、仲良し度 share_affinity 未満ならケンカになる
+    pub fight_hunger: i32,
+    pub share_affinity: f64,
+    // お世話の回数の差が jealousy_gap になると、構われなかった猫がやきもちを焼く
+    pub jealousy_gap: u32,
+}
+
+// 猫がひとりでにするふるまい
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BehaviorRules {
+    // お世話されずにひとりで過ごす時間がこれだけ続くと、何かを始める
+    pub interval_minutes: f64,
+    // 健康度 nap_health 未満ならひと眠り、清潔度 groom_cleanliness 未満なら毛づくろい、
+    // 幸福度 mischief_happiness 未満なら物を落とす。どれでもなければしっぽを追いかける
+    pub nap_health: i32,
+    pub groom_cleanliness: i32,
+    pub mischief_happiness: i32,
+}
+
+// 飼い主との絆（0-100）と、絆でできるようになること
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BondRules {
+    // 上機嫌・満腹・遊びたい・普通で過ごすと1時間あたり good_gain 深まり、
+    // 空腹・不潔・不機嫌・痩せすぎ・瀕死で放っておかれると1時間あたり neglect_loss 薄れる
+    pub good_gain: f64,
+    pub neglect_loss: f64,
+    // お世話（餌・遊び・睡眠・薬）1回で深まる絆と、お風呂1回で薄れる絆
+    pub care_gain: f64,
+    pub bath_loss: f64,
+    // この絆でお出迎え・カーソルについてくる・お風呂をがまんするようになる
+    pub greet_bond: f64,
+    pub follow_bond: f64,
+    pub bath_bond: f64,
+    // お風呂をがまんするときの幸福度の減少の倍率
+    pub bath_tolerance: f64,
+}
+
+// 偶然の出来事が起きる確率（1分あたり）
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EventRules {
+    // 幸福度が gift_happiness 以上のとき、ネズミをプレゼントしてくれる
+    pub gift_chance: f64,
+    pub gift_happiness: i32,
+    // コップを落とす（幸福度0のときの確率。幸福度が高いほど起きにくい）
+    pub cup_chance: f64,
+    // 雷（夜は storm_night 倍）
+    pub storm_chance: f64,
+    pub storm_night: f64,
+    // 日だまり（朝と昼だけ）
+    pub sunbeam_chance: f64,
+}
+
+// 毎日のクエスト
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct QuestRules {
+    // 1日に出るクエストの数
+    pub per_day: usize,
+    // クエスト1つを達成したときのコイン
+    pub reward: u32,
+}
+
+// 難易度。ふつうはほかの設定のまま、のんびりときびしいは [decay] と [sleep] の値に倍率をかけ、お別れを無くすこともできる
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DifficultyRules {
+    pub relaxed: DifficultyPreset,
+    pub hardcore: DifficultyPreset,
+}
+
+// 難易度ごとの倍率（省略した項目はふつうと同じ）。ほかの設定の値にかけるので、--rules で変えた値も生きる
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DifficultyPreset {
+    // [decay] の減少量（健康度の減少を含む）にかける倍率
+    pub decay_scale: f64,
+    // [decay] の penalty_threshold にかける倍率（四捨五入）
+    pub threshold_scale: f64,
+    // [sleep] の duration_seconds にかける倍率
+    pub sleep_scale: f64,
+    // false なら [death] の mortal に関係なくお別れしない
+    pub mortal: bool,
+}
+
+// 体重（kg）と体型
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WeightRules {
+    // 体重がこの範囲を外れると痩せすぎ・太りすぎ
+    pub underweight_kg: f64,
+    pub overweight_kg: f64,
+    // 満腹を超えて食べた空腹度1あたりに増える体重
+    pub overfeed_gain: f64,
+    // 1回遊ぶと減る体重
+    pub play_loss: f64,
+    // 空腹度 starve_hunger 未満のあいだ、1分あたり starve_loss ずつ痩せる
+    pub starve_hunger: i32,
+    pub starve_loss: f64,
+    // 空腹度 well_fed_hunger 以上なら、迎えたときの体重まで1分あたり recover_gain ずつ戻る
+    pub well_fed_hunger: i32,
+    pub recover_gain: f64,
+    // 太りすぎのときの健康度の回復の倍率
+    pub overweight_recovery: f64,
+    // 痩せすぎのときの減少量の倍率
+    pub underweight: DecayMultiplier,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DecayMultiplier {
+    pub hunger: f64,
+    pub happiness: f64,
+    pub cleanliness: f64,
+    pub health: f64,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            decay: DecayRules::default(),
+            feed: FeedRules::default(),
+            scraps: ActionEffect {
+                hunger: 25.0,
+                happiness: 0.0,
+                cleanliness: -10.0,
+                health: 0.0,
+            },
+            play: ActionEffect {
+                hunger: -15.0,
+                happiness: 25.0,
+                cleanliness: -5.0,
+                health: 0.0,
+            },
+            bathe: ActionEffect {
+                hunger: 0.0,
+                happiness: -30.0,
+                cleanliness: 60.0,
+                health: -10.0,
+            },
+            sleep: SleepRules::default(),
+            life: LifeRules::default(),
+            death: DeathRules::default(),
+            items: ItemRules::default(),
+            coins: CoinRules::default(),
+            shop: ShopRules::default(),
+            illness: IllnessRules::default(),
+            daytime: DaytimeRules::default(),
+            relations: RelationRules::default(),
+            behavior: BehaviorRules::default(),
+            weight: WeightRules::default(),
+            bond: BondRules::default(),
+            quests: QuestRules::default(),
+            events: EventRules::default(),
+            difficulty: DifficultyRules::default(),
+        }
+    }
+}
+
+impl Default for ItemRules {
+    fn default() -> Self {
+        Self {
+            wet_food: ActionEffect {
+                hunger: 50.0,
+                happiness: 20.0,
+                cleanliness: -15.0,
+                health: 0.0,
+            },
+            treat: ActionEffect {
+                hunger: 10.0,
+                happiness: 25.0,
+                cleanliness: 0.0,
+                health: -2.0,
+            },
+            feather_toy: ActionEffect {
+                hunger: -15.0,
+                happiness: 35.0,
+                cleanliness: -5.0,
+                health: 0.0,
+            },
+            ball: ActionEffect {
+                hunger: -20.0,
+                happiness: 20.0,
+                cleanliness: -5.0,
+                health: 5.0,
+            },
+            catnip: ActionEffect {
+                hunger: -5.0,
+                happiness: 45.0,
+                cleanliness: 0.0,
+                health: 0.0,
+            },
+            // 猫は薬が苦手
+            medicine: ActionEffect {
+                hunger: 0.0,
+                happiness: -10.0,
+                cleanliness: 0.0,
+                health: 30.0,
+            },
+        }
+    }
+}
+
+impl Default for CoinRules {
+    fn default() -> Self {
+        Self {
+            start: 50,
+            per_good_minute: 1,
+            streak_hours: 1.0,
+            streak_bonus: 10,
+        }
+    }
+}
+
+impl Default for ShopRules {
+    fn default() -> Self {
+        Self {
+            dry_food: 30,
+            wet_food: 15,
+            treat: 5,
+            feather_toy: 20,
+            ball: 40,
+            catnip: 25,
+            medicine: 50,
+            ribbon: 100,
+            bell: 150,
+            bandana: 120,
+        }
+    }
+}
+
+impl Default for DaytimeRules {
+    fn default() -> Self {
+        Self {
+            morning_hour: 5,
+            day_hour: 10,
+            dusk_hour: 17,
+            night_hour: 21,
+            // 夕方はそわそわして退屈しやすい
+            dusk: DecayMultiplier {
+                happiness: 1.3,
+                ..DecayMultiplier::default()
+            },
+            dusk_play: 1.3,
+            // 夜は動かないのでお腹が空きにくく、汚れにくい
+            night: DecayMultiplier {
+                hunger: 0.6,
+                happiness: 0.8,
+                cleanliness: 0.5,
+                health: 1.0,
+            },
+            doze_after_minutes: 30.0,
+            doze_minutes: 20.0,
+        }
+    }
+}
+
+impl Default for IllnessRules {
+    fn default() -> Self {
+        Self {
+            low_health: 40,
+            cold_minutes: 30.0,
+            dirty_cleanliness: 20,
+            skin_minutes: 60.0,
+            overfeed_hunger: 90,
+            overfeed_count: 2,
+            overfeed_window_minutes: 10.0,
+            hairball_hours: 24.0,
+            moderate_minutes: 30.0,
+            severe_minutes: 90.0,
+            vet_price: 80,
+            vet_minutes: 30.0,
+        }
+    }
+}
+
+impl Default for RelationRules {
+    fn default() -> Self {
+        Self {
+            start_affinity: 50.0,
+            interval_minutes: 15.0,
+            play_affinity: 40.0,
+            settle: 2.0,
+            groom_affinity: 60.0,
+            groom_cleanliness: 50,
+            fight_hunger: 40,
+            share_affinity: 80.0,
+            jealousy_gap: 3,
+        }
+    }
+}
+
+impl Default for BehaviorRules {
+    fn default() -> Self {
+        Self {
+            interval_minutes: 20.0,
+            nap_health: 60,
+            groom_cleanliness: 70,
+            mischief_happiness: 50,
+        }
+    }
+}
+
+impl Default for BondRules {
+    fn default() -> Self {
+        Self {
+            good_gain: 2.0,
+            neglect_loss: 4.0,
+            care_gain: 0.5,
+            bath_loss: 3.0,
+            greet_bond: 30.0,
+            follow_bond: 50.0,
+            bath_bond: 70.0,
+            bath_tolerance: 0.5,
+        }
+    }
+}
+
+impl Default for EventRules {
+    fn default() -> Self {
+        Self {
+            gift_chance: 0.01,
+            gift_happiness: 60,
+            cup_chance: 0.02,
+            storm_chance: 0.003,
+            storm_night: 3.0,
+            sunbeam_chance: 0.01,
+        }
+    }
+}
+
+impl EventRules {
+    // 偶然の出来事が起きない設定（ほかの仕組みを確かめるテスト用）
+    pub fn quiet() -> Self {
+        Self {
+            gift_chance: 0.0,
+            cup_chance: 0.0,
+            storm_chance: 0.0,
+            sunbeam_chance: 0.0,
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for DifficultyRules {
+    fn default() -> Self {
+        Self {
+            // 何時間か留守にしても平気で、瀕死になってもお別れしない
+            relaxed: DifficultyPreset {
+                decay_scale: 0.1,
+                threshold_scale: 0.5,
+                sleep_scale: 0.5,
+                mortal: false,
+            },
+            hardcore: DifficultyPreset {
+                decay_scale: 1.5,
+                threshold_scale: 1.33,
+                sleep_scale: 1.5,
+                mortal: true,
+            },
+        }
+    }
+}
+
+impl Default for DifficultyPreset {
+    fn default() -> Self {
+        Self {
+            decay_scale: 1.0,
+            threshold_scale: 1.0,
+            sleep_scale: 1.0,
+            mortal: true,
+        }
+    }
+}
+
+impl Default for QuestRules {
+    fn default() -> Self {
+        Self {
+            per_day: 3,
+            reward: 30,
+        }
+    }
+}
+
+impl Default for WeightRules {
+    fn default() -> Self {
+        Self {
+            underweight_kg: 3.0,
+            overweight_kg: 6.0,
+            overfeed_gain: 0.01,
+            play_loss: 0.1,
+            starve_hunger: 20,
+            starve_loss: 0.01,
+            well_fed_hunger: 60,
+            recover_gain: 0.005,
+            overweight_recovery: 0.7,
+            // 痩せすぎると体力が落ちる
+            underweight: DecayMultiplier {
+                health: 1.3,
+                ..DecayMultiplier::default()
+            },
+        }
+    }
+}
+
+impl Default for DecayRules {
+    fn default() -> Self {
+        Self {
+            hunger: 2.5,
+            happiness: 2.0,
+            cleanliness: 1.0,
+            health: 2.0,
+            health_penalty: 4.0,
+            penalty_threshold: 30,
+        }
+    }
+}
+
+impl Default for FeedRules {
+    fn default() -> Self {
+        Self {
+            hunger: 40.0,
+            happiness: 10.0,
+            cleanliness: -10.0,
+            health: 0.0,
+            full_seconds: 30,
+        }
+    }
+}
+
+impl Default for SleepRules {
+    fn default() -> Self {
+        Self {
+            duration_seconds: 600,
+            recovery_divisor: 3.0,
+            restless_below: 30,
+            hungry_quality: 0.5,
+            dirty_quality: 0.7,
+            disturbed_quality: 0.8,
+            wake_happiness: 15.0,
+        }
+    }
+}
+
+impl Default for LifeRules {
+    fn default() -> Self {
+        Self {
+            adult_hours: 12.0,
+            senior_hours: 240.0,
+            // 子猫はお腹が空きやすい
+            kitten: DecayMultiplier {
+                hunger: 1.5,
+                ..DecayMultiplier::default()
+            },
+            adult: DecayMultiplier::default(),
+            // シニア猫は体調を崩しやすい
+            senior: DecayMultiplier {
+                health: 1.5,
+                ..DecayMultiplier::default()
+            },
+        }
+    }
+}
+
+impl Default for DeathRules {
+    fn default() -> Self {
+        Self {
+            grace_minutes: 30.0,
+            gentle: false,
+            mortal: true,
+            coax_attempts: 3,
+        }
+    }
+}
+
+impl Default for DecayMultiplier {
+    fn default() -> Self {
+        Self {
+            hunger: 1.0,
+            happiness: 1.0,
+            cleanliness: 1.0,
+            health: 1.0,
+        }
+    }
+}
+
+impl DaytimeRules {
+    // 時間帯ごとの減少量の倍率
+    pub fn multiplier(&self, time: TimeOfDay) -> DecayMultiplier {
+        match time {
+            TimeOfDay::Dusk => self.dusk,
+            TimeOfDay::Night => self.night,
+            TimeOfDay::Morning | TimeOfDay::Day => DecayMultiplier::default(),
+        }
+    }
+}
+
+impl DecayMultiplier {
+    // 倍率の掛け合わせ（成長段階 × 性格 など）
+    pub fn combine(&self, other: &DecayMultiplier) -> DecayMultiplier {
+        DecayMultiplier {
+            hunger: self.hunger * other.hunger,
+            happiness: self.happiness * other.happiness,
+            cleanliness: self.cleanliness * other.cleanliness,
+            health: self.health * other.health,
+        }
+    }
+}
+
+impl ActionEffect {
+    // ステータスごとに倍率をかけた増減量
+    pub fn scaled(&self, scale: &DecayMultiplier) -> ActionEffect {
+        ActionEffect {
+            hunger: self.hunger * scale.hunger,
+            happiness: self.happiness * scale.happiness,
+            cleanliness: self.cleanliness * scale.cleanliness,
+            health: self.health * scale.health,
+        }
+    }
+}
+
+impl FeedRules {
+    pub fn effect(&self) -> ActionEffect {
+        ActionEffect {
+            hunger: self.hunger,
+            happiness: self.happiness,
+            cleanliness: self.cleanliness,
+            health: self.health,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RulesError {
+    pub path: Option<PathBuf>,
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.path, self.line) {
+            (Some(path), Some(line)) => write!(f, "{}:{}: ", path.display(), line)?,
+            (Some(path), None) => write!(f, "{}: ", path.display())?,
+            (None, Some(line)) => write!(f, "{}行目: ", line)?,
+            (None, None) => {}
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for RulesError {}
+
+impl Rules {
+    // アイテムを使ったときの増減量
+    pub fn item_effect(&self, kind: ItemKind) -> ActionEffect {
+        match kind {
+            ItemKind::DryFood => self.feed.effect(),
+            ItemKind::WetFood => self.items.wet_food,
+            ItemKind::Treat => self.items.treat,
+            ItemKind::FeatherToy => self.items.feather_toy,
+            ItemKind::Ball => self.items.ball,
+            ItemKind::Catnip => self.items.catnip,
+            ItemKind::Medicine => self.items.medicine,
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, RulesError> {
+        let source = fs::read_to_string(path).map_err(|err| RulesError {
+            path: Some(path.to_path_buf()),
+            line: None,
+            message: format!("読み込めません: {}", err),
+        })?;
+        Self::parse(&source).map_err(|err| RulesError {
+            path: Some(path.to_path_buf()),
+            ..err
+        })
+    }
+
+    pub fn parse(source: &str) -> Result<Self, RulesError> {
+        let rules: Rules = toml::from_str(source).map_err(|err| RulesError {
+            path: None,
+            line: err.span().map(|span| line_of_offset(source, span.start)),
+            message: err.message().trim().replace('\n', " "),
+        })?;
+        rules.validate(source)?;
+        Ok(rules)
+    }
+
+    fn validate(&self, source: &str) -> Result<(), RulesError> {
+        let mut checks: Vec<(&str, &str, bool, &str)> = vec![
+            ("decay", "hunger", non_negative(self.decay.hunger), "0以上"),
+            ("decay", "happiness", non_negative(self.decay.happiness), "0以上"),
+            ("decay", "cleanliness", non_negative(self.decay.cleanliness), "0以上"),
+            ("decay", "health", non_negative(self.decay.health), "0以上"),
+            ("decay", "health_penalty", non_negative(self.decay.health_penalty), "0以上"),
+            (
+                "decay",
+                "penalty_threshold",
+                (0..=100).contains(&self.decay.penalty_threshold),
+                "0から100",
+            ),
+            ("feed", "full_seconds", (0..=DAY_SECONDS).contains(&self.feed.full_seconds), "0から86400（1日）"),
+            (
+                "sleep",
+                "duration_seconds",
+                (1..=DAY_SECONDS).contains(&self.sleep.duration_seconds),
+                "1から86400（1日）",
+            ),
+            ("sleep", "recovery_divisor", positive(self.sleep.recovery_divisor), "正の数"),
+            ("sleep", "restless_below", (0..=100).contains(&self.sleep.restless_below), "0から100"),
+            ("sleep", "hungry_quality", (0.0..=1.0).contains(&self.sleep.hungry_quality), "0から1"),
+            ("sleep", "dirty_quality", (0.0..=1.0).contains(&self.sleep.dirty_quality), "0から1"),
+            ("sleep", "disturbed_quality", (0.0..=1.0).contains(&self.sleep.disturbed_quality), "0から1"),
+            ("sleep", "wake_happiness", (0.0..=100.0).contains(&self.sleep.wake_happiness), "0から100"),
+            ("life", "adult_hours", non_negative(self.life.adult_hours), "0以上"),
+            (
+                "life",
+                "senior_hours",
+                self.life.senior_hours.is_finite() && self.life.senior_hours > self.life.adult_hours,
+                "adult_hours より大きい数",
+            ),
+            ("death", "grace_minutes", non_negative(self.death.grace_minutes), "0以上"),
+            ("death", "coax_attempts", self.death.coax_attempts > 0, "正の数"),
+            ("coins", "streak_hours", positive(self.coins.streak_hours), "正の数"),
+            ("illness", "low_health", (0..=100).contains(&self.illness.low_health), "0から100"),
+            ("illness", "cold_minutes", positive(self.illness.cold_minutes), "正の数"),
+            (
+                "illness",
+                "dirty_cleanliness",
+                (0..=100).contains(&self.illness.dirty_cleanliness),
+                "0から100",
+            ),
+            ("illness", "skin_minutes", positive(self.illness.skin_minutes), "正の数"),
+            ("illness", "overfeed_hunger", (0..=100).contains(&self.illness.overfeed_hunger), "0から100"),
+            ("illness", "overfeed_count", self.illness.overfeed_count > 0, "正の数"),
+            ("illness", "overfeed_window_minutes", positive(self.illness.overfeed_window_minutes), "正の数"),
+            ("illness", "hairball_hours", positive(self.illness.hairball_hours), "正の数"),
+            ("illness", "moderate_minutes", non_negative(self.illness.moderate_minutes), "0以上"),
+            (
+                "illness",
+                "severe_minutes",
+                self.illness.severe_minutes.is_finite() && self.illness.severe_minutes > self.illness.moderate_minutes,
+                "moderate_minutes より大きい数",
+            ),
+            ("illness", "vet_minutes", positive(self.illness.vet_minutes), "正の数"),
+            ("daytime", "morning_hour", self.daytime.morning_hour < 24, "0から23"),
+            (
+                "daytime",
+                "day_hour",
+                (self.daytime.morning_hour..24).contains(&self.daytime.day_hour),
+                "morning_hour から23",
+            ),
+            (
+                "daytime",
+                "dusk_hour",
+                (self.daytime.day_hour..24).contains(&self.daytime.dusk_hour),
+                "day_hour から23",
+            ),
+            (
+                "daytime",
+                "night_hour",
+                (self.daytime.dusk_hour..24).contains(&self.daytime.night_hour),
+                "dusk_hour から23",
+            ),
+            ("daytime", "dusk_play", non_negative(self.daytime.dusk_play), "0以上"),
+            ("daytime", "doze_after_minutes", positive(self.daytime.doze_after_minutes), "正の数"),
+            ("daytime", "doze_minutes", positive(self.daytime.doze_minutes), "正の数"),
+            (
+                "relations",
+                "start_affinity",
+                (0.0..=100.0).contains(&self.relations.start_affinity),
+                "0から100",
+            ),
+            ("relations", "interval_minutes", positive(self.relations.interval_minutes), "正の数"),
+            (
+                "relations",
+                "play_affinity",
+                (0.0..=100.0).contains(&self.relations.play_affinity),
+                "0から100",
+            ),
+            ("relations", "settle", non_negative(self.relations.settle), "0以上"),
+            (
+                "relations",
+                "groom_affinity",
+                (0.0..=100.0).contains(&self.relations.groom_affinity),
+                "0から100",
+            ),
+            (
+                "relations",
+                "groom_cleanliness",
+                (0..=100).contains(&self.relations.groom_cleanliness),
+                "0から100",
+            ),
+            ("relations", "fight_hunger", (0..=100).contains(&self.relations.fight_hunger), "0から100"),
+            (
+                "relations",
+                "share_affinity",
+                (0.0..=100.0).contains(&self.relations.share_affinity),
+                "0から100",
+            ),
+            ("relations", "jealousy_gap", self.relations.jealousy_gap > 0, "正の数"),
+            ("behavior", "interval_minutes", positive(self.behavior.interval_minutes), "正の数"),
+            ("behavior", "nap_health", (0..=100).contains(&self.behavior.nap_health), "0から100"),
+            (
+                "behavior",
+                "groom_cleanliness",
+                (0..=100).contains(&self.behavior.groom_cleanliness),
+                "0から100",
+            ),
+            (
+                "behavior",
+                "mischief_happiness",
+                (0..=100).contains(&self.behavior.mischief_happiness),
+                "0から100",
+            ),
+            ("weight", "underweight_kg", positive(self.weight.underweight_kg), "正の数"),
+            (
+                "weight",
+                "overweight_kg",
+                self.weight.overweight_kg.is_finite() && self.weight.overweight_kg > self.weight.underweight_kg,
+                "underweight_kg より大きい数",
+            ),
+            ("weight", "overfeed_gain", non_negative(self.weight.overfeed_gain), "0以上"),
+            ("weight", "play_loss", non_negative(self.weight.play_loss), "0以上"),
+            ("weight", "starve_hunger", (0..=100).contains(&self.weight.starve_hunger), "0から100"),
+            ("weight", "starve_loss", non_negative(self.weight.starve_loss), "0以上"),
+            ("weight", "well_fed_hunger", (0..=100).contains(&self.weight.well_fed_hunger), "0から100"),
+            ("weight", "recover_gain", non_negative(self.weight.recover_gain), "0以上"),
+            ("weight", "overweight_recovery", non_negative(self.weight.overweight_recovery), "0以上"),
+            ("bond", "good_gain", non_negative(self.bond.good_gain), "0以上"),
+            ("bond", "neglect_loss", non_negative(self.bond.neglect_loss), "0以上"),
+            ("bond", "care_gain", non_negative(self.bond.care_gain), "0以上"),
+            ("bond", "bath_loss", non_negative(self.bond.bath_loss), "0以上"),
+            ("bond", "greet_bond", (0.0..=100.0).contains(&self.bond.greet_bond), "0から100"),
+            ("bond", "follow_bond", (0.0..=100.0).contains(&self.bond.follow_bond), "0から100"),
+            ("bond", "bath_bond", (0.0..=100.0).contains(&self.bond.bath_bond), "0から100"),
+            ("bond", "bath_tolerance", non_negative(self.bond.bath_tolerance), "0以上"),
+            ("events", "gift_chance", (0.0..=1.0).contains(&self.events.gift_chance), "0から1"),
+            ("events", "gift_happiness", (0..=100).contains(&self.events.gift_happiness), "0から100"),
+            ("events", "cup_chance", (0.0..=1.0).contains(&self.events.cup_chance), "0から1"),
+            ("events", "storm_chance", (0.0..=1.0).contains(&self.events.storm_chance), "0から1"),
+            ("events", "storm_night", non_negative(self.events.storm_night), "0以上"),
+            ("events", "sunbeam_chance", (0.0..=1.0).contains(&self.events.sunbeam_chance), "0から1"),
+            (
+                "quests",
+                "per_day",
+                (1..=QUEST_POOL.len()).contains(&self.quests.per_day),
+                "1からクエストの種類の数まで",
+            ),
+        ];
+        for (table, preset) in [
+            ("difficulty.relaxed", self.difficulty.relaxed),
+            ("difficulty.hardcore", self.difficulty.hardcore),
+        ] {
+            checks.push((table, "decay_scale", non_negative(preset.decay_scale), "0以上"));
+            checks.push((table, "threshold_scale", non_negative(preset.threshold_scale), "0以上"));
+            checks.push((table, "sleep_scale", positive(preset.sleep_scale), "正の数"));
+        }
+        for (table, multiplier) in [
+            ("life.kitten", self.life.kitten),
+            ("life.adult", self.life.adult),
+            ("life.senior", self.life.senior),
+            ("daytime.dusk", self.daytime.dusk),
+            ("daytime.night", self.daytime.night),
+            ("weight.underweight", self.weight.underweight),
+        ] {
+            for (key, value) in [
+                ("hunger", multiplier.hunger),
+                ("happiness", multiplier.happiness),
+                ("cleanliness", multiplier.cleanliness),
+                ("health", multiplier.health),
+            ] {
+                checks.push((table, key, non_negative(value), "0以上"));
+            }
+        }
+        for (table, effect) in [
+            ("feed", self.feed.effect()),
+            ("scraps", self.scraps),
+            ("play", self.play),
+            ("bathe", self.bathe),
+            ("items.wet_food", self.items.wet_food),
+            ("items.treat", self.items.treat),
+            ("items.feather_toy", self.items.feather_toy),
+            ("items.ball", self.items.ball),
+            ("items.catnip", self.items.catnip),
+            ("items.medicine", self.items.medicine),
+        ] {
+            for (key, value) in [
+                ("hunger", effect.hunger),
+                ("happiness", effect.happiness),
+                ("cleanliness", effect.cleanliness),
+                ("health", effect.health),
+            ] {
+                checks.push((table, key, (-100.0..=100.0).contains(&value), "-100から100"));
+            }
+        }
+
+        match checks.into_iter().find(|(_, _, ok, _)| !ok) {
+            Some((table, key, _, expected)) => Err(RulesError {
+                path: None,
+                line: find_key_line(source, table, key),
+                message: format!("{}.{} は{}で指定してください", table, key, expected),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+// inf は「0以上」「正の数」に当てはまってしまうので、有限の数だけ通す
+fn non_negative(value: f64) -> bool {
+    value.is_finite() && value >= 0.0
+}
+
+fn positive(value: f64) -> bool {
+    value.is_finite() && value > 0.0
+}
+
+fn line_of_offset(source: &str, offset: usize) -> usize {
+    source[..offset.min(source.len())].matches('\n').count() + 1
+}
+
+// [table] 内の key = ... の行番号（1始まり）を探す
+fn find_key_line(source: &str, table: &str, key: &str) -> Option<usize> {
+    let mut current = "";
+    for (index, line) in source.lines().enumerate() {
+        let line = line.trim();
+        if let Some(name) = line.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+            current = name.trim();
+        } else if current == table
+            && let Some((name, _)) = line.split_once('=')
+            && name.trim() == key
+        {
+            return Some(index + 1);
+        }
+    }
+    None
+}
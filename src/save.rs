@@ -0,0 +1,129 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::achievements::Achievements;
+use crate::cat::Cat;
+use crate::dice::Dice;
+use crate::difficulty::Difficulty;
+use crate::items::Inventory;
+use crate::memorial::MemorialEntry;
+use crate::quests::QuestBoard;
+use crate::relations::Relations;
+use crate::shop::Cosmetic;
+
+const SAVE_DIR: &str = "neko-watch";
+const SAVE_FILE: &str = "save.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveData {
+    pub saved_at: DateTime<Local>,
+    // 一緒に暮らしている猫たち（古いセーブデータでは "cat" に1匹だけ）
+    #[serde(alias = "cat", deserialize_with = "one_or_many")]
+    pub cats: Vec<Cat>,
+    // 選択中の猫
+    #[serde(default)]
+    pub focus: usize,
+    // お別れした猫たち（古い順）
+    #[serde(default)]
+    pub memorial: Vec<MemorialEntry>,
+    // 持ち物（古いセーブデータでは最初の持ち物）
+    #[serde(default)]
+    pub inventory: Inventory,
+    #[serde(default)]
+    pub coins: u32,
+    // 買った着せかえ
+    #[serde(default)]
+    pub wardrobe: Vec<Cosmetic>,
+    // 猫どうしの関係
+    #[serde(default)]
+    pub relations: Relations,
+    // 解除した実績
+    #[serde(default)]
+    pub achievements: Achievements,
+    // 今日のクエストと進み具合
+    #[serde(default)]
+    pub quests: QuestBoard,
+    // ゲーム全体の乱数の種と途中の状態（古いセーブデータではランダムな種から）
+    #[serde(default = "Dice::random")]
+    pub dice: Dice,
+    // 新しいゲームで選んだ難易度（古いセーブデータではふつう）
+    #[serde(default)]
+    pub difficulty: Difficulty,
+}
+
+impl SaveData {
+    pub fn new(cat: Cat, saved_at: DateTime<Local>) -> Self {
+        Self {
+            saved_at,
+            cats: vec![cat],
+            focus: 0,
+            memorial: Vec::new(),
+            inventory: Inventory::default(),
+            coins: 0,
+            wardrobe: Vec::new(),
+            relations: Relations::default(),
+            achievements: Achievements::default(),
+            quests: QuestBoard::default(),
+            dice: Dice::random(),
+            difficulty: Difficulty::default(),
+        }
+    }
+}
+
+// 1匹だけの古い形式と、複数匹の形式のどちらも読めるようにする
+fn one_or_many<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Cat>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(Box<Cat>),
+        Many(Vec<Cat>),
+    }
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(cat) => vec![*cat],
+        OneOrMany::Many(cats) => cats,
+    })
+}
+
+// XDGデータディレクトリ（例: ~/.local/share/neko-watch/save.json）
+pub fn save_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join(SAVE_DIR).join(SAVE_FILE))
+}
+
+// セーブファイルが無い場合は None を返す
+pub fn load() -> Result<Option<SaveData>, Box<dyn std::error::Error>> {
+    let Some(path) = save_path() else {
+        return Ok(None);
+    };
+    load_from(&path)
+}
+
+pub fn load_from(path: &Path) -> Result<Option<SaveData>, Box<dyn std::error::Error>> {
+    let json = match fs::read_to_string(path) {
+        Ok(json) => json,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    let data = serde_json::from_str(&json)
+        .map_err(|err| format!("セーブデータの読み込みに失敗しました ({}): {}", path.display(), err))?;
+    Ok(Some(data))
+}
+
+pub fn save(data: &SaveData) -> Result<(), Box<dyn std::error::Error>> {
+    let path = save_path().ok_or("データディレクトリが見つかりません")?;
+    save_to(&path, data)
+}
+
+pub fn save_to(path: &Path, data: &SaveData) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    // 書き込み途中で落ちても元のセーブが壊れないよう一時ファイル経由で置き換える
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, serde_json::to_string_pretty(data)?)?;
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use crate::items::ItemKind;
+use crate::rules::ShopRules;
+
+// 着せかえ（一度買えばどの猫にも着けられる）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Cosmetic {
+    Ribbon,  // リボン
+    Bell,    // 鈴
+    Bandana, // バンダナ
+}
+
+impl Cosmetic {
+    pub const ALL: [Cosmetic; 3] = [Cosmetic::Ribbon, Cosmetic::Bell, Cosmetic::Bandana];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Cosmetic::Ribbon => "リボン",
+            Cosmetic::Bell => "鈴",
+            Cosmetic::Bandana => "バンダナ",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Cosmetic::Ribbon => "🎀",
+            Cosmetic::Bell => "🔔",
+            Cosmetic::Bandana => "🧣",
+        }
+    }
+}
+
+// ショップの品物
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShopEntry {
+    Item(ItemKind),
+    Cosmetic(Cosmetic),
+}
+
+impl ShopEntry {
+    // 並び順: ごはん → おもちゃ → 薬 → 着せかえ
+    pub fn catalogue() -> Vec<ShopEntry> {
+        ItemKind::ALL
+            .into_iter()
+            .map(ShopEntry::Item)
+            .chain(Cosmetic::ALL.into_iter().map(ShopEntry::Cosmetic))
+            .collect()
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ShopEntry::Item(kind) => kind.label(),
+            ShopEntry::Cosmetic(cosmetic) => cosmetic.label(),
+        }
+    }
+
+    pub fn price(&self, rules: &ShopRules) -> u32 {
+        match self {
+            ShopEntry::Item(ItemKind::DryFood) => rules.dry_food,
+            ShopEntry::Item(ItemKind::WetFood) => rules.wet_food,
+            ShopEntry::Item(ItemKind::Treat) => rules.treat,
+            ShopEntry::Item(ItemKind::FeatherToy) => rules.feather_toy,
+            ShopEntry::Item(ItemKind::Ball) => rules.ball,
+            ShopEntry::Item(ItemKind::Catnip) => rules.catnip,
+            ShopEntry::Item(ItemKind::Medicine) => rules.medicine,
+            ShopEntry::Cosmetic(Cosmetic::Ribbon) => rules.ribbon,
+            ShopEntry::Cosmetic(Cosmetic::Bell) => rules.bell,
+            ShopEntry::Cosmetic(Cosmetic::Bandana) => rules.bandana,
+        }
+    }
+}
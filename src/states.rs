@@ -0,0 +1,274 @@
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::art::{self, Animation};
+use crate::cat::{Cat, CatState};
+use crate::daytime::TimeOfDay;
+use crate::illness::Illness;
+use crate::weight::Build;
+
+// 猫の状態の定義。条件を満たすルールのうち priority が最も小さいものが現在の状態になる
+// 状態を追加するときはここにルールを1つ追加する
+pub const STATE_RULES: &[StateRule] = &[
+    StateRule {
+        state: CatState::Dead,
+        priority: 0,
+        when: When::All(&[Condition::Dead]),
+        color: Color::White,
+        animation: art::dead,
+    },
+    StateRule {
+        state: CatState::RanAway,
+        priority: 1,
+        when: When::All(&[Condition::RanAway]),
+        color: Color::DarkGray,
+        animation: art::ran_away,
+    },
+    StateRule {
+        state: CatState::AtVet,
+        priority: 2,
+        when: When::All(&[Condition::AtVet]),
+        color: Color::LightCyan,
+        animation: art::at_vet,
+    },
+    StateRule {
+        state: CatState::Sleeping,
+        priority: 5,
+        when: When::All(&[Condition::Sleeping]),
+        color: Color::Blue,
+        animation: art::sleeping,
+    },
+    StateRule {
+        state: CatState::Dying,
+        priority: 10,
+        when: When::Any(&[
+            Condition::Below(Stat::Hunger, 10),
+            Condition::Below(Stat::Happiness, 10),
+            Condition::Below(Stat::Cleanliness, 10),
+            Condition::Below(Stat::Health, 10),
+        ]),
+        color: Color::Red,
+        animation: art::dying,
+    },
+    // 病気の症状は瀕死より目立たず、健康度が低いだけの「病気」より優先する
+    StateRule {
+        state: CatState::Cold,
+        priority: 12,
+        when: When::All(&[Condition::Ill(Illness::Cold)]),
+        color: Color::LightMagenta,
+        animation: art::cold,
+    },
+    StateRule {
+        state: CatState::UpsetStomach,
+        priority: 14,
+        when: When::All(&[Condition::Ill(Illness::UpsetStomach)]),
+        color: Color::LightMagenta,
+        animation: art::upset_stomach,
+    },
+    StateRule {
+        state: CatState::SkinTrouble,
+        priority: 16,
+        when: When::All(&[Condition::Ill(Illness::SkinTrouble)]),
+        color: Color::LightMagenta,
+        animation: art::skin_trouble,
+    },
+    StateRule {
+        state: CatState::Hairball,
+        priority: 18,
+        when: When::All(&[Condition::Ill(Illness::Hairball)]),
+        color: Color::LightMagenta,
+        animation: art::hairball,
+    },
+    StateRule {
+        state: CatState::Sick,
+        priority: 20,
+        when: When::All(&[Condition::Below(Stat::Health, 20)]),
+        color: Color::LightRed,
+        animation: art::sick,
+    },
+    // 空腹が続いて痩せてしまった
+    StateRule {
+        state: CatState::Underweight,
+        priority: 25,
+        when: When::All(&[Condition::Shaped(Build::Skinny)]),
+        color: Color::Magenta,
+        animation: art::underweight,
+    },
+    StateRule {
+        state: CatState::Hungry,
+        priority: 30,
+        when: When::All(&[Condition::Below(Stat::Hunger, 30)]),
+        color: Color::LightYellow,
+        animation: art::hungry,
+    },
+    StateRule {
+        state: CatState::Dirty,
+        priority: 40,
+        when: When::All(&[Condition::Below(Stat::Cleanliness, 30)]),
+        color: Color::Yellow,
+        animation: art::dirty,
+    },
+    StateRule {
+        state: CatState::Unhappy,
+        priority: 50,
+        when: When::All(&[Condition::Below(Stat::Happiness, 30)]),
+        color: Color::Gray,
+        animation: art::unhappy,
+    },
+    StateRule {
+        state: CatState::Tired,
+        priority: 60,
+        when: When::All(&[
+            Condition::Below(Stat::Health, 50),
+            Condition::Below(Stat::Happiness, 50),
+        ]),
+        color: Color::DarkGray,
+        animation: art::tired,
+    },
+    // 夜は眠くなりやすい
+    StateRule {
+        state: CatState::Tired,
+        priority: 60,
+        when: When::All(&[Condition::At(TimeOfDay::Night), Condition::Below(Stat::Health, 70)]),
+        color: Color::DarkGray,
+        animation: art::tired,
+    },
+    // 食べすぎて太ってしまった（満腹より目立つ）
+    StateRule {
+        state: CatState::Overweight,
+        priority: 65,
+        when: When::All(&[Condition::Shaped(Build::Chubby)]),
+        color: Color::LightBlue,
+        animation: art::overweight,
+    },
+    StateRule {
+        state: CatState::Full,
+        priority: 70,
+        when: When::All(&[Condition::Above(Stat::Hunger, 90), Condition::RecentlyFed]),
+        color: Color::Green,
+        animation: art::full,
+    },
+    // 夕方は遊びたがる
+    StateRule {
+        state: CatState::Playful,
+        priority: 75,
+        when: When::All(&[Condition::At(TimeOfDay::Dusk), Condition::Below(Stat::Happiness, 80)]),
+        color: Color::LightCyan,
+        animation: art::playful,
+    },
+    StateRule {
+        state: CatState::Happy,
+        priority: 80,
+        when: When::All(&[Condition::Above(Stat::Happiness, 80)]),
+        color: Color::LightGreen,
+        animation: art::happy,
+    },
+    StateRule {
+        state: CatState::Normal,
+        priority: u8::MAX,
+        when: When::Always,
+        color: Color::Yellow,
+        animation: art::normal,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Stat {
+    Hunger,
+    Happiness,
+    Cleanliness,
+    Health,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Condition {
+    Below(Stat, i32),
+    Above(Stat, i32),
+    Sleeping,
+    RecentlyFed,
+    Dead,
+    RanAway,
+    AtVet,
+    Ill(Illness),
+    At(TimeOfDay),
+    Shaped(Build),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum When {
+    Always,
+    All(&'static [Condition]),
+    Any(&'static [Condition]),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StateRule {
+    pub state: CatState,
+    pub priority: u8,
+    pub when: When,
+    pub color: Color,
+    pub animation: Animation,
+}
+
+impl Stat {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Stat::Hunger => "空腹度",
+            Stat::Happiness => "幸福度",
+            Stat::Cleanliness => "清潔度",
+            Stat::Health => "健康度",
+        }
+    }
+
+    pub fn value(&self, cat: &Cat) -> i32 {
+        match self {
+            Stat::Hunger => cat.hunger,
+            Stat::Happiness => cat.happiness,
+            Stat::Cleanliness => cat.cleanliness,
+            Stat::Health => cat.health,
+        }
+    }
+}
+
+impl Condition {
+    pub fn holds(&self, cat: &Cat) -> bool {
+        match self {
+            Condition::Below(stat, threshold) => stat.value(cat) < *threshold,
+            Condition::Above(stat, threshold) => stat.value(cat) > *threshold,
+            Condition::Sleeping => cat.is_sleeping(),
+            Condition::RecentlyFed => cat.is_recently_fed(),
+            Condition::Dead => cat.is_dead(),
+            Condition::RanAway => cat.has_run_away(),
+            Condition::AtVet => cat.is_at_vet(),
+            Condition::Ill(illness) => cat.illness() == Some(*illness),
+            Condition::At(time) => cat.time_of_day() == *time,
+            Condition::Shaped(build) => cat.build() == *build,
+        }
+    }
+}
+
+impl StateRule {
+    pub fn matches(&self, cat: &Cat) -> bool {
+        match self.when {
+            When::Always => true,
+            When::All(conditions) => conditions.iter().all(|condition| condition.holds(cat)),
+            When::Any(conditions) => conditions.iter().any(|condition| condition.holds(cat)),
+        }
+    }
+}
+
+// 状態のルール（優先順位に関係なく条件だけ調べたいとき）
+pub fn rule(state: CatState) -> &'static StateRule {
+    STATE_RULES
+        .iter()
+        .find(|rule| rule.state == state)
+        .expect("すべての状態にルールがある")
+}
+
+pub fn evaluate(cat: &Cat) -> &'static StateRule {
+    STATE_RULES
+        .iter()
+        .filter(|rule| rule.matches(cat))
+        .min_by_key(|rule| rule.priority)
+        .expect("普通状態のルールは常に成立する")
+}
@@ -1,18 +1,35 @@
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 
-use crate::{cat::Cat, app::App};
+use crate::{
+    achievements::ACHIEVEMENT_RULES,
+    app::{App, Notice, Screen, MAX_CATS},
+    art::Animation,
+    items::Category,
+    rules::ActionEffect,
+    shop::ShopEntry,
+    away::AwaySummary,
+    bond::{Perk, PERKS},
+    cat::Cat,
+    clock::format_duration,
+    daytime::TimeOfDay,
+    difficulty::Difficulty,
+    life::LifeStage,
+    memorial::MemorialEntry,
+    relations,
+};
 
 pub fn draw(frame: &mut Frame, app: &App) {
     #[cfg(debug_assertions)]
     let constraints = if app.show_debug {
         vec![
             Constraint::Length(3),   // タイトル
+            Constraint::Length(3),   // 家族
             Constraint::Length(14),  // 猫の表示（6行対応に拡大）
             Constraint::Length(3),   // コマンド
             Constraint::Min(5),      // デバッグ情報
@@ -20,6 +37,7 @@ pub fn draw(frame: &mut Frame, app: &App) {
     } else {
         vec![
             Constraint::Length(3),   // タイトル
+            Constraint::Length(3),   // 家族
             Constraint::Length(14),  // 猫の表示（6行対応に拡大）
             Constraint::Length(3),   // コマンド
         ]
@@ -28,6 +46,7 @@ pub fn draw(frame: &mut Frame, app: &App) {
     #[cfg(not(debug_assertions))]
     let constraints = vec![
         Constraint::Length(3),   // タイトル
+        Constraint::Length(3),   // 家族
         Constraint::Length(14),  // 猫の表示（6行対応に拡大）
         Constraint::Length(3),   // コマンド
     ];
@@ -38,86 +57,785 @@ pub fn draw(frame: &mut Frame, app: &App) {
         .constraints(constraints)
         .split(frame.size());
 
-    draw_title(frame, chunks[0]);
-    draw_cat(frame, chunks[1], &app.cat);
-    draw_commands(frame, chunks[2]);
+    draw_title(frame, chunks[0], app);
+    draw_household(frame, chunks[1], app);
+    draw_cat(frame, chunks[2], app);
+    draw_commands(frame, chunks[3], app);
     
     #[cfg(debug_assertions)]
-    if app.show_debug && chunks.len() > 3 {
-        let debug_text = app.cat.debug_status();
-        draw_debug_info(frame, chunks[3], &debug_text);
+    if app.show_debug && chunks.len() > 4 {
+        let debug_text = format!("{}\n乱数の種: {}", app.cat().debug_status(), app.dice.seed());
+        draw_debug_info(frame, chunks[4], &debug_text);
+    }
+
+    match &app.screen {
+        Screen::Main => {}
+        Screen::GameOver => {
+            if let Some(entry) = MemorialEntry::of(app.cat()) {
+                draw_game_over(frame, &entry, app.cats.len() > 1);
+            }
+        }
+        Screen::Memorial => draw_memorial(frame, &app.memorial),
+        Screen::Profile => draw_profile(frame, app),
+        Screen::Adopt { name, difficulty, .. } => draw_adopt(frame, name, *difficulty),
+        Screen::Picker { category, selected } => draw_picker(frame, app, *category, *selected),
+        Screen::Shop { selected } => draw_shop(frame, app, *selected),
+        Screen::Litter { selected } => draw_litter(frame, app, *selected),
+        Screen::Achievements => draw_achievements(frame, app),
+        Screen::Quests => draw_quests(frame, app),
+    }
+
+    if let Some(summary) = &app.away {
+        draw_away(frame, summary);
+    }
+    if let Some(toast) = &app.toast {
+        draw_toast(frame, toast);
     }
 }
 
-fn draw_title(frame: &mut Frame, area: Rect) {
-    let title = Paragraph::new("neko-watch")
-        .style(Style::default().fg(Color::Cyan))
+fn draw_title(frame: &mut Frame, area: Rect, app: &App) {
+    let mut spans = vec![Span::styled("neko-watch", Style::default().fg(Color::Cyan))];
+    // 通常速度でないことが一目でわかるようにする
+    if app.is_paused() {
+        spans.push(Span::raw("  "));
+        spans.push(Span::styled(" ⏸ 一時停止中 ", Style::default().fg(Color::Black).bg(Color::Yellow)));
+    } else if app.speed() != 1.0 {
+        spans.push(Span::raw("  "));
+        spans.push(Span::styled(
+            format!(" ▶▶ {}倍速 ", app.speed()),
+            Style::default().fg(Color::Black).bg(Color::Magenta),
+        ));
+    }
+    spans.push(Span::raw("  "));
+    spans.push(Span::styled(
+        format!("{} {}", app.difficulty.icon(), app.difficulty.label()),
+        Style::default().fg(difficulty_color(app.difficulty)),
+    ));
+    spans.push(Span::raw("  "));
+    spans.push(Span::styled(format!("🪙 {}", app.coins), Style::default().fg(Color::LightYellow)));
+    // 今日のクエストの達成数
+    spans.push(Span::raw("  "));
+    spans.push(Span::styled(
+        format!("📜 {}/{}", app.quests.done_count(), app.quests.quests().len()),
+        Style::default().fg(Color::LightGreen),
+    ));
+    // ゲーム内の時刻と時間帯
+    let time = app.cat().time_of_day();
+    spans.push(Span::raw("  "));
+    spans.push(Span::styled(
+        format!("{} {} {}", time.icon(), time.label(), app.cat().clock().now().format("%H:%M")),
+        Style::default().fg(time_color(time)),
+    ));
+    let title = Paragraph::new(Line::from(spans))
         .alignment(Alignment::Center)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .style(Style::default().fg(Color::White)),
+                .style(Style::default().fg(Color::White))
+                .border_style(border_style(app)),
         );
     frame.render_widget(title, area);
 }
 
-fn draw_cat(frame: &mut Frame, area: Rect, cat: &Cat) {
-    let color = get_cat_color(cat);
-    let cat_art = Paragraph::new(cat.get_ascii_art())
-        .style(Style::default().fg(color))
+// 家族全員の様子を状態の色で並べる。選択中の猫は太字
+fn draw_household(frame: &mut Frame, area: Rect, app: &App) {
+    let mut spans = Vec::new();
+    for (index, cat) in app.cats.iter().enumerate() {
+        let mut style = Style::default().fg(get_cat_color(cat));
+        let marker = if index == app.focus {
+            style = style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+            "▶"
+        } else {
+            "●"
+        };
+        spans.push(Span::styled(format!("{} {}", marker, cat.name), style));
+        spans.push(Span::raw("  "));
+    }
+    let mut hints = Vec::new();
+    if app.cats.len() > 1 {
+        hints.push("[Tab] 切り替え");
+    }
+    if app.cats.len() < MAX_CATS {
+        hints.push("[a] 家族を増やす");
+        hints.push("[l] 子猫");
+    }
+    spans.push(Span::styled(hints.join("  "), Style::default().fg(Color::Gray)));
+    let household = Paragraph::new(Line::from(spans)).alignment(Alignment::Center).block(
+        Block::default()
+            .title(format!("家族 {}/{}", app.cats.len(), MAX_CATS))
+            .borders(Borders::ALL)
+            .border_style(border_style(app)),
+    );
+    frame.render_widget(household, area);
+}
+
+fn draw_cat(frame: &mut Frame, area: Rect, app: &App) {
+    let cat = app.cat();
+    let mut style = Style::default().fg(get_cat_color(cat));
+    // シニア猫と夜は少しくすんだ色で表示
+    if cat.life_stage() == LifeStage::Senior || is_night(app) {
+        style = style.add_modifier(Modifier::DIM);
+    }
+    let mut title = format!("名前: {} ({})", cat.name, cat.life_stage().label());
+    if let Some(accessory) = cat.accessory {
+        title = format!("{} {}", title, accessory.icon());
+    }
+    if cat.outcome().is_some() {
+        title = format!("{} - {}", title, cat.get_state().label());
+    } else if let (Some(illness), Some(severity)) = (cat.illness(), cat.severity()) {
+        title = format!("{} - {}（{}）", title, illness.label(), severity.label());
+    } else if let Some(behavior) = cat.behavior() {
+        title = format!("{} - {}中", title, behavior.label());
+    }
+    let mut block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style(app));
+    if let Some(notice) = &app.notice {
+        block = block.title_bottom(
+            Line::styled(
+                format!(" {} ", notice.text),
+                Style::default().fg(Color::LightMagenta).add_modifier(Modifier::BOLD),
+            )
+            .alignment(Alignment::Center),
+        );
+    }
+    // ほかの猫と関わり合っている間は2匹を並べる
+    let mut art: Vec<Line> = match app.scene() {
+        Some((actor, partner, interaction)) => {
+            let rule = interaction.rule();
+            let left = paint_cat(actor, rule.actor_animation);
+            let right = paint_cat(partner, rule.partner_animation);
+            left.into_iter()
+                .zip(right)
+                .map(|(left, right)| {
+                    let mut spans = left.spans;
+                    spans.push(Span::raw("  "));
+                    spans.extend(right.spans);
+                    Line::from(spans)
+                })
+                .collect()
+        }
+        None => paint_cat(cat, cat.animation()),
+    };
+    if let Some(line) = sleep_line(cat) {
+        art.push(Line::raw(""));
+        art.push(line);
+    }
+    let cat_art = Paragraph::new(art)
+        .style(style)
         .alignment(Alignment::Center)
+        .block(block);
+    frame.render_widget(cat_art, area);
+}
+
+// 眠っている間の進み具合と眠りの質
+fn sleep_line(cat: &Cat) -> Option<Line<'static>> {
+    let nap = cat.nap()?;
+    let remaining = cat.sleep_remaining_seconds()?;
+    let quality = cat.sleep_quality()?;
+    Some(Line::styled(
+        format!(
+            "💤 {} 残り {}  眠りの質: {}",
+            progress_bar(nap.progress()),
+            format_duration(chrono::Duration::seconds(remaining)),
+            quality.label()
+        ),
+        Style::default().fg(Color::LightBlue),
+    ))
+}
+
+// 絆が深まった猫は、選択肢のカーソルを追いかけて歩いてくる
+fn follower(app: &App, index: usize) -> Option<Span<'static>> {
+    let cat = app.cat();
+    if index != app.follower || !cat.has_perk(Perk::FollowCursor) {
+        return None;
+    }
+    Some(Span::styled(" ᓚᘏᗢ", Style::default().fg(cat.coat.color.color())))
+}
+
+fn get_cat_color(cat: &Cat) -> Color {
+    cat.state_rule().color
+}
+
+// アートをマスごとに塗る。体は毛並み、目は目の色、行6のエフェクトは状態の色
+fn paint_cat(cat: &Cat, animation: Animation) -> Vec<Line<'static>> {
+    let eyes = cat.eye_position(animation);
+    let state_color = get_cat_color(cat);
+    let cell_color = |row: usize, col: usize| match eyes {
+        // 家出中・通院中など猫がいないときは状態の色だけ
+        None => state_color,
+        Some(_) if row >= 5 => state_color,
+        // "( o.o )" の o の部分
+        Some((eye_row, eye_col)) if row == eye_row && (col == eye_col + 2 || col == eye_col + 4) => {
+            cat.coat.eyes.color()
+        }
+        Some(_) => cat.coat.cell_color(row, col),
+    };
+    cat.art_with(animation)
+        .lines()
+        .enumerate()
+        .map(|(row, text)| {
+            // 同じ色が続くマスはまとめて1つの Span にする
+            let mut spans: Vec<Span<'static>> = Vec::new();
+            let mut run = String::new();
+            let mut run_color = state_color;
+            for (col, ch) in text.chars().enumerate() {
+                let color = cell_color(row, col);
+                if color != run_color && !run.is_empty() {
+                    spans.push(Span::styled(std::mem::take(&mut run), Style::default().fg(run_color)));
+                }
+                run_color = color;
+                run.push(ch);
+            }
+            spans.push(Span::styled(run, Style::default().fg(run_color)));
+            Line::from(spans)
+        })
+        .collect()
+}
+
+fn is_night(app: &App) -> bool {
+    app.cat().time_of_day() == TimeOfDay::Night
+}
+
+// 夜は枠線を暗くする
+fn border_style(app: &App) -> Style {
+    if is_night(app) {
+        Style::default().fg(Color::DarkGray)
+    } else {
+        Style::default()
+    }
+}
+
+fn time_color(time: TimeOfDay) -> Color {
+    match time {
+        TimeOfDay::Morning => Color::LightYellow,
+        TimeOfDay::Day => Color::Yellow,
+        TimeOfDay::Dusk => Color::LightRed,
+        TimeOfDay::Night => Color::LightBlue,
+    }
+}
+
+fn difficulty_color(difficulty: Difficulty) -> Color {
+    match difficulty {
+        Difficulty::Relaxed => Color::LightGreen,
+        Difficulty::Standard => Color::Gray,
+        Difficulty::Hardcore => Color::LightRed,
+    }
+}
+
+fn draw_away(frame: &mut Frame, summary: &AwaySummary) {
+    let mut lines: Vec<Line> = summary.lines().into_iter().map(Line::from).collect();
+    lines.push(Line::from(""));
+    lines.push(Line::styled("何かキーを押すと再開します", Style::default().fg(Color::Gray)));
+
+    let area = centered_rect(frame.size(), 44, lines.len() as u16 + 2);
+    let popup = Paragraph::new(lines)
+        .style(Style::default().fg(Color::White))
         .block(
             Block::default()
-                .title(format!("名前: {}", cat.name))
-                .borders(Borders::ALL),
+                .title("留守中の様子")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::Cyan)),
         );
-    frame.render_widget(cat_art, area);
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
 }
 
-fn get_cat_color(cat: &Cat) -> Color {
-    // 睡眠中は専用の色
-    if cat.is_sleeping() {
-        return Color::Blue;
+// farewell: ほかの猫がいれば見送ってそちらへ戻れる
+fn draw_game_over(frame: &mut Frame, entry: &MemorialEntry, farewell: bool) {
+    let mut lines: Vec<Line> = entry.lines().into_iter().map(Line::from).collect();
+    lines.push(Line::from(""));
+    if farewell {
+        lines.push(Line::styled("[Esc] 見送ってほかの猫のところへ", Style::default().fg(Color::Gray)));
     }
-    
-    let state = cat.get_state();
-    match state {
-        crate::cat::CatState::Dying => Color::Red,
-        crate::cat::CatState::Sick => Color::LightRed,
-        crate::cat::CatState::Hungry => Color::LightYellow,
-        crate::cat::CatState::Dirty => Color::Yellow,
-        crate::cat::CatState::Unhappy => Color::Gray,
-        crate::cat::CatState::Tired => Color::DarkGray,
-        crate::cat::CatState::Happy => Color::LightGreen,
-        crate::cat::CatState::Full => Color::Green,
-        crate::cat::CatState::Normal => Color::Yellow,
+    lines.push(Line::styled(
+        "[n] 新しい猫を迎える  [m] 思い出  [q] 終了",
+        Style::default().fg(Color::Gray),
+    ));
+
+    let area = centered_rect(frame.size(), 48, lines.len() as u16 + 2);
+    let popup = Paragraph::new(lines)
+        .style(Style::default().fg(Color::White))
+        .block(
+            Block::default()
+                .title("お別れ")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::LightMagenta)),
+        );
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+}
+
+fn draw_memorial(frame: &mut Frame, memorial: &[MemorialEntry]) {
+    let mut lines: Vec<Line> = if memorial.is_empty() {
+        vec![Line::from("まだ誰もいません")]
+    } else {
+        // 新しい順
+        memorial.iter().rev().map(|entry| Line::from(entry.summary())).collect()
+    };
+    lines.push(Line::from(""));
+    lines.push(Line::styled("[Esc] 戻る", Style::default().fg(Color::Gray)));
+
+    let height = (lines.len() as u16 + 2).min(frame.size().height);
+    let area = centered_rect(frame.size(), 64, height);
+    let popup = Paragraph::new(lines)
+        .style(Style::default().fg(Color::White))
+        .block(
+            Block::default()
+                .title("思い出")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::Cyan)),
+        );
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+}
+
+fn draw_profile(frame: &mut Frame, app: &App) {
+    let cat = app.cat();
+    let care = cat.care;
+    let mut lines = vec![
+        Line::from(format!("名前: {}", cat.name)),
+        Line::from(format!("成長段階: {}", cat.life_stage().label())),
+        Line::from(format!("毛並み: {}", cat.coat.label())),
+        Line::from(format!("体重: {:.1}kg（{}）", cat.weight, cat.build().label())),
+        Line::from(format!(
+            "一緒に過ごした時間: {}",
+            format_duration(chrono::Duration::seconds(cat.age_seconds() as i64))
+        )),
+        Line::from(""),
+        Line::from(format!("性格: {}", cat.personality.label())),
+    ];
+    if let Some([mother, father]) = &cat.parents {
+        lines.insert(3, Line::from(format!("両親: {} × {}", mother, father)));
+    }
+    for t in &cat.personality.traits {
+        lines.push(Line::styled(
+            format!("  {}: {}", t.label(), t.description()),
+            Style::default().fg(Color::Gray),
+        ));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(format!("絆: {:.0}（{}）", cat.bond.value(), cat.bond.label())));
+    for perk in PERKS {
+        let (mark, color) = if cat.has_perk(perk) {
+            ("✓", Color::LightGreen)
+        } else {
+            ("・", Color::DarkGray)
+        };
+        lines.push(Line::styled(
+            format!("  {} {}（絆{:.0}）", mark, perk.label(), perk.threshold(&cat.rules().bond)),
+            Style::default().fg(color),
+        ));
+    }
+    lines.push(Line::from(format!(
+        "お世話: 餌 {}回 / 遊び {}回 / お風呂 {}回 / 睡眠 {}回 / 薬 {}回",
+        care.fed, care.played, care.bathed, care.slept, care.medicated
+    )));
+    lines.push(Line::from(format!(
+        "お世話の連続記録: {}",
+        format_duration(chrono::Duration::seconds(cat.streak_seconds() as i64))
+    )));
+    if let Some(accessory) = cat.accessory {
+        lines.push(Line::from(format!("着せかえ: {} {}", accessory.icon(), accessory.label())));
+    }
+    if let (Some(ailment), Some(severity)) = (cat.ailment(), cat.severity()) {
+        lines.push(Line::styled(
+            format!(
+                "病気: {}（{}、かかってから{}）",
+                ailment.illness.label(),
+                severity.label(),
+                format_duration(chrono::Duration::seconds(ailment.seconds as i64))
+            ),
+            Style::default().fg(Color::LightMagenta),
+        ));
     }
+    // ほかの猫との仲
+    let others: Vec<&Cat> = app.cats.iter().filter(|other| other.id != cat.id).collect();
+    if !others.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from("仲良し度:"));
+    }
+    for other in others {
+        let affinity = app
+            .relations
+            .affinity(cat.id, other.id)
+            .unwrap_or(cat.rules().relations.start_affinity);
+        lines.push(Line::styled(
+            format!("  {}: {:.0}（{}）", other.name, affinity, relations::affinity_label(affinity)),
+            Style::default().fg(Color::Gray),
+        ));
+    }
+    // 不具合を報告するときに添えてもらう
+    lines.push(Line::from(""));
+    lines.push(Line::styled(format!("乱数の種: {}", app.dice.seed()), Style::default().fg(Color::Gray)));
+    lines.push(Line::from(""));
+    lines.push(Line::styled("[Esc] 戻る", Style::default().fg(Color::Gray)));
+
+    let area = centered_rect(frame.size(), 60, lines.len() as u16 + 2);
+    let popup = Paragraph::new(lines)
+        .style(Style::default().fg(Color::White))
+        .block(
+            Block::default()
+                .title("プロフィール")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::Cyan)),
+        );
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
 }
 
-fn draw_commands(frame: &mut Frame, area: Rect) {
-    #[cfg(debug_assertions)]
-    let commands = vec![
-        Span::raw("[1] 餌をあげる  "),
-        Span::raw("[2] 遊ぶ  "),
-        Span::raw("[3] お風呂  "),
-        Span::raw("[4] 寝かせる  "),
-        Span::styled("[q] 終了", Style::default().fg(Color::Red)),
-        Span::raw("  "),
-        Span::styled("[d] デバッグ [h] 非表示 [0] 瀕死 [8] 病気 [9] 空腹", Style::default().fg(Color::Gray)),
+// 解除済みは日時、目標のある実績は進み具合のバーを表示する
+fn draw_achievements(frame: &mut Frame, app: &App) {
+    let mut lines = Vec::new();
+    for rule in ACHIEVEMENT_RULES {
+        let unlocked = app.achievements.unlocked_at(rule.achievement);
+        let (mark, color) = if unlocked.is_some() {
+            ("🏆", Color::LightYellow)
+        } else {
+            ("・", Color::DarkGray)
+        };
+        lines.push(Line::styled(format!("{} {}", mark, rule.label), Style::default().fg(color)));
+        let detail = match (unlocked, rule.goal, app.achievements.progress(rule.achievement)) {
+            (Some(at), _, _) => format!("{}（{}）", rule.description, at.format("%Y-%m-%d %H:%M")),
+            (None, Some(goal), Some(seconds)) => format!(
+                "{} {} {}/{}{}",
+                rule.description,
+                progress_bar(seconds / goal.seconds),
+                (seconds / goal.unit_seconds) as u32,
+                (goal.seconds / goal.unit_seconds) as u32,
+                goal.unit
+            ),
+            _ => rule.description.to_string(),
+        };
+        lines.push(Line::styled(format!("    {}", detail), Style::default().fg(Color::Gray)));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::styled(
+        format!("{}/{} 解除  [Esc] 戻る", app.achievements.unlocked().len(), ACHIEVEMENT_RULES.len()),
+        Style::default().fg(Color::Gray),
+    ));
+
+    let area = centered_rect(frame.size(), 60, lines.len() as u16 + 2);
+    let popup = Paragraph::new(lines)
+        .style(Style::default().fg(Color::White))
+        .block(
+            Block::default()
+                .title("実績")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::Cyan)),
+        );
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+}
+
+fn draw_quests(frame: &mut Frame, app: &App) {
+    let mut lines = Vec::new();
+    for quest in app.quests.quests() {
+        if quest.is_done() {
+            lines.push(Line::styled(format!("✓ {}", quest.goal.label()), Style::default().fg(Color::LightGreen)));
+        } else {
+            lines.push(Line::from(format!("・ {}", quest.goal.label())));
+            lines.push(Line::styled(
+                format!(
+                    "    {} {}",
+                    progress_bar(quest.progress / quest.goal.target()),
+                    quest.goal.progress_label(quest.progress)
+                ),
+                Style::default().fg(Color::Gray),
+            ));
+        }
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::styled(
+        format!("1つ達成するごとに {} コイン。日付が変わると新しいクエストになります", app.cat().rules().quests.reward),
+        Style::default().fg(Color::Gray),
+    ));
+    lines.push(Line::styled("[Esc] 戻る", Style::default().fg(Color::Gray)));
+
+    let title = match app.quests.date() {
+        Some(date) => format!("今日のクエスト（{}）", date.format("%m/%d")),
+        None => "今日のクエスト".to_string(),
+    };
+    let area = centered_rect(frame.size(), 64, lines.len() as u16 + 2);
+    let popup = Paragraph::new(lines)
+        .style(Style::default().fg(Color::White))
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::Cyan)),
+        );
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+}
+
+// 10マスの進み具合（0.0-1.0）
+fn progress_bar(ratio: f64) -> String {
+    let filled = (ratio.clamp(0.0, 1.0) * 10.0) as usize;
+    format!("[{}{}]", "█".repeat(filled), "░".repeat(10 - filled))
+}
+
+// 右上に重ねて表示する
+fn draw_toast(frame: &mut Frame, toast: &Notice) {
+    let size = frame.size();
+    let width = (Line::from(toast.text.as_str()).width() as u16 + 4).min(size.width);
+    let area = Rect {
+        x: size.x + size.width - width,
+        y: size.y,
+        width,
+        height: 3.min(size.height),
+    };
+    let popup = Paragraph::new(toast.text.as_str())
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(Color::LightYellow).add_modifier(Modifier::BOLD))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::Yellow)),
+        );
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+}
+
+fn draw_picker(frame: &mut Frame, app: &App, category: Category, selected: usize) {
+    let rules = app.cat().rules();
+    let mut lines = Vec::new();
+    for (index, item) in App::picker_options(category).into_iter().enumerate() {
+        let (name, effect, in_stock) = match item {
+            Some(kind) => (
+                app.inventory.describe(kind),
+                rules.item_effect(kind),
+                app.inventory.quantity(kind) > 0,
+            ),
+            None if category == Category::Food => ("ありあわせのごはん".to_string(), rules.scraps, true),
+            None => ("手で遊ぶ".to_string(), rules.play, true),
+        };
+        let marker = if index == selected { "> " } else { "  " };
+        let mut style = Style::default().fg(if in_stock { Color::White } else { Color::DarkGray });
+        if index == selected {
+            style = style.add_modifier(Modifier::BOLD);
+        }
+        lines.push(Line::from_iter(std::iter::once(Span::styled(format!("{}{}", marker, name), style)).chain(follower(app, index))));
+        lines.push(Line::styled(
+            format!("    {}", effect_summary(&effect)),
+            Style::default().fg(Color::Gray),
+        ));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::styled(
+        "[↑↓] 選択  [Enter] 決定  [Esc] 戻る",
+        Style::default().fg(Color::Gray),
+    ));
+
+    let title = match category {
+        Category::Food => "ごはんを選ぶ",
+        Category::Toy => "遊び方を選ぶ",
+        Category::Medicine => "薬",
+    };
+    let area = centered_rect(frame.size(), 44, lines.len() as u16 + 2);
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Cyan)),
+    );
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+}
+
+fn draw_shop(frame: &mut Frame, app: &App, selected: usize) {
+    let rules = app.cat().rules();
+    let mut lines = vec![Line::from(format!("所持金: 🪙 {}", app.coins)), Line::from("")];
+    for (index, entry) in ShopEntry::catalogue().into_iter().enumerate() {
+        let price = entry.price(&rules.shop);
+        let detail = match entry {
+            ShopEntry::Item(kind) => format!("{}コイン  (持っている数: {})", price, app.inventory.quantity(kind)),
+            ShopEntry::Cosmetic(cosmetic) if app.cat().accessory == Some(cosmetic) => "着けている".to_string(),
+            ShopEntry::Cosmetic(cosmetic) if app.wardrobe.contains(&cosmetic) => "持っている（着ける）".to_string(),
+            ShopEntry::Cosmetic(_) => format!("{}コイン", price),
+        };
+        let affordable = match entry {
+            ShopEntry::Cosmetic(cosmetic) if app.wardrobe.contains(&cosmetic) => true,
+            _ => app.coins >= price,
+        };
+        let marker = if index == selected { "> " } else { "  " };
+        let mut style = Style::default().fg(if affordable { Color::White } else { Color::DarkGray });
+        if index == selected {
+            style = style.add_modifier(Modifier::BOLD);
+        }
+        lines.push(Line::from_iter(
+            std::iter::once(Span::styled(format!("{}{:<8} {}", marker, entry.label(), detail), style)).chain(follower(app, index)),
+        ));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::styled(
+        "[↑↓] 選択  [Enter] 買う  [Esc] 戻る",
+        Style::default().fg(Color::Gray),
+    ));
+
+    let area = centered_rect(frame.size(), 50, lines.len() as u16 + 2);
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .title("ショップ")
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Cyan)),
+    );
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+}
+
+// 例: "空腹+50 幸福+20 清潔-15"
+fn effect_summary(effect: &ActionEffect) -> String {
+    [
+        ("空腹", effect.hunger),
+        ("幸福", effect.happiness),
+        ("清潔", effect.cleanliness),
+        ("健康", effect.health),
+    ]
+    .into_iter()
+    .filter(|(_, value)| *value != 0.0)
+    .map(|(name, value)| format!("{}{:+}", name, value))
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+fn draw_litter(frame: &mut Frame, app: &App, selected: usize) {
+    let mut lines = vec![
+        Line::from(format!("{}（{}）と子猫を産む相手", app.cat().name, app.cat().coat.label())),
+        Line::from(""),
     ];
-    
-    #[cfg(not(debug_assertions))]
-    let commands = vec![
-        Span::raw("[1] 餌をあげる  "),
-        Span::raw("[2] 遊ぶ  "),
-        Span::raw("[3] お風呂  "),
-        Span::raw("[4] 寝かせる  "),
-        Span::styled("[q] 終了", Style::default().fg(Color::Red)),
+    for (index, partner) in app.partners().into_iter().enumerate() {
+        let partner = &app.cats[partner];
+        let marker = if index == selected { "> " } else { "  " };
+        let mut style = Style::default().fg(Color::White);
+        if index == selected {
+            style = style.add_modifier(Modifier::BOLD);
+        }
+        lines.push(Line::from_iter(
+            std::iter::once(Span::styled(format!("{}{}  {}", marker, partner.name, partner.coat.label()), style))
+                .chain(follower(app, index)),
+        ));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::styled(
+        "[↑↓] 選択  [Enter] 決定  [Esc] 戻る",
+        Style::default().fg(Color::Gray),
+    ));
+
+    let area = centered_rect(frame.size(), 52, lines.len() as u16 + 2);
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .title("子猫")
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Cyan)),
+    );
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+}
+
+fn draw_adopt(frame: &mut Frame, name: &str, difficulty: Option<Difficulty>) {
+    let mut lines = vec![
+        Line::from("新しい猫の名前を入力してください"),
+        Line::from(""),
+        Line::styled(format!("> {}_", name), Style::default().fg(Color::Yellow)),
+        Line::from(""),
     ];
-    
+    // 最初からやり直すときは難易度も選べる
+    if let Some(difficulty) = difficulty {
+        lines.push(Line::styled(
+            format!("難易度: {} {}", difficulty.icon(), difficulty.label()),
+            Style::default().fg(difficulty_color(difficulty)),
+        ));
+        lines.push(Line::from(""));
+        lines.push(Line::styled("[Enter] 決定  [Tab] 難易度  [Esc] 戻る", Style::default().fg(Color::Gray)));
+    } else {
+        lines.push(Line::styled("[Enter] 決定  [Esc] 戻る", Style::default().fg(Color::Gray)));
+    }
+
+    let area = centered_rect(frame.size(), 40, lines.len() as u16 + 2);
+    let popup = Paragraph::new(lines)
+        .style(Style::default().fg(Color::White))
+        .block(
+            Block::default()
+                .title("新しい猫を迎える")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::Cyan)),
+        );
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+}
+
+fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+fn draw_commands(frame: &mut Frame, area: Rect, app: &App) {
+    let quit = Span::styled("[q] 終了", Style::default().fg(Color::Red));
+    let mut commands = match app.screen {
+        Screen::GameOver if app.cats.len() > 1 => vec![
+            Span::raw("[Esc] 見送る  "),
+            Span::raw("[Tab] 切り替え  "),
+            Span::raw("[n] 新しい猫を迎える  "),
+            Span::raw("[m] 思い出  "),
+            quit,
+        ],
+        Screen::GameOver => vec![
+            Span::raw("[n] 新しい猫を迎える  "),
+            Span::raw("[m] 思い出  "),
+            quit,
+        ],
+        Screen::Memorial | Screen::Profile | Screen::Adopt { .. } | Screen::Achievements | Screen::Quests => {
+            vec![Span::raw("[Esc] 戻る")]
+        }
+        Screen::Picker { .. } | Screen::Litter { .. } => vec![Span::raw("[↑↓] 選択  [Enter] 決定  [Esc] 戻る")],
+        Screen::Shop { .. } => vec![Span::raw("[↑↓] 選択  [Enter] 買う  [Esc] 戻る")],
+        // 家出中はお世話の代わりに呼び戻す
+        Screen::Main if app.cat().has_run_away() => vec![
+            Span::raw("[c] 呼び戻す  "),
+            Span::raw("[n] 新しい猫を迎える  "),
+            Span::raw("[m] 思い出  "),
+            quit,
+        ],
+        Screen::Main => {
+            let mut commands = vec![
+                Span::raw("[1] 餌をあげる  "),
+                Span::raw("[2] 遊ぶ  "),
+                Span::raw("[3] お風呂  "),
+                Span::raw(if app.cat().is_sleeping() { "[4] 起こす  " } else { "[4] 寝かせる  " }),
+                Span::raw("[5] 薬  "),
+                Span::raw("[s] ショップ  "),
+                Span::raw("[f] 速度 [space] 停止  "),
+                Span::raw("[p] プロフィール [m] 思い出 [t] 実績 [g] クエスト  "),
+                quit,
+            ];
+            // 病気のときだけ動物病院へ行ける
+            if app.cat().illness().is_some() {
+                commands.insert(5, Span::styled("[v] 動物病院  ", Style::default().fg(Color::LightMagenta)));
+            }
+            commands
+        }
+    };
+
+    #[cfg(debug_assertions)]
+    if app.screen == Screen::Main {
+        commands.push(Span::raw("  "));
+        commands.push(Span::styled(
+            "[d] デバッグ [h] 非表示 [0] 瀕死 [8] 病気 [9] 空腹",
+            Style::default().fg(Color::Gray),
+        ));
+    }
+
     let commands_paragraph = Paragraph::new(Line::from(commands))
         .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL));
+        .block(Block::default().borders(Borders::ALL).border_style(border_style(app)));
     frame.render_widget(commands_paragraph, area);
 }
 
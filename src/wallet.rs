@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rules::CoinRules;
+
+// お世話の良さをコインに換えるための記録（猫ごと）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Rewards {
+    // まだコインにしていない、上機嫌・満腹・普通で過ごした時間（秒）
+    good_seconds: f64,
+    // 空腹・不潔にならずに続いている時間（秒）
+    streak_seconds: f64,
+    // 今の連続記録で受け取ったボーナスの回数
+    bonuses: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Earning {
+    Care(u32),                          // 良い状態で過ごした
+    Streak { hours: f64, coins: u32 }, // 連続記録のボーナス
+}
+
+impl Earning {
+    pub fn coins(&self) -> u32 {
+        match self {
+            Earning::Care(coins) | Earning::Streak { coins, .. } => *coins,
+        }
+    }
+}
+
+impl Rewards {
+    // good: 上機嫌・満腹・普通のとき、broken: 空腹・不潔のとき
+    pub fn track(&mut self, delta_seconds: f64, good: bool, broken: bool, rules: &CoinRules) -> Vec<Earning> {
+        let mut earnings = Vec::new();
+        let delta_seconds = delta_seconds.max(0.0);
+
+        if good {
+            self.good_seconds += delta_seconds;
+            let minutes = (self.good_seconds / 60.0).floor();
+            self.good_seconds -= minutes * 60.0;
+            let coins = minutes as u32 * rules.per_good_minute;
+            if coins > 0 {
+                earnings.push(Earning::Care(coins));
+            }
+        }
+
+        if broken {
+            self.streak_seconds = 0.0;
+            self.bonuses = 0;
+        } else {
+            self.streak_seconds += delta_seconds;
+            let interval = rules.streak_hours * 3600.0;
+            while self.streak_seconds >= interval * (self.bonuses + 1) as f64 {
+                self.bonuses += 1;
+                earnings.push(Earning::Streak {
+                    hours: rules.streak_hours * self.bonuses as f64,
+                    coins: rules.streak_bonus,
+                });
+            }
+        }
+        earnings
+    }
+
+    pub fn streak_seconds(&self) -> f64 {
+        self.streak_seconds
+    }
+}
@@ -0,0 +1,44 @@
+use crate::rules::WeightRules;
+
+// 迎えたときの体重（kg）。十分に食べていればここまで戻る
+pub const START_KG: f64 = 4.0;
+
+// 体重がこれより軽く・重くはならない
+const MIN_KG: f64 = 1.0;
+const MAX_KG: f64 = 12.0;
+
+// 体型。体重が標準の範囲を外れると痩せすぎ・太りすぎ
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Build {
+    Skinny, // 痩せすぎ
+    Normal, // 標準
+    Chubby, // 太りすぎ
+}
+
+impl Build {
+    pub fn of(weight: f64, rules: &WeightRules) -> Self {
+        if weight >= rules.overweight_kg {
+            Build::Chubby
+        } else if weight < rules.underweight_kg {
+            Build::Skinny
+        } else {
+            Build::Normal
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Build::Skinny => "痩せすぎ",
+            Build::Normal => "標準",
+            Build::Chubby => "太りすぎ",
+        }
+    }
+}
+
+pub fn start() -> f64 {
+    START_KG
+}
+
+pub fn clamp(weight: f64) -> f64 {
+    weight.clamp(MIN_KG, MAX_KG)
+}